@@ -9,6 +9,14 @@ fn readme(c: &mut Criterion) {
     });
 }
 
+fn ascii_attention(c: &mut Criterion) {
+    let doc = "a *b* c_d_ **e** (*f*) g\n".repeat(1024);
+
+    c.bench_with_input(BenchmarkId::new("ascii", "attention"), &doc, |b, s| {
+        b.iter(|| markdown::to_html(s));
+    });
+}
+
 // fn one_and_a_half_mb(c: &mut Criterion) {
 //     let doc = fs::read_to_string("../a-dump-of-markdown/markdown.md").unwrap();
 //     let mut group = c.benchmark_group("giant");
@@ -20,5 +28,5 @@ fn readme(c: &mut Criterion) {
 // }
 // , one_and_a_half_mb
 
-criterion_group!(benches, readme);
+criterion_group!(benches, readme, ascii_attention);
 criterion_main!(benches);
@@ -1,8 +1,11 @@
+use crate::event::Name;
 use crate::util::{
+    constant::THEMATIC_BREAK_MARKER_COUNT_MIN,
     line_ending::LineEnding,
     mdx::{EsmParse as MdxEsmParse, ExpressionParse as MdxExpressionParse},
+    text_construct::TextConstruct,
 };
-use alloc::{boxed::Box, fmt, string::String};
+use alloc::{boxed::Box, fmt, string::String, vec, vec::Vec};
 
 /// Control which constructs are enabled.
 ///
@@ -31,7 +34,22 @@ use alloc::{boxed::Box, fmt, string::String};
 /// ```
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "camelCase")
+)]
 pub struct Constructs {
+    /// Abbreviation.
+    ///
+    /// ```markdown
+    /// > | a HTML b
+    ///       ^^^^
+    ///   |
+    /// > | *[HTML]: HyperText Markup Language
+    ///     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    pub abbreviation: bool,
     /// Attention.
     ///
     /// ```markdown
@@ -45,6 +63,10 @@ pub struct Constructs {
     /// > | a <https://example.com> b <user@example.org>.
     ///       ^^^^^^^^^^^^^^^^^^^^^   ^^^^^^^^^^^^^^^^^^
     /// ```
+    ///
+    /// This is separate from
+    /// [`gfm_autolink_literal`][Constructs::gfm_autolink_literal], so you
+    /// can turn off autolinks with angle brackets but keep literals.
     pub autolink: bool,
     /// Block quote.
     ///
@@ -99,6 +121,27 @@ pub struct Constructs {
     ///     ^^^^^^^^^^
     /// ```
     pub definition: bool,
+    /// Details.
+    ///
+    /// ```markdown
+    /// > | >>> a
+    ///     ^^^^^
+    /// > | b
+    ///     ^
+    /// > | <<<
+    ///     ^^^
+    /// ```
+    pub details: bool,
+    /// Emoji.
+    ///
+    /// ```markdown
+    /// > | a :smile: b
+    ///       ^^^^^^^
+    /// ```
+    ///
+    /// > 👉 **Note**: there is no mdast node for emoji, so `to_mdast`
+    /// > turns them into text.
+    pub emoji: bool,
     /// Frontmatter.
     ///
     /// ````markdown
@@ -240,6 +283,20 @@ pub struct Constructs {
     ///     ^^
     /// ```
     pub math_flow: bool,
+    /// Math (flow) with LaTeX delimiters.
+    ///
+    /// Forms math (flow), like [`math_flow`][Constructs::math_flow], with
+    /// `\[` and `\]` on their own lines.
+    ///
+    /// ```markdown
+    /// > | \[
+    ///     ^^
+    /// > | \frac{1}{2}
+    ///     ^^^^^^^^^^^
+    /// > | \]
+    ///     ^^
+    /// ```
+    pub math_flow_latex: bool,
     /// Math (text).
     ///
     /// ```markdown
@@ -247,6 +304,16 @@ pub struct Constructs {
     ///       ^^^
     /// ```
     pub math_text: bool,
+    /// Math (text) with LaTeX delimiters.
+    ///
+    /// Forms math (text), like [`math_text`][Constructs::math_text], with
+    /// `\(` and `\)`.
+    ///
+    /// ```markdown
+    /// > | a \(b\) c
+    ///       ^^^^^
+    /// ```
+    pub math_text_latex: bool,
     /// MDX: ESM.
     ///
     /// ```markdown
@@ -320,6 +387,30 @@ pub struct Constructs {
     /// > Otherwise, expressions are parsed with a basic algorithm that only
     /// > cares about braces.
     pub mdx_jsx_text: bool,
+    /// Subscript.
+    ///
+    /// ```markdown
+    /// > | H~2~O
+    ///      ^^^
+    /// ```
+    ///
+    /// > 👉 **Note**: when this is on, a single tilde forms subscript
+    /// > instead of GFM strikethrough.
+    /// > Two tildes still form GFM strikethrough, if that is on.
+    ///
+    /// > 👉 **Note**: there is no mdast node for subscript, so `to_mdast`
+    /// > keeps its content as text.
+    pub subscript: bool,
+    /// Superscript.
+    ///
+    /// ```markdown
+    /// > | x^2^
+    ///      ^^^
+    /// ```
+    ///
+    /// > 👉 **Note**: there is no mdast node for superscript, so `to_mdast`
+    /// > keeps its content as text.
+    pub superscript: bool,
     /// Thematic break.
     ///
     /// ```markdown
@@ -340,6 +431,7 @@ impl Default for Constructs {
     /// <https://spec.commonmark.org>.
     fn default() -> Self {
         Self {
+            abbreviation: false,
             attention: true,
             autolink: true,
             block_quote: true,
@@ -349,6 +441,8 @@ impl Default for Constructs {
             code_fenced: true,
             code_text: true,
             definition: true,
+            details: false,
+            emoji: false,
             frontmatter: false,
             gfm_autolink_literal: false,
             gfm_label_start_footnote: false,
@@ -367,12 +461,16 @@ impl Default for Constructs {
             label_end: true,
             list_item: true,
             math_flow: false,
+            math_flow_latex: false,
             math_text: false,
+            math_text_latex: false,
             mdx_esm: false,
             mdx_expression_flow: false,
             mdx_expression_text: false,
             mdx_jsx_flow: false,
             mdx_jsx_text: false,
+            subscript: false,
+            superscript: false,
             thematic_break: true,
         }
     }
@@ -432,6 +530,220 @@ impl Constructs {
             ..Self::default()
         }
     }
+
+    /// Whether the construct that produces events named `name` is turned on.
+    ///
+    /// Names that are produced by several constructs (such as
+    /// [`CodeFlowChunk`][Name::CodeFlowChunk], which is in both code
+    /// (fenced) and code (indented)) are enabled when one of those constructs
+    /// is.
+    /// Names that are not produced by a construct that can be turned off here
+    /// (such as [`Paragraph`][Name::Paragraph], [`Data`][Name::Data], or
+    /// [`CommentLine`][Name::CommentLine], which is instead turned on with
+    /// [`line_comment_prefix`][ParseOptions::line_comment_prefix]) are always
+    /// enabled.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{Constructs, EventName};
+    /// # fn main() {
+    ///
+    /// assert!(Constructs::default().is_enabled(&EventName::HeadingAtx));
+    /// assert!(!Constructs::default().is_enabled(&EventName::GfmTable));
+    /// assert!(Constructs::gfm().is_enabled(&EventName::GfmTable));
+    /// # }
+    /// ```
+    pub fn is_enabled(&self, name: &Name) -> bool {
+        match name {
+            Name::ByteOrderMark
+            | Name::BlankLineEnding
+            | Name::CommentLine
+            | Name::Content
+            | Name::Data
+            | Name::LineEnding
+            | Name::Paragraph
+            | Name::SpaceOrTab
+            | Name::TextConstruct => true,
+            Name::Abbreviation
+            | Name::AbbreviationDefinition
+            | Name::AbbreviationDefinitionLabel
+            | Name::AbbreviationDefinitionLabelMarker
+            | Name::AbbreviationDefinitionLabelString
+            | Name::AbbreviationDefinitionMarker
+            | Name::AbbreviationDefinitionTitle => self.abbreviation,
+            Name::AttentionSequence => {
+                self.attention || self.gfm_strikethrough || self.subscript || self.superscript
+            }
+            Name::Autolink
+            | Name::AutolinkEmail
+            | Name::AutolinkMarker
+            | Name::AutolinkProtocol => self.autolink,
+            Name::BlockQuote | Name::BlockQuoteMarker | Name::BlockQuotePrefix => self.block_quote,
+            Name::CharacterEscape | Name::CharacterEscapeMarker | Name::CharacterEscapeValue => {
+                self.character_escape
+            }
+            Name::CharacterReference
+            | Name::CharacterReferenceMarker
+            | Name::CharacterReferenceMarkerHexadecimal
+            | Name::CharacterReferenceMarkerNumeric
+            | Name::CharacterReferenceMarkerSemi
+            | Name::CharacterReferenceValue => self.character_reference,
+            Name::CodeFenced
+            | Name::CodeFencedFence
+            | Name::CodeFencedFenceInfo
+            | Name::CodeFencedFenceMeta
+            | Name::CodeFencedFenceSequence => self.code_fenced,
+            Name::CodeFlowChunk => self.code_fenced || self.code_indented,
+            Name::CodeIndented => self.code_indented,
+            Name::CodeText | Name::CodeTextData | Name::CodeTextSequence => self.code_text,
+            Name::Definition
+            | Name::DefinitionDestination
+            | Name::DefinitionDestinationLiteral
+            | Name::DefinitionDestinationLiteralMarker
+            | Name::DefinitionDestinationRaw
+            | Name::DefinitionDestinationString
+            | Name::DefinitionLabel
+            | Name::DefinitionLabelMarker
+            | Name::DefinitionLabelString
+            | Name::DefinitionTitle
+            | Name::DefinitionTitleMarker
+            | Name::DefinitionTitleString => self.definition,
+            // Also used after the label of footnote definitions.
+            Name::DefinitionMarker => self.definition || self.gfm_footnote_definition,
+            Name::Details
+            | Name::DetailsFence
+            | Name::DetailsFenceSequence
+            | Name::DetailsSummary => self.details,
+            Name::Emoji | Name::EmojiMarker | Name::EmojiShortcode => self.emoji,
+            Name::Emphasis
+            | Name::EmphasisSequence
+            | Name::EmphasisText
+            | Name::Strong
+            | Name::StrongSequence
+            | Name::StrongText => self.attention,
+            Name::Frontmatter
+            | Name::FrontmatterChunk
+            | Name::FrontmatterFence
+            | Name::FrontmatterSequence => self.frontmatter,
+            Name::GfmAutolinkLiteralEmail
+            | Name::GfmAutolinkLiteralMailto
+            | Name::GfmAutolinkLiteralProtocol
+            | Name::GfmAutolinkLiteralWww
+            | Name::GfmAutolinkLiteralXmpp => self.gfm_autolink_literal,
+            Name::GfmFootnoteCall => self.gfm_label_start_footnote && self.label_end,
+            Name::GfmFootnoteCallLabel | Name::GfmFootnoteCallMarker => {
+                self.gfm_label_start_footnote
+            }
+            Name::GfmFootnoteDefinition
+            | Name::GfmFootnoteDefinitionPrefix
+            | Name::GfmFootnoteDefinitionLabel
+            | Name::GfmFootnoteDefinitionLabelMarker
+            | Name::GfmFootnoteDefinitionLabelString
+            | Name::GfmFootnoteDefinitionMarker => self.gfm_footnote_definition,
+            Name::GfmStrikethrough
+            | Name::GfmStrikethroughSequence
+            | Name::GfmStrikethroughText => self.gfm_strikethrough,
+            Name::GfmTable
+            | Name::GfmTableBody
+            | Name::GfmTableCell
+            | Name::GfmTableCellText
+            | Name::GfmTableCellDivider
+            | Name::GfmTableDelimiterRow
+            | Name::GfmTableDelimiterMarker
+            | Name::GfmTableDelimiterCell
+            | Name::GfmTableDelimiterCellValue
+            | Name::GfmTableDelimiterFiller
+            | Name::GfmTableHead
+            | Name::GfmTableRow => self.gfm_table,
+            Name::GfmTaskListItemCheck
+            | Name::GfmTaskListItemMarker
+            | Name::GfmTaskListItemValueChecked
+            | Name::GfmTaskListItemValueUnchecked => self.gfm_task_list_item,
+            Name::HardBreakEscape => self.hard_break_escape,
+            Name::HardBreakTrailing => self.hard_break_trailing,
+            Name::HeadingAtx | Name::HeadingAtxSequence | Name::HeadingAtxText => self.heading_atx,
+            Name::HeadingSetext
+            | Name::HeadingSetextText
+            | Name::HeadingSetextUnderline
+            | Name::HeadingSetextUnderlineSequence => self.heading_setext,
+            Name::HtmlFlow | Name::HtmlFlowData => self.html_flow,
+            Name::HtmlText | Name::HtmlTextData => self.html_text,
+            Name::Image => self.label_start_image && self.label_end,
+            Name::LabelImage | Name::LabelImageMarker => self.label_start_image,
+            Name::LabelLink => self.label_start_link,
+            Name::Link => self.label_start_link && self.label_end,
+            Name::Label
+            | Name::LabelEnd
+            | Name::LabelMarker
+            | Name::LabelText
+            | Name::Reference
+            | Name::ReferenceMarker
+            | Name::ReferenceString
+            | Name::Resource
+            | Name::ResourceDestination
+            | Name::ResourceDestinationLiteral
+            | Name::ResourceDestinationLiteralMarker
+            | Name::ResourceDestinationRaw
+            | Name::ResourceDestinationString
+            | Name::ResourceMarker
+            | Name::ResourceTitle
+            | Name::ResourceTitleMarker
+            | Name::ResourceTitleString => self.label_end,
+            Name::ListItem
+            | Name::ListItemMarker
+            | Name::ListItemPrefix
+            | Name::ListItemValue
+            | Name::ListOrdered
+            | Name::ListUnordered => self.list_item,
+            Name::MathFlow
+            | Name::MathFlowFence
+            | Name::MathFlowFenceMeta
+            | Name::MathFlowFenceSequence
+            | Name::MathFlowChunk => self.math_flow || self.math_flow_latex,
+            Name::MathText | Name::MathTextData | Name::MathTextSequence => {
+                self.math_text || self.math_text_latex
+            }
+            Name::MdxEsm | Name::MdxEsmData => self.mdx_esm,
+            // Also used in attributes.
+            Name::MdxExpressionMarker | Name::MdxExpressionData => {
+                self.mdx_expression_flow
+                    || self.mdx_expression_text
+                    || self.mdx_jsx_flow
+                    || self.mdx_jsx_text
+            }
+            Name::MdxFlowExpression => self.mdx_expression_flow,
+            Name::MdxTextExpression => self.mdx_expression_text,
+            Name::MdxJsxFlowTag => self.mdx_jsx_flow,
+            Name::MdxJsxTextTag => self.mdx_jsx_text,
+            Name::MdxJsxEsWhitespace
+            | Name::MdxJsxTagMarker
+            | Name::MdxJsxTagClosingMarker
+            | Name::MdxJsxTagName
+            | Name::MdxJsxTagNamePrimary
+            | Name::MdxJsxTagNameMemberMarker
+            | Name::MdxJsxTagNamePrefixMarker
+            | Name::MdxJsxTagNameMember
+            | Name::MdxJsxTagNameLocal
+            | Name::MdxJsxTagAttribute
+            | Name::MdxJsxTagAttributeExpression
+            | Name::MdxJsxTagAttributeName
+            | Name::MdxJsxTagAttributePrimaryName
+            | Name::MdxJsxTagAttributeNamePrefixMarker
+            | Name::MdxJsxTagAttributeNameLocal
+            | Name::MdxJsxTagAttributeInitializerMarker
+            | Name::MdxJsxTagAttributeValueExpression
+            | Name::MdxJsxTagAttributeValueLiteral
+            | Name::MdxJsxTagAttributeValueLiteralMarker
+            | Name::MdxJsxTagAttributeValueLiteralValue
+            | Name::MdxJsxTagSelfClosingMarker => self.mdx_jsx_flow || self.mdx_jsx_text,
+            Name::Subscript | Name::SubscriptSequence | Name::SubscriptText => self.subscript,
+            Name::Superscript | Name::SuperscriptSequence | Name::SuperscriptText => {
+                self.superscript
+            }
+            Name::ThematicBreak | Name::ThematicBreakSequence => self.thematic_break,
+        }
+    }
 }
 
 /// Configuration that describes how to compile to HTML.
@@ -467,6 +779,11 @@ impl Constructs {
 /// ```
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "camelCase")
+)]
 pub struct CompileOptions {
     /// Whether to allow (dangerous) HTML.
     ///
@@ -550,374 +867,1512 @@ pub struct CompileOptions {
     /// ```
     pub allow_dangerous_protocol: bool,
 
-    /// Default line ending to use when compiling to HTML, for line endings not
-    /// in `value`.
-    ///
-    /// Generally, `markdown-rs` copies line endings (`\r`, `\n`, `\r\n`) in
-    /// the markdown document over to the compiled HTML.
-    /// In some cases, such as `> a`, CommonMark requires that extra line
-    /// endings are added: `<blockquote>\n<p>a</p>\n</blockquote>`.
-    ///
-    /// To create that line ending, the document is checked for the first line
-    /// ending that is used.
-    /// If there is no line ending, `default_line_ending` is used.
-    /// If that isn’t configured, `\n` is used.
+    /// Extra attributes to use on elements.
+    ///
+    /// The default is an empty list, which means no extra attributes are
+    /// used.
+    /// Pass a list of event names, attribute names, and attribute values to
+    /// add attributes to the elements that those events turn into, such as
+    /// for microdata or `RDFa`.
+    /// Values are encoded.
+    /// Attributes with names that are not valid in HTML are dropped.
+    /// The values of `class` and `rel` attributes are added to classes and
+    /// link types the element already has, such as from
+    /// [`block_quote_class`][CompileOptions::block_quote_class], instead of
+    /// adding the attribute twice.
+    ///
+    /// Attributes are supported on
+    /// [`BlockQuote`][Name::BlockQuote],
+    /// [`CodeFenced`][Name::CodeFenced] and
+    /// [`CodeIndented`][Name::CodeIndented] (on `<pre>`),
+    /// [`Emphasis`][Name::Emphasis],
+    /// [`GfmTable`][Name::GfmTable],
+    /// [`HeadingAtx`][Name::HeadingAtx],
+    /// [`HeadingSetext`][Name::HeadingSetext],
+    /// [`Image`][Name::Image],
+    /// [`Link`][Name::Link],
+    /// [`ListOrdered`][Name::ListOrdered],
+    /// [`ListUnordered`][Name::ListUnordered],
+    /// [`Paragraph`][Name::Paragraph] (when not in a tight list),
+    /// [`Strong`][Name::Strong], and
+    /// [`ThematicBreak`][Name::ThematicBreak].
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html, to_html_with_options, CompileOptions, LineEnding, Options};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, EventName, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `markdown-rs` uses `\n` by default:
+    /// // `markdown-rs` uses no extra attributes by default:
     /// assert_eq!(
-    ///     to_html("> a"),
-    ///     "<blockquote>\n<p>a</p>\n</blockquote>"
+    ///     to_html("# a"),
+    ///     "<h1>a</h1>"
     /// );
     ///
-    /// // Define `default_line_ending` to configure the default:
+    /// // Pass `attributes` to use some:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "> a",
+    ///         "# a",
     ///         &Options {
     ///             compile: CompileOptions {
-    ///               default_line_ending: LineEnding::CarriageReturnLineFeed,
+    ///               attributes: vec![(EventName::HeadingAtx, "itemprop".into(), "headline".into())],
     ///               ..CompileOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<blockquote>\r\n<p>a</p>\r\n</blockquote>"
+    ///     "<h1 itemprop=\"headline\">a</h1>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub default_line_ending: LineEnding,
+    pub attributes: Vec<(Name, String, String)>,
 
-    /// Textual label to use for the footnotes section.
-    ///
-    /// The default value is `"Footnotes"`.
-    /// Change it when the markdown is not in English.
+    /// Whether to mark paragraphs that are written right-to-left.
     ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass different attributes with the `gfm_footnote_label_attributes`
-    /// option.
+    /// The default is `false`, which means the direction of paragraphs is
+    /// left to the browser.
+    /// Pass `true` to add `dir="rtl"` to paragraphs where most letters are
+    /// from scripts written right-to-left, such as Arabic and Hebrew.
+    /// Other paragraphs are not marked.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"Footnotes"` is used by default:
-    /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
-    /// );
+    /// // `markdown-rs` does not mark paragraphs by default:
+    /// assert_eq!(to_html("مرحبا"), "<p>مرحبا</p>");
     ///
-    /// // Pass `gfm_footnote_label` to use something else:
+    /// // Pass `auto_text_direction` to mark right-to-left paragraphs:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "مرحبا",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label: Some("Notes de bas de page".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               auto_text_direction: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Notes de bas de page</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p dir=\"rtl\">مرحبا</p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label: Option<String>,
+    pub auto_text_direction: bool,
 
-    /// HTML tag name to use for the footnote label element.
+    /// Whether to not add `mailto:` to the URL of email autolinks.
     ///
-    /// The default value is `"h2"`.
-    /// Change it to match your document structure.
+    /// The default is `false`, which means `<a@b.c>` links to `mailto:a@b.c`.
+    /// Pass `true` to link to the bare address instead, such as when the
+    /// links are handled by something that expects addresses.
+    /// The text of the link is the bare address either way.
     ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass different attributes with the `gfm_footnote_label_attributes`
-    /// option.
+    /// This applies to email autolinks (`<a@b.c>`), not to GFM email
+    /// autolink literals.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"h2"` is used by default:
-    /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
-    /// );
+    /// // `markdown-rs` adds `mailto:` by default:
+    /// assert_eq!(to_html("<a@b.c>"), "<p><a href=\"mailto:a@b.c\">a@b.c</a></p>");
     ///
-    /// // Pass `gfm_footnote_label_tag_name` to use something else:
+    /// // Pass `autolink_email_without_mailto` to not add it:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "<a@b.c>",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label_tag_name: Some("h1".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               autolink_email_without_mailto: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h1 id=\"footnote-label\" class=\"sr-only\">Footnotes</h1>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p><a href=\"a@b.c\">a@b.c</a></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label_tag_name: Option<String>,
+    pub autolink_email_without_mailto: bool,
 
-    /// Attributes to use on the footnote label.
-    ///
-    /// The default value is `"class=\"sr-only\""`.
-    /// Change it to show the label and add other attributes.
-    ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass an empty string.
-    /// You can also add different attributes.
+    /// Class to use on block quotes.
     ///
-    /// > 👉 **Note**: `id="footnote-label"` is always added, because footnote
-    /// > calls use it with `aria-describedby` to provide an accessible label.
+    /// The default is `None`, which means no class is used.
+    /// Pass a value to add a `class` attribute to `<blockquote>` elements,
+    /// such as for theming.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"class=\"sr-only\""` is used by default:
-    /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
-    /// );
+    /// // `markdown-rs` uses no class by default:
+    /// assert_eq!(to_html("> a"), "<blockquote>\n<p>a</p>\n</blockquote>");
     ///
-    /// // Pass `gfm_footnote_label_attributes` to use something else:
+    /// // Pass `block_quote_class` to use one:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "> a",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label_attributes: Some("class=\"footnote-heading\"".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               block_quote_class: Some("note".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"footnote-heading\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<blockquote class=\"note\">\n<p>a</p>\n</blockquote>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label_attributes: Option<String>,
+    pub block_quote_class: Option<String>,
 
-    /// Textual label to describe the backreference back to footnote calls.
+    /// Class to use on code blocks (fenced and indented).
     ///
-    /// The default value is `"Back to content"`.
-    /// Change it when the markdown is not in English.
-    ///
-    /// This label is used in the `aria-label` attribute on each backreference
-    /// (the `↩` links).
-    /// It affects users of assistive technology.
+    /// The default is `None`, which means no class is used.
+    /// Pass a value to add a `class` attribute to the `<pre>` elements of
+    /// code blocks, such as for theming.
+    /// The `<code>` element of fenced code keeps its `language-*` class.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"Back to content"` is used by default:
+    /// // `markdown-rs` uses no class by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html("```js\na\n```"),
+    ///     "<pre><code class=\"language-js\">a\n</code></pre>"
     /// );
     ///
-    /// // Pass `gfm_footnote_back_label` to use something else:
+    /// // Pass `code_block_class` to use one:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "```js\na\n```",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_back_label: Some("Arrière".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               code_block_class: Some("highlight".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Arrière\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<pre class=\"highlight\"><code class=\"language-js\">a\n</code></pre>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_back_label: Option<String>,
+    pub code_block_class: Option<String>,
 
-    /// Prefix to use before the `id` attribute on footnotes to prevent them
-    /// from *clobbering*.
-    ///
-    /// The default is `"user-content-"`.
-    /// Pass `Some("".into())` for trusted markdown and when you are careful
-    /// with polyfilling.
-    /// You could pass a different prefix.
-    ///
-    /// DOM clobbering is this:
-    ///
-    /// ```html
-    /// <p id="x"></p>
-    /// <script>alert(x) // `x` now refers to the `p#x` DOM element</script>
-    /// ```
-    ///
-    /// The above example shows that elements are made available by browsers,
-    /// by their ID, on the `window` object.
-    /// This is a security risk because you might be expecting some other
-    /// variable at that place.
-    /// It can also break polyfills.
-    /// Using a prefix solves these problems.
+    /// Attribute to add to code blocks (fenced and indented), such as for
+    /// copy buttons.
+    ///
+    /// The default is `None`, which means no attribute is added.
+    /// Pass a name, such as `"data-code"`, to add an attribute with that
+    /// name to the `<pre>` elements of code blocks, so that scripts can find
+    /// them and attach a copy button.
+    /// Names that are not valid in HTML are ignored.
+    /// Its value is empty, unless
+    /// [`code_block_copy_value`][CompileOptions::code_block_copy_value] is
+    /// on.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"user-content-"` is used by default:
+    /// // `markdown-rs` adds no attribute by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html("```js\na\n```"),
+    ///     "<pre><code class=\"language-js\">a\n</code></pre>"
     /// );
     ///
-    /// // Pass `gfm_footnote_clobber_prefix` to use something else:
+    /// // Pass `code_block_copy_attribute` to add one:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "```js\na\n```",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_clobber_prefix: Some("".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               code_block_copy_attribute: Some("data-code".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#fn-a\" id=\"fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"fn-a\">\n<p>b <a href=\"#fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<pre data-code=\"\"><code class=\"language-js\">a\n</code></pre>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_clobber_prefix: Option<String>,
+    pub code_block_copy_attribute: Option<String>,
 
-    /// Whether or not GFM task list html `<input>` items are enabled.
+    /// Whether to use the code as the value of
+    /// [`code_block_copy_attribute`][CompileOptions::code_block_copy_attribute].
     ///
-    /// This determines whether or not the user of the browser is able
-    /// to click and toggle generated checkbox items. The default is false.
+    /// The default is `false`, which means the attribute is empty.
+    /// Pass `true` to use the code in the block, without the fences, as its
+    /// value, so that scripts can copy it as it is in the markdown instead
+    /// of as it is shown.
+    /// The value is encoded.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // With `gfm_task_list_item_checkable`, generated `<input type="checkbox" />`
-    /// // tags do not contain the attribute `disabled=""` and are thus toggleable by
-    /// // browser users.
+    /// // Pass `code_block_copy_value: true` to add the code:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "* [x] y.",
+    ///         "```js\na < b\nc\n```",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///                 gfm_task_list_item_checkable: true,
-    ///                 ..CompileOptions::gfm()
-    ///             }
+    ///               code_block_copy_attribute: Some("data-code".into()),
+    ///               code_block_copy_value: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<ul>\n<li><input type=\"checkbox\" checked=\"\" /> y.</li>\n</ul>"
+    ///     "<pre data-code=\"a &lt; b\nc\"><code class=\"language-js\">a &lt; b\nc\n</code></pre>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_task_list_item_checkable: bool,
+    pub code_block_copy_value: bool,
 
-    /// Whether to support the GFM tagfilter.
-    ///
-    /// This option does nothing if `allow_dangerous_html` is not turned on.
-    /// The default is `false`, which does not apply the GFM tagfilter to HTML.
-    /// Pass `true` for output that is a bit closer to GitHub’s actual output.
+    /// Whether to add line numbers to code blocks (fenced and indented).
     ///
-    /// The tagfilter is kinda weird and kinda useless.
-    /// The tag filter is a naïve attempt at XSS protection.
-    /// You should use a proper HTML sanitizing algorithm instead.
+    /// The default is `false`, which means no line numbers are added.
+    /// Pass `true` to add `data-line-start` and `data-line-count` attributes
+    /// to the `<pre>` elements of code blocks, such as for highlighters that
+    /// show line numbers.
+    /// `data-line-start` is the line in the source document where the content
+    /// of the code starts (so after the opening fence of fenced code), and
+    /// `data-line-count` is the number of lines of that content.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // With `allow_dangerous_html`, `markdown-rs` passes HTML through untouched:
+    /// // `markdown-rs` adds no line numbers by default:
+    /// assert_eq!(
+    ///     to_html("```js\na\nb\n```"),
+    ///     "<pre><code class=\"language-js\">a\nb\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `code_line_numbers: true` to add them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "```js\na\nb\n```",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               code_line_numbers: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<pre data-line-start=\"2\" data-line-count=\"2\"><code class=\"language-js\">a\nb\n</code></pre>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub code_line_numbers: bool,
+
+    /// Default line ending to use when compiling to HTML, for line endings not
+    /// in `value`.
+    ///
+    /// Generally, `markdown-rs` copies line endings (`\r`, `\n`, `\r\n`) in
+    /// the markdown document over to the compiled HTML.
+    /// In some cases, such as `> a`, CommonMark requires that extra line
+    /// endings are added: `<blockquote>\n<p>a</p>\n</blockquote>`.
+    ///
+    /// To create that line ending, the document is checked for the first line
+    /// ending that is used.
+    /// If there is no line ending, `default_line_ending` is used.
+    /// If that isn’t configured, `\n` is used.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, LineEnding, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses `\n` by default:
+    /// assert_eq!(
+    ///     to_html("> a"),
+    ///     "<blockquote>\n<p>a</p>\n</blockquote>"
+    /// );
+    ///
+    /// // Define `default_line_ending` to configure the default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "> a",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               default_line_ending: LineEnding::CarriageReturnLineFeed,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<blockquote>\r\n<p>a</p>\r\n</blockquote>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub default_line_ending: LineEnding,
+
+    /// URL of links that are not external.
+    ///
+    /// This option does nothing if `external_links_target_blank` is not
+    /// turned on.
+    /// The default is `None`, which means that all web URLs (those with an
+    /// `http` or `https` protocol, such as `https://example.com`, or
+    /// protocol-relative ones, such as `//example.com`) are external.
+    /// Pass the URL of your own site, such as `"https://example.com"`, to
+    /// treat links to it as internal.
+    /// The protocol and host are compared case-insensitively, so
+    /// `https://example.com.org` is still external.
+    /// If the base has a path, such as `"https://example.com/docs"`, links
+    /// must be in that path (`https://example.com/docs/a`) to be internal.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // Pass `external_link_base` to treat some absolute URLs as internal:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://example.com/a), [b](https://example.org/b)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               external_links_target_blank: true,
+    ///               external_link_base: Some("https://example.com".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://example.com/a\">a</a>, <a href=\"https://example.org/b\" target=\"_blank\" rel=\"noopener\">b</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub external_link_base: Option<String>,
+
+    /// Value of the `rel` attribute on external links.
+    ///
+    /// This option does nothing if `external_links_target_blank` is not
+    /// turned on.
+    /// The default is `"noopener"`, which prevents the opened page from
+    /// accessing the page that opened it.
+    /// Pass a different value, such as `"noopener noreferrer"`, to change it,
+    /// or pass `Some("".into())` to not add a `rel` attribute.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // Pass `external_link_rel` to use a different `rel`:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://example.com)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               external_links_target_blank: true,
+    ///               external_link_rel: Some("noopener noreferrer".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://example.com\" target=\"_blank\" rel=\"noopener noreferrer\">a</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub external_link_rel: Option<String>,
+
+    /// Whether to open external links in a new tab.
+    ///
+    /// The default is `false`, which does not add any attributes to links.
+    /// Pass `true` to add `target="_blank"` and a `rel` (see
+    /// `external_link_rel`) to links (resources and references) whose
+    /// destination is external (see `external_link_base`).
+    /// Only web URLs can be external: relative links (such as `./a.html`),
+    /// fragments (such as `#a`), and other protocols (such as `mailto:` or
+    /// `tel:`) are never external.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not add `target` by default:
+    /// assert_eq!(
+    ///     to_html("[a](https://example.com)"),
+    ///     "<p><a href=\"https://example.com\">a</a></p>"
+    /// );
+    ///
+    /// // Pass `external_links_target_blank: true` to open external links in a new tab:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://example.com), [b](#b)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               external_links_target_blank: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://example.com\" target=\"_blank\" rel=\"noopener\">a</a>, <a href=\"#b\">b</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub external_links_target_blank: bool,
+
+    /// Textual label to use for the footnotes section.
+    ///
+    /// The default value is `"Footnotes"`.
+    /// Change it when the markdown is not in English.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass different attributes with the `gfm_footnote_label_attributes`
+    /// option.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"Footnotes"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label: Some("Notes de bas de page".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Notes de bas de page</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label: Option<String>,
+
+    /// HTML tag name to use for the footnote label element.
+    ///
+    /// The default value is `"h2"`.
+    /// Change it to match your document structure.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass different attributes with the `gfm_footnote_label_attributes`
+    /// option.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"h2"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label_tag_name` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label_tag_name: Some("h1".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h1 id=\"footnote-label\" class=\"sr-only\">Footnotes</h1>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label_tag_name: Option<String>,
+
+    /// Attributes to use on the footnote label.
+    ///
+    /// The default value is `"class=\"sr-only\""`.
+    /// Change it to show the label and add other attributes.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass an empty string.
+    /// You can also add different attributes.
+    ///
+    /// > 👉 **Note**: `id="footnote-label"` is always added, because footnote
+    /// > calls use it with `aria-describedby` to provide an accessible label.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"class=\"sr-only\""` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label_attributes` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label_attributes: Some("class=\"footnote-heading\"".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"footnote-heading\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label_attributes: Option<String>,
+
+    /// Textual label to describe the backreference back to footnote calls.
+    ///
+    /// The default value is `"Back to content"`.
+    /// Change it when the markdown is not in English.
+    ///
+    /// This label is used in the `aria-label` attribute on each backreference
+    /// (the `↩` links).
+    /// It affects users of assistive technology.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"Back to content"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_back_label` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_back_label: Some("Arrière".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Arrière\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_back_label: Option<String>,
+
+    /// Prefix to use before the `id` attribute on footnotes to prevent them
+    /// from *clobbering*.
+    ///
+    /// The default is `"user-content-"`.
+    /// Pass `Some("".into())` for trusted markdown and when you are careful
+    /// with polyfilling.
+    /// You could pass a different prefix.
+    ///
+    /// DOM clobbering is this:
+    ///
+    /// ```html
+    /// <p id="x"></p>
+    /// <script>alert(x) // `x` now refers to the `p#x` DOM element</script>
+    /// ```
+    ///
+    /// The above example shows that elements are made available by browsers,
+    /// by their ID, on the `window` object.
+    /// This is a security risk because you might be expecting some other
+    /// variable at that place.
+    /// It can also break polyfills.
+    /// Using a prefix solves these problems.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"user-content-"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_clobber_prefix` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_clobber_prefix: Some("".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#fn-a\" id=\"fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"fn-a\">\n<p>b <a href=\"#fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_clobber_prefix: Option<String>,
+
+    /// Class to use on GFM tables.
+    ///
+    /// The default is `None`, which means no class is used.
+    /// Pass a value to add a `class` attribute to `<table>` elements, such as
+    /// for theming.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses no class by default:
+    /// assert_eq!(
+    ///     to_html_with_options("| a |\n| - |", &Options::gfm())?,
+    ///     "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n</table>"
+    /// );
+    ///
+    /// // Pass `gfm_table_class` to use one:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "| a |\n| - |",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_table_class: Some("data".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<table class=\"data\">\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n</table>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_table_class: Option<String>,
+
+    /// Whether to add a `<colgroup>` to tables.
+    ///
+    /// The default is `false`, which only adds `align` attributes to cells.
+    /// Pass `true` to also add a `<colgroup>` at the start of tables, with a
+    /// `<col>` for each column, which gets a `text-align` style when the
+    /// column is aligned in the delimiter row.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not add a `<colgroup>` by default:
+    /// assert_eq!(
+    ///     to_html_with_options("| a | b |\n| - | -: |", &Options::gfm())?,
+    ///     "<table>\n<thead>\n<tr>\n<th>a</th>\n<th align=\"right\">b</th>\n</tr>\n</thead>\n</table>"
+    /// );
+    ///
+    /// // Pass `gfm_table_colgroup: true` to add one:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "| a | b |\n| - | -: |",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_table_colgroup: true,
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<table>\n<colgroup>\n<col />\n<col style=\"text-align: right\" />\n</colgroup>\n<thead>\n<tr>\n<th>a</th>\n<th align=\"right\">b</th>\n</tr>\n</thead>\n</table>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_table_colgroup: bool,
+
+    /// Whether or not GFM task list html `<input>` items are enabled.
+    ///
+    /// This determines whether or not the user of the browser is able
+    /// to click and toggle generated checkbox items. The default is false.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // With `gfm_task_list_item_checkable`, generated `<input type="checkbox" />`
+    /// // tags do not contain the attribute `disabled=""` and are thus toggleable by
+    /// // browser users.
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "* [x] y.",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///                 gfm_task_list_item_checkable: true,
+    ///                 ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<ul>\n<li><input type=\"checkbox\" checked=\"\" /> y.</li>\n</ul>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_task_list_item_checkable: bool,
+
+    /// Whether to support the GFM tagfilter.
+    ///
+    /// This option does nothing if `allow_dangerous_html` is not turned on.
+    /// The default is `false`, which does not apply the GFM tagfilter to HTML.
+    /// Pass `true` for output that is a bit closer to GitHub’s actual output.
+    ///
+    /// The tagfilter is kinda weird and kinda useless.
+    /// The tag filter is a naïve attempt at XSS protection.
+    /// You should use a proper HTML sanitizing algorithm instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // With `allow_dangerous_html`, `markdown-rs` passes HTML through untouched:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<iframe>",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<iframe>"
+    /// );
+    ///
+    /// // Pass `gfm_tagfilter: true` to make some of that safe:
     /// assert_eq!(
     ///     to_html_with_options(
     ///         "<iframe>",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               gfm_tagfilter: true,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "&lt;iframe>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## References
+    ///
+    /// *   [*§ 6.1 Disallowed Raw HTML (extension)* in GFM](https://github.github.com/gfm/#disallowed-raw-html-extension-)
+    /// *   [`cmark-gfm#extensions/tagfilter.c`](https://github.com/github/cmark-gfm/blob/master/extensions/tagfilter.c)
+    pub gfm_tagfilter: bool,
+
+    /// Number to shift the rank of headings (atx and setext) by.
+    ///
+    /// The default is `0`, which keeps headings as they are.
+    /// Pass a positive number to nest a document under a section, such as
+    /// `1` to turn `# a` into `<h2>`, or a negative number to raise headings.
+    /// Resulting ranks are clamped to `1` through `6`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps the rank of headings by default:
+    /// assert_eq!(
+    ///     to_html("# a\n\nb\n-"),
+    ///     "<h1>a</h1>\n<h2>b</h2>"
+    /// );
+    ///
+    /// // Pass `heading_offset` to shift them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# a\n\nb\n-",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_offset: 1,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h2>a</h2>\n<h3>b</h3>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_offset: i8,
+
+    /// Whether to add `decoding="async"` to images.
+    ///
+    /// The default is `false`, which does not add a `decoding` attribute.
+    /// Pass `true` to let browsers decode images off the main thread.
+    /// This speeds up rendering the rest of the page.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not add `decoding` by default:
+    /// assert_eq!(
+    ///     to_html("![a](b.png)"),
+    ///     "<p><img src=\"b.png\" alt=\"a\" /></p>"
+    /// );
+    ///
+    /// // Pass `image_async_decoding: true` to add it:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![a](b.png)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               image_async_decoding: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><img src=\"b.png\" alt=\"a\" decoding=\"async\" /></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub image_async_decoding: bool,
+
+    /// Whether to add `loading="lazy"` to images.
+    ///
+    /// The default is `false`, which does not add a `loading` attribute.
+    /// Pass `true` to let browsers defer loading images until they are almost in view.
+    /// This speeds up loading pages with many images, but images near the top of
+    /// the page may show up later.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not add `loading` by default:
+    /// assert_eq!(
+    ///     to_html("![a](b.png)"),
+    ///     "<p><img src=\"b.png\" alt=\"a\" /></p>"
+    /// );
+    ///
+    /// // Pass `image_lazy_loading: true` to add it:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![a](b.png)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               image_lazy_loading: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><img src=\"b.png\" alt=\"a\" loading=\"lazy\" /></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub image_lazy_loading: bool,
+
+    /// Whether to leave out the `alt` attribute of images without alt text.
+    ///
+    /// The default is `false`, which adds `alt=""` to images without alt
+    /// text, such as `![](a.png)`.
+    /// That marks them as decorative, which is good for accessibility.
+    /// Pass `true` to not add an `alt` attribute to them.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` adds an empty `alt` by default:
+    /// assert_eq!(
+    ///     to_html("![](a.png)"),
+    ///     "<p><img src=\"a.png\" alt=\"\" /></p>"
+    /// );
+    ///
+    /// // Pass `image_omit_empty_alt: true` to leave it out:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![](a.png)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               image_omit_empty_alt: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><img src=\"a.png\" /></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub image_omit_empty_alt: bool,
+
+    /// Function to resolve the sources of images with.
+    ///
+    /// The default is `None`, which uses the destination of each image as
+    /// its `src`.
+    /// Pass a function to call it with the destination of each image (after
+    /// character escapes and character references in it are decoded).
+    /// When it returns `Some`, that is used as the `src` instead.
+    /// This can be used to inline small images as data URIs, for
+    /// self-contained HTML, while reading files is up to you.
+    ///
+    /// > 👉 **Note**: what the function returns is trusted, so it is not
+    /// > checked for dangerous protocols, but it is still encoded.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses the destination as the source by default:
+    /// assert_eq!(
+    ///     to_html("![a](b.png)"),
+    ///     "<p><img src=\"b.png\" alt=\"a\" /></p>"
+    /// );
+    ///
+    /// // Pass `image_src_resolver` to change it:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![a](b.png)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               image_src_resolver: Some(|destination| {
+    ///                   if destination == "b.png" {
+    ///                       Some("data:image/png;base64,iVBORw0KGgo=".into())
+    ///                   } else {
+    ///                       None
+    ///                   }
+    ///               }),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><img src=\"data:image/png;base64,iVBORw0KGgo=\" alt=\"a\" /></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub image_src_resolver: Option<ImageSrcResolver>,
+
+    /// Whether to turn paragraphs with only an image into figures.
+    ///
+    /// The default is `false`, which follows `CommonMark`: an image alone in
+    /// a paragraph is compiled to an `<img>` in a `<p>`.
+    /// Pass `true` to instead compile such paragraphs to `<figure>`, with the
+    /// title of the image, if any, as a `<figcaption>`.
+    /// Images with other things around them in a paragraph are not affected.
+    /// Paragraphs in tight lists, which are not wrapped in `<p>`, are not
+    /// affected either.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` compiles images in paragraphs by default:
+    /// assert_eq!(
+    ///     to_html("![a](b \"c\")"),
+    ///     "<p><img src=\"b\" alt=\"a\" title=\"c\" /></p>"
+    /// );
+    ///
+    /// // Pass `images_as_figures: true` to compile figures instead:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![a](b \"c\")",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               images_as_figures: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<figure><img src=\"b\" alt=\"a\" title=\"c\" /><figcaption>c</figcaption></figure>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub images_as_figures: bool,
+
+    /// Function to get extra attributes for links with.
+    ///
+    /// The default is `None`, which does not add extra attributes.
+    /// Pass a function to call it with the destination of each link
+    /// (resources and references, not autolinks), after character escapes
+    /// and character references in it are decoded.
+    /// The attributes it returns, as names and values, are added to the link.
+    /// Values are encoded, and attributes with names that are not valid in
+    /// HTML are dropped.
+    /// A `rel` attribute is merged with the one added by
+    /// [`external_links_target_blank`][CompileOptions::external_links_target_blank].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not add extra attributes to links by default:
+    /// assert_eq!(
+    ///     to_html("[a](b.pdf)"),
+    ///     "<p><a href=\"b.pdf\">a</a></p>"
+    /// );
+    ///
+    /// // Pass `link_attributes` to add some:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](b.pdf)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               link_attributes: Some(|destination| {
+    ///                   if destination.ends_with(".pdf") {
+    ///                       vec![("type".into(), "application/pdf".into())]
+    ///                   } else {
+    ///                       vec![]
+    ///                   }
+    ///               }),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"b.pdf\" type=\"application/pdf\">a</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub link_attributes: Option<LinkAttributes>,
+
+    /// Whether to indent block-level elements.
+    ///
+    /// The default is `false`, which puts each block-level element on its
+    /// own line, without indentation.
+    /// Pass `true` to also indent elements in block quotes, lists, list
+    /// items, tables, details, and the footnote section with two spaces per
+    /// level, which is easier to read, and gives more readable diffs.
+    /// Inline content and what is in `<pre>` and raw HTML is not changed.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not indent by default:
+    /// assert_eq!(
+    ///     to_html("> * a"),
+    ///     "<blockquote>\n<ul>\n<li>a</li>\n</ul>\n</blockquote>"
+    /// );
+    ///
+    /// // Pass `pretty: true` to indent:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "> * a",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               pretty: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<blockquote>\n  <ul>\n    <li>a</li>\n  </ul>\n</blockquote>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub pretty: bool,
+
+    /// Whether to encode internationalized hosts in URLs with punycode.
+    ///
+    /// The default is `false`, which follows `CommonMark`: non-ASCII
+    /// characters in URLs, including in their hosts, are percent-encoded
+    /// (already percent-encoded sequences are kept as is), which browsers
+    /// understand.
+    /// Pass `true` to instead turn labels of hosts with non-ASCII characters
+    /// into punycode (`xn--…`), such as for tools that do not decode
+    /// percent-encoded hosts.
+    /// This applies to links, images, and autolinks (including GFM autolink
+    /// literals).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` percent-encodes hosts by default:
+    /// assert_eq!(
+    ///     to_html("[a](https://münchen.de/straße)"),
+    ///     "<p><a href=\"https://m%C3%BCnchen.de/stra%C3%9Fe\">a</a></p>"
+    /// );
+    ///
+    /// // Pass `punycode_hosts: true` to use punycode for them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://münchen.de/straße)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               punycode_hosts: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://xn--mnchen-3ya.de/stra%C3%9Fe\">a</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub punycode_hosts: bool,
+
+    /// Whether to turn soft line endings in paragraphs into hard breaks.
+    ///
+    /// The default is `false`, which follows `CommonMark`: line endings in
+    /// paragraphs are kept as-is, which browsers display as a space.
+    /// Pass `true` to render every line ending in a paragraph as `<br />`,
+    /// like how comments on `github.com` work.
+    ///
+    /// Line endings in code (text), HTML (text), and code (flow) are not
+    /// affected.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps soft line endings by default:
+    /// assert_eq!(
+    ///     to_html("a\nb"),
+    ///     "<p>a\nb</p>"
+    /// );
+    ///
+    /// // Pass `soft_break_as_hard_break: true` to turn them into hard breaks:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a\nb",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               soft_break_as_hard_break: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a<br />\nb</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub soft_break_as_hard_break: bool,
+
+    /// Whether to add source positions to elements.
+    ///
+    /// The default is `false`, which means no positions are added.
+    /// Pass `true` to add a `data-sourcepos` attribute to block quotes,
+    /// code (on `<pre>`), headings, lists, list items, paragraphs (when not
+    /// in a tight list), tables, and thematic breaks, such as for editors
+    /// that want to map elements back to the source.
+    ///
+    /// The value is in the form `line:column-line:column`, of where the
+    /// construct starts and where it ends, like `cmark`: the end points to the
+    /// last character of the construct.
+    /// Trailing blank lines are not included.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` adds no positions by default:
+    /// assert_eq!(
+    ///     to_html("- a"),
+    ///     "<ul>\n<li>a</li>\n</ul>"
+    /// );
+    ///
+    /// // Pass `source_positions: true` to add them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "- a",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               source_positions: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<ul data-sourcepos=\"1:1-1:3\">\n<li data-sourcepos=\"1:1-1:3\">a</li>\n</ul>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub source_positions: bool,
+
+    /// Element to use for GFM strikethrough.
+    ///
+    /// The default is [`StrikethroughElement::Del`][], which is what GitHub
+    /// uses: `<del>` represents removals from a document.
+    /// Pass [`StrikethroughElement::S`][] to use `<s>`, which represents
+    /// things that are no longer accurate or relevant.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, StrikethroughElement};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses `<del>` by default:
+    /// assert_eq!(
+    ///     to_html_with_options("~~a~~", &Options::gfm())?,
+    ///     "<p><del>a</del></p>"
+    /// );
+    ///
+    /// // Pass `strikethrough_element: StrikethroughElement::S` to use `<s>`:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "~~a~~",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               strikethrough_element: StrikethroughElement::S,
+    ///               ..CompileOptions::gfm()
+    ///             },
+    ///             ..Options::gfm()
+    ///         }
+    ///     )?,
+    ///     "<p><s>a</s></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub strikethrough_element: StrikethroughElement,
+
+    /// Whether to remove `style` attributes from HTML.
+    ///
+    /// This option does nothing if `allow_dangerous_html` is not turned on.
+    /// The default is `false`, which passes HTML through untouched.
+    /// Pass `true` to remove `style` attributes (and the whitespace before
+    /// them) from tags in HTML, to prevent CSS-based attacks.
+    /// Other attributes are kept.
+    ///
+    /// Like the tagfilter, this is a naïve attempt at sanitizing.
+    /// You should use a proper HTML sanitizing algorithm instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // With `allow_dangerous_html`, `markdown-rs` passes HTML through untouched:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<div style=\"color: red\" class=\"a\">",
+    ///         &Options {
     ///             compile: CompileOptions {
     ///               allow_dangerous_html: true,
     ///               ..CompileOptions::default()
-    ///             }
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<iframe>"
+    ///     "<div style=\"color: red\" class=\"a\">"
     /// );
     ///
-    /// // Pass `gfm_tagfilter: true` to make some of that safe:
+    /// // Pass `strip_style_attributes: true` to remove `style` attributes:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "<iframe>",
+    ///         "<div style=\"color: red\" class=\"a\">",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
     ///               allow_dangerous_html: true,
-    ///               gfm_tagfilter: true,
+    ///               strip_style_attributes: true,
     ///               ..CompileOptions::default()
-    ///             }
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "&lt;iframe>"
+    ///     "<div class=\"a\">"
     /// );
     /// # Ok(())
     /// # }
     /// ```
+    pub strip_style_attributes: bool,
+
+    /// Class to use on thematic breaks.
     ///
-    /// ## References
+    /// The default is `None`, which means no class is used.
+    /// Pass a value to add a `class` attribute to `<hr />` elements (or the
+    /// element from `thematic_break_tag_name`), such as for theming.
     ///
-    /// *   [*§ 6.1 Disallowed Raw HTML (extension)* in GFM](https://github.github.com/gfm/#disallowed-raw-html-extension-)
-    /// *   [`cmark-gfm#extensions/tagfilter.c`](https://github.com/github/cmark-gfm/blob/master/extensions/tagfilter.c)
-    pub gfm_tagfilter: bool,
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses no class by default:
+    /// assert_eq!(to_html("***"), "<hr />");
+    ///
+    /// // Pass `thematic_break_class` to use one:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "***",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               thematic_break_class: Some("divider".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<hr class=\"divider\" />"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub thematic_break_class: Option<String>,
+
+    /// HTML tag name to use for thematic breaks.
+    ///
+    /// The default is `None`, which means `hr` is used: `<hr />`.
+    /// Pass a different name, such as `"div"`, to use that element instead,
+    /// which is then opened and closed: `<div></div>`.
+    /// The name is not checked, so don’t pass names from untrusted sources.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses `<hr />` by default:
+    /// assert_eq!(to_html("***"), "<hr />");
+    ///
+    /// // Pass `thematic_break_tag_name` to use a different element:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "***",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               thematic_break_class: Some("divider".into()),
+    ///               thematic_break_tag_name: Some("div".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<div class=\"divider\"></div>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub thematic_break_tag_name: Option<String>,
 }
 
 impl CompileOptions {
@@ -962,6 +2417,11 @@ impl CompileOptions {
 /// # }
 /// ```
 #[allow(clippy::struct_excessive_bools)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "camelCase")
+)]
 pub struct ParseOptions {
     // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
     /// Which constructs to enable and disable.
@@ -1002,6 +2462,46 @@ pub struct ParseOptions {
     /// ```
     pub constructs: Constructs,
 
+    /// Whether to warn about unmatched emphasis and strong sequences.
+    ///
+    /// The default is `false`, which means no warnings are made for them.
+    /// Pass `true` to get a [`Warning`][crate::Warning] from
+    /// [`to_html_with_report()`][crate::to_html_with_report()] for each
+    /// sequence of asterisks or underscores that could open or close, but
+    /// was not matched (completely), such as the `*` in `*a` or `a*`, or
+    /// the first `*` in `**a*`, which is shown as text.
+    ///
+    /// > 👉 **Note**: this does not change the HTML or mdast.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_report, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not warn about them by default:
+    /// let (html, warnings) = to_html_with_report("*a", &Options::default())?;
+    /// assert_eq!(html, "<p>*a</p>");
+    /// assert!(warnings.is_empty());
+    ///
+    /// // Pass `attention_warnings: true` to warn about them:
+    /// let (html, warnings) = to_html_with_report(
+    ///     "*a",
+    ///     &Options {
+    ///         parse: ParseOptions {
+    ///           attention_warnings: true,
+    ///           ..ParseOptions::default()
+    ///         },
+    ///         ..Options::default()
+    ///     }
+    /// )?;
+    /// assert_eq!(html, "<p>*a</p>");
+    /// assert_eq!(warnings[0].message, "Unmatched `*`, expected a closing sequence");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub attention_warnings: bool,
+
     /// Whether to support GFM strikethrough with a single tilde
     ///
     /// This option does nothing if `gfm_strikethrough` is not turned on in
@@ -1016,43 +2516,169 @@ pub struct ParseOptions {
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports single tildes by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "~a~",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::gfm(),
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><del>a</del></p>"
+    /// );
+    ///
+    /// // Pass `gfm_strikethrough_single_tilde: false` to turn that off:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "~a~",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::gfm(),
+    ///               gfm_strikethrough_single_tilde: false,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>~a~</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_strikethrough_single_tilde: bool,
+
+    /// Whether to keep trailing whitespace in the text of headings (atx).
+    ///
+    /// The default is `false`, which, as `CommonMark` requires, does not
+    /// include whitespace between the text of a heading (atx) and its closing
+    /// sequence or the end of the line in that text.
+    /// Pass `true` to include that whitespace in
+    /// [`HeadingAtxText`][crate::EventName::HeadingAtxText] events, such as
+    /// for a formatter that wants to stay faithful to the source.
+    ///
+    /// > 👉 **Note**: this does not change the HTML or mdast, as whitespace
+    /// > at the end of text is not shown.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{EventName, Markdown, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not include trailing whitespace by default:
+    /// let markdown = Markdown::new("# a  #", &ParseOptions::default())?;
+    /// let text = markdown.spans(&[EventName::HeadingAtxText]).next().unwrap();
+    /// assert_eq!(text.as_str(), "a");
+    ///
+    /// // Pass `heading_atx_trailing_whitespace: true` to include it:
+    /// let options = ParseOptions {
+    ///     heading_atx_trailing_whitespace: true,
+    ///     ..ParseOptions::default()
+    /// };
+    /// let markdown = Markdown::new("# a  #", &options)?;
+    /// let text = markdown.spans(&[EventName::HeadingAtxText]).next().unwrap();
+    /// assert_eq!(text.as_str(), "a  ");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_atx_trailing_whitespace: bool,
+
+    /// Prefix of lines that are comments.
+    ///
+    /// Some toolchains strip lines that start with a certain prefix, such as
+    /// `%` or `//`, before rendering.
+    /// When this is set, lines in flow that start with `prefix` form comment
+    /// lines, which are not part of the output.
+    /// The prefix must be at the start of the line (after container markers,
+    /// such as `>` for block quotes), not indented or in the middle of a line.
+    /// Comment lines interrupt paragraphs.
+    ///
+    /// The default is `None`, which means comment lines are not supported.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `markdown-rs` supports single tildes by default:
+    /// // `markdown-rs` does not support comment lines by default:
+    /// assert_eq!(to_html("% hidden\nvisible"), "<p>% hidden\nvisible</p>");
+    ///
+    /// // Pass `line_comment_prefix` to turn them on:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "~a~",
+    ///         "% hidden\nvisible",
     ///         &Options {
     ///             parse: ParseOptions {
-    ///               constructs: Constructs::gfm(),
+    ///               line_comment_prefix: Some("%".into()),
     ///               ..ParseOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><del>a</del></p>"
+    ///     "<p>visible</p>"
     /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub line_comment_prefix: Option<String>,
+
+    /// Whether to treat backslashes in destinations of links, images, and
+    /// definitions as literal characters.
     ///
-    /// // Pass `gfm_strikethrough_single_tilde: false` to turn that off:
+    /// Authors sometimes paste Windows-style paths, such as `C:\a\b`, as
+    /// destinations, but in markdown a backslash before ASCII punctuation is
+    /// a character escape.
+    /// When this is on, backslashes in destinations are never character
+    /// escapes, and they do not escape parens or angle brackets either.
+    /// Character references still work.
+    ///
+    /// > 👉 **Note**: a path with a drive letter, such as `C:\a\b`, looks like
+    /// > a URL with the protocol `C:`, which is not safe, so it is dropped
+    /// > when compiling to HTML, unless
+    /// > [`allow_dangerous_protocol`][CompileOptions::allow_dangerous_protocol]
+    /// > is on.
+    /// > Relative paths, such as `..\a\b`, work either way.
+    ///
+    /// The default is `false`, which is how `CommonMark` works.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` follows CommonMark by default:
+    /// assert_eq!(
+    ///     to_html(r"[a](..\\docs\*.md)"),
+    ///     "<p><a href=\"..%5Cdocs*.md\">a</a></p>"
+    /// );
+    ///
+    /// // Pass `literal_backslash_in_destinations` to keep backslashes:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "~a~",
+    ///         r"[a](..\\docs\*.md)",
     ///         &Options {
     ///             parse: ParseOptions {
-    ///               constructs: Constructs::gfm(),
-    ///               gfm_strikethrough_single_tilde: false,
+    ///               literal_backslash_in_destinations: true,
     ///               ..ParseOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p>~a~</p>"
+    ///     "<p><a href=\"..%5C%5Cdocs%5C*.md\">a</a></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_strikethrough_single_tilde: bool,
+    pub literal_backslash_in_destinations: bool,
 
     /// Whether to support math (text) with a single dollar
     ///
@@ -1113,6 +2739,41 @@ pub struct ParseOptions {
     /// ```
     pub math_text_single_dollar: bool,
 
+    /// Maximum size of the input, in bytes.
+    ///
+    /// Parsing markdown takes time and memory proportional to the size of
+    /// the input.
+    /// Services that turn untrusted markdown into HTML can pass a maximum,
+    /// so that too large inputs error before they are parsed.
+    ///
+    /// The default is `None`, which means there is no maximum.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    /// let options = Options {
+    ///     parse: ParseOptions {
+    ///       max_input_bytes: Some(8),
+    ///       ..ParseOptions::default()
+    ///     },
+    ///     ..Options::default()
+    /// };
+    ///
+    /// // Inputs within the maximum work:
+    /// assert_eq!(to_html_with_options("*a*", &options)?, "<p><em>a</em></p>");
+    ///
+    /// // Larger inputs error:
+    /// assert_eq!(
+    ///     to_html_with_options("*alpha bravo*", &options),
+    ///     Err("1:1: Unexpected input of `13` bytes, expected at most `8` bytes (max-input-bytes)".into())
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub max_input_bytes: Option<usize>,
+
     /// Function to parse expressions with.
     ///
     /// This function can be used to add support for arbitrary programming
@@ -1123,6 +2784,7 @@ pub struct ParseOptions {
     ///
     /// For an example that adds support for JavaScript with SWC, see
     /// `tests/test_utils/mod.rs`.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub mdx_expression_parse: Option<Box<MdxExpressionParse>>,
 
     /// Function to parse ESM with.
@@ -1139,7 +2801,52 @@ pub struct ParseOptions {
     ///
     /// For an example that adds support for JavaScript with SWC, see
     /// `tests/test_utils/mod.rs`.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub mdx_esm_parse: Option<Box<MdxEsmParse>>,
+
+    /// Whether labels of definitions and references can span multiple lines.
+    ///
+    /// The default is `true`, which is what `CommonMark` does:
+    /// a line ending in a label (such as `[a\nb]`) is fine.
+    /// Pass `false` to only allow labels on a single line, so that a stray
+    /// `[` cannot turn several lines into a reference by accident.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports labels that span multiple lines by default:
+    /// assert_eq!(to_html("[a\nb]: c\n\n[a b]"), "<p><a href=\"c\">a b</a></p>");
+    ///
+    /// // Pass `multiline_labels: false` to turn that off:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a\nb]: c\n\n[a b]",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               multiline_labels: false,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>[a\nb]: c</p>\n<p>[a b]</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub multiline_labels: bool,
+
+    /// Custom constructs in text.
+    ///
+    /// These are tried in order, before the built-in constructs, at their
+    /// markers.
+    ///
+    /// For an example, see [`TextConstruct`][].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub text_constructs: Vec<Box<dyn TextConstruct>>,
     // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
 }
 
@@ -1147,11 +2854,22 @@ impl fmt::Debug for ParseOptions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ParseOptions")
             .field("constructs", &self.constructs)
+            .field("attention_warnings", &self.attention_warnings)
             .field(
                 "gfm_strikethrough_single_tilde",
                 &self.gfm_strikethrough_single_tilde,
             )
+            .field(
+                "heading_atx_trailing_whitespace",
+                &self.heading_atx_trailing_whitespace,
+            )
+            .field("line_comment_prefix", &self.line_comment_prefix)
+            .field(
+                "literal_backslash_in_destinations",
+                &self.literal_backslash_in_destinations,
+            )
             .field("math_text_single_dollar", &self.math_text_single_dollar)
+            .field("max_input_bytes", &self.max_input_bytes)
             .field(
                 "mdx_expression_parse",
                 &self.mdx_expression_parse.as_ref().map(|_d| "[Function]"),
@@ -1160,6 +2878,15 @@ impl fmt::Debug for ParseOptions {
                 "mdx_esm_parse",
                 &self.mdx_esm_parse.as_ref().map(|_d| "[Function]"),
             )
+            .field("multiline_labels", &self.multiline_labels)
+            .field(
+                "text_constructs",
+                &self
+                    .text_constructs
+                    .iter()
+                    .map(|_d| "[TextConstruct]")
+                    .collect::<Vec<_>>(),
+            )
             .finish()
     }
 }
@@ -1169,10 +2896,17 @@ impl Default for ParseOptions {
     fn default() -> Self {
         Self {
             constructs: Constructs::default(),
+            attention_warnings: false,
             gfm_strikethrough_single_tilde: true,
+            heading_atx_trailing_whitespace: false,
+            line_comment_prefix: None,
+            literal_backslash_in_destinations: false,
             math_text_single_dollar: true,
+            max_input_bytes: None,
             mdx_expression_parse: None,
             mdx_esm_parse: None,
+            multiline_labels: true,
+            text_constructs: vec![],
         }
     }
 }
@@ -1220,6 +2954,333 @@ impl ParseOptions {
     }
 }
 
+/// Signature of a function that gets extra attributes for a link.
+///
+/// Can be passed as `link_attributes` in [`CompileOptions`][].
+/// Gets the destination of the link, and returns the names and values of
+/// attributes.
+pub type LinkAttributes = fn(&str) -> Vec<(String, String)>;
+
+/// Signature of a function that gets the source of an image.
+///
+/// Can be passed as `image_src_resolver` in [`CompileOptions`][].
+/// Gets the destination of the image, and returns the source to use instead,
+/// if any.
+pub type ImageSrcResolver = fn(&str) -> Option<String>;
+
+/// Element to use for GFM strikethrough.
+///
+/// Used by [`CompileOptions`][].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum StrikethroughElement {
+    /// `<del>`.
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <del>a</del>
+    /// ```
+    Del,
+    /// `<s>`.
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <s>a</s>
+    /// ```
+    S,
+}
+
+impl Default for StrikethroughElement {
+    /// `<del>`, like GitHub.
+    fn default() -> Self {
+        Self::Del
+    }
+}
+
+impl StrikethroughElement {
+    /// Tag name of the element.
+    pub fn tag_name(&self) -> &'static str {
+        match self {
+            Self::Del => "del",
+            Self::S => "s",
+        }
+    }
+}
+
+/// Style of headings.
+///
+/// Used by [`ToMarkdownOptions`][].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum HeadingStyle {
+    /// Heading (atx).
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// # a
+    /// ```
+    Atx,
+    /// Heading (setext).
+    ///
+    /// Only ranks 1 and 2 can be setext headings.
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// a
+    /// =
+    /// ```
+    Setext,
+    /// Keep headings as they are.
+    Preserve,
+}
+
+impl Default for HeadingStyle {
+    /// Keep headings as they are.
+    fn default() -> Self {
+        Self::Preserve
+    }
+}
+
+/// Style of thematic breaks.
+///
+/// Used by [`ToMarkdownOptions`][].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum ThematicBreakStyle {
+    /// Thematic break with asterisks.
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// ***
+    /// ```
+    Asterisk,
+    /// Thematic break with dashes.
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// ---
+    /// ```
+    Dash,
+    /// Thematic break with underscores.
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// ___
+    /// ```
+    Underscore,
+    /// Keep thematic breaks as they are.
+    Preserve,
+}
+
+impl ThematicBreakStyle {
+    /// Marker of the style, if any.
+    pub fn marker(&self) -> Option<char> {
+        match self {
+            Self::Asterisk => Some('*'),
+            Self::Dash => Some('-'),
+            Self::Underscore => Some('_'),
+            Self::Preserve => None,
+        }
+    }
+}
+
+impl Default for ThematicBreakStyle {
+    /// Keep thematic breaks as they are.
+    fn default() -> Self {
+        Self::Preserve
+    }
+}
+
+/// Configuration that describes how to turn markdown into (normalized)
+/// markdown.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{HeadingStyle, ToMarkdownOptions};
+/// # fn main() {
+///
+/// // Use the default trait to keep everything as it is:
+/// let preserve = ToMarkdownOptions::default();
+///
+/// // Pass options to normalize things:
+/// let atx = ToMarkdownOptions {
+///   normalize_headings: HeadingStyle::Atx,
+///   ..ToMarkdownOptions::default()
+/// };
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "camelCase")
+)]
+pub struct ToMarkdownOptions {
+    /// Style to use for headings.
+    ///
+    /// The default is [`HeadingStyle::Preserve`][], which keeps headings as
+    /// they are.
+    /// Pass [`HeadingStyle::Atx`][] to turn headings (setext) into headings
+    /// (atx): the rank comes from the underline (`=` is 1, `-` is 2), and
+    /// text that spans several lines is joined with spaces.
+    /// Pass [`HeadingStyle::Setext`][] to turn headings (atx) of rank 1 and
+    /// 2 into headings (setext).
+    ///
+    /// Headings that cannot be turned into the other style are kept as they
+    /// are, such as headings (setext) with hard breaks, empty headings
+    /// (atx), and headings (atx) of rank 3 and more.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_markdown, HeadingStyle, ParseOptions, ToMarkdownOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps headings as they are by default:
+    /// assert_eq!(
+    ///     to_markdown("a\n===", &ParseOptions::default(), &ToMarkdownOptions::default())?,
+    ///     "a\n==="
+    /// );
+    ///
+    /// // Pass `normalize_headings` to choose a style:
+    /// assert_eq!(
+    ///     to_markdown(
+    ///         "a\n===",
+    ///         &ParseOptions::default(),
+    ///         &ToMarkdownOptions {
+    ///             normalize_headings: HeadingStyle::Atx,
+    ///             ..ToMarkdownOptions::default()
+    ///         }
+    ///     )?,
+    ///     "# a"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub normalize_headings: HeadingStyle,
+
+    /// Style to use for thematic breaks.
+    ///
+    /// The default is [`ThematicBreakStyle::Preserve`][], which keeps
+    /// thematic breaks as they are.
+    /// Pass another style to turn all thematic breaks, whether they are
+    /// written with asterisks, dashes, or underscores, and with or without
+    /// whitespace between markers, into that style, with
+    /// [`thematic_break_size`][ToMarkdownOptions::thematic_break_size]
+    /// markers.
+    ///
+    /// Thematic breaks that would turn into something else are kept as they
+    /// are: with [`ThematicBreakStyle::Dash`][], those right after a
+    /// paragraph (which would become headings (setext)), and, when
+    /// frontmatter is on, those at the start of the document.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_markdown, ParseOptions, ThematicBreakStyle, ToMarkdownOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps thematic breaks as they are by default:
+    /// assert_eq!(
+    ///     to_markdown("* * *", &ParseOptions::default(), &ToMarkdownOptions::default())?,
+    ///     "* * *"
+    /// );
+    ///
+    /// // Pass `normalize_thematic_breaks` to choose a style:
+    /// assert_eq!(
+    ///     to_markdown(
+    ///         "* * *",
+    ///         &ParseOptions::default(),
+    ///         &ToMarkdownOptions {
+    ///             normalize_thematic_breaks: ThematicBreakStyle::Dash,
+    ///             ..ToMarkdownOptions::default()
+    ///         }
+    ///     )?,
+    ///     "---"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub normalize_thematic_breaks: ThematicBreakStyle,
+
+    /// Number of markers to use in thematic breaks.
+    ///
+    /// This option does nothing if
+    /// [`normalize_thematic_breaks`][ToMarkdownOptions::normalize_thematic_breaks]
+    /// is [`ThematicBreakStyle::Preserve`][].
+    ///
+    /// The default is `3`, which is the least that forms a thematic break.
+    /// Values less than `3` are treated as `3`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_markdown, ParseOptions, ThematicBreakStyle, ToMarkdownOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses 3 markers by default:
+    /// assert_eq!(
+    ///     to_markdown(
+    ///         "- - - - -",
+    ///         &ParseOptions::default(),
+    ///         &ToMarkdownOptions {
+    ///             normalize_thematic_breaks: ThematicBreakStyle::Asterisk,
+    ///             ..ToMarkdownOptions::default()
+    ///         }
+    ///     )?,
+    ///     "***"
+    /// );
+    ///
+    /// // Pass `thematic_break_size` to use more:
+    /// assert_eq!(
+    ///     to_markdown(
+    ///         "- - - - -",
+    ///         &ParseOptions::default(),
+    ///         &ToMarkdownOptions {
+    ///             normalize_thematic_breaks: ThematicBreakStyle::Asterisk,
+    ///             thematic_break_size: 10,
+    ///             ..ToMarkdownOptions::default()
+    ///         }
+    ///     )?,
+    ///     "**********"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub thematic_break_size: usize,
+}
+
+impl Default for ToMarkdownOptions {
+    /// Keep everything as it is.
+    fn default() -> Self {
+        Self {
+            normalize_headings: HeadingStyle::default(),
+            normalize_thematic_breaks: ThematicBreakStyle::default(),
+            thematic_break_size: THEMATIC_BREAK_MARKER_COUNT_MIN,
+        }
+    }
+}
+
 /// Configuration that describes how to parse from markdown and compile to
 /// HTML.
 ///
@@ -1240,6 +3301,11 @@ impl ParseOptions {
 /// ```
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Options {
     /// Configuration that describes how to parse from markdown.
     pub parse: ParseOptions,
@@ -1315,6 +3381,169 @@ mod tests {
         assert!(!constructs.frontmatter, "should support `mdx` shortcut (4)");
     }
 
+    #[test]
+    fn test_constructs_is_enabled() {
+        let all = Constructs {
+            abbreviation: true,
+            details: true,
+            emoji: true,
+            frontmatter: true,
+            math_flow: true,
+            math_flow_latex: true,
+            math_text: true,
+            math_text_latex: true,
+            mdx_esm: true,
+            mdx_expression_flow: true,
+            mdx_expression_text: true,
+            mdx_jsx_flow: true,
+            mdx_jsx_text: true,
+            subscript: true,
+            superscript: true,
+            ..Constructs::gfm()
+        };
+
+        let flags: [(&str, fn(&mut Constructs), &[Name]); 14] = [
+            (
+                "abbreviation",
+                |c| c.abbreviation = false,
+                &[Name::Abbreviation, Name::AbbreviationDefinition],
+            ),
+            ("autolink", |c| c.autolink = false, &[Name::Autolink]),
+            (
+                "block_quote",
+                |c| c.block_quote = false,
+                &[Name::BlockQuote, Name::BlockQuotePrefix],
+            ),
+            (
+                "code_indented",
+                |c| c.code_indented = false,
+                &[Name::CodeIndented],
+            ),
+            (
+                "definition",
+                |c| c.definition = false,
+                &[Name::Definition, Name::DefinitionLabelString],
+            ),
+            ("details", |c| c.details = false, &[Name::DetailsSummary]),
+            (
+                "gfm_table",
+                |c| c.gfm_table = false,
+                &[Name::GfmTable, Name::GfmTableCell],
+            ),
+            (
+                "hard_break_trailing",
+                |c| c.hard_break_trailing = false,
+                &[Name::HardBreakTrailing],
+            ),
+            (
+                "heading_atx",
+                |c| c.heading_atx = false,
+                &[Name::HeadingAtx],
+            ),
+            (
+                "label_end",
+                |c| c.label_end = false,
+                &[
+                    Name::Link,
+                    Name::Image,
+                    Name::GfmFootnoteCall,
+                    Name::Resource,
+                ],
+            ),
+            (
+                "label_start_link",
+                |c| c.label_start_link = false,
+                &[Name::Link, Name::LabelLink],
+            ),
+            (
+                "list_item",
+                |c| c.list_item = false,
+                &[Name::ListOrdered, Name::ListItemPrefix],
+            ),
+            (
+                "subscript",
+                |c| c.subscript = false,
+                &[Name::Subscript, Name::SubscriptText],
+            ),
+            (
+                "thematic_break",
+                |c| c.thematic_break = false,
+                &[Name::ThematicBreak],
+            ),
+        ];
+
+        for (flag, turn_off, names) in flags {
+            let mut constructs = all.clone();
+            turn_off(&mut constructs);
+
+            for name in names {
+                assert!(
+                    all.is_enabled(name),
+                    "should enable `{:?}` when `{}` is on",
+                    name,
+                    flag
+                );
+                assert!(
+                    !constructs.is_enabled(name),
+                    "should disable `{:?}` when `{}` is off",
+                    name,
+                    flag
+                );
+            }
+        }
+
+        let mut constructs = all.clone();
+        constructs.code_fenced = false;
+        assert!(
+            constructs.is_enabled(&Name::CodeFlowChunk),
+            "should enable names shared by several constructs when one is on"
+        );
+        constructs.code_indented = false;
+        assert!(
+            !constructs.is_enabled(&Name::CodeFlowChunk),
+            "should disable names shared by several constructs when all are off"
+        );
+
+        let constructs = Constructs {
+            attention: false,
+            character_escape: false,
+            html_flow: false,
+            ..Constructs::default()
+        };
+        assert!(
+            constructs.is_enabled(&Name::Paragraph) && constructs.is_enabled(&Name::Data),
+            "should always enable names not produced by a construct"
+        );
+
+        let options = ParseOptions {
+            constructs: Constructs {
+                abbreviation: true,
+                details: true,
+                emoji: true,
+                frontmatter: true,
+                math_flow: true,
+                math_text: true,
+                subscript: true,
+                superscript: true,
+                ..Constructs::gfm()
+            },
+            ..ParseOptions::default()
+        };
+        let (events, _) = crate::parser::parse(
+            "---\na: b\n---\n\n>>> *a* **b** ~c~ ^d^ ~~e~~ :+1:\n# f\n\ng  \nh\\\ni &amp; \\*\n\n    j\n\n```k\nl\n```\n\n$$\nm\n$$\n\n> - [ ] n\n>\n> 1. `o` $p$\n<<<\n\n| q |\n| - |\n\n***\n\n<div>\n\n<r> <s@t.u> www.v.w [x](y \"z\") ![a][b] [^c]\n\n[b]: c 'd'\n[^c]: e\n*[f]: g\n\nf\n=",
+            &options,
+        )
+        .unwrap();
+
+        for event in &events {
+            assert!(
+                options.constructs.is_enabled(&event.name),
+                "should enable `{:?}`, as it was produced",
+                event.name
+            );
+        }
+    }
+
     #[test]
     fn test_parse_options() {
         ParseOptions::default();
@@ -1365,7 +3594,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", ParseOptions::default()),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: None, mdx_esm_parse: None }",
+            "ParseOptions { constructs: Constructs { abbreviation: false, attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, details: false, emoji: false, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_flow_latex: false, math_text: false, math_text_latex: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, subscript: false, superscript: false, thematic_break: true }, attention_warnings: false, gfm_strikethrough_single_tilde: true, heading_atx_trailing_whitespace: false, line_comment_prefix: None, literal_backslash_in_destinations: false, math_text_single_dollar: true, max_input_bytes: None, mdx_expression_parse: None, mdx_esm_parse: None, multiline_labels: true, text_constructs: [] }",
             "should support `Debug` trait"
         );
         assert_eq!(
@@ -1378,7 +3607,7 @@ mod tests {
                 })),
                 ..Default::default()
             }),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\") }",
+            "ParseOptions { constructs: Constructs { abbreviation: false, attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, details: false, emoji: false, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_flow_latex: false, math_text: false, math_text_latex: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, subscript: false, superscript: false, thematic_break: true }, attention_warnings: false, gfm_strikethrough_single_tilde: true, heading_atx_trailing_whitespace: false, line_comment_prefix: None, literal_backslash_in_destinations: false, math_text_single_dollar: true, max_input_bytes: None, mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\"), multiline_labels: true, text_constructs: [] }",
             "should support `Debug` trait on mdx functions"
         );
     }
@@ -1449,4 +3678,66 @@ mod tests {
             "should support safe `gfm` shortcut (4)"
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() -> Result<(), serde_json::Error> {
+        let options: Options = serde_json::from_str(
+            r#"{
+                "parse": {"constructs": {"gfmTable": true, "codeIndented": false}},
+                "compile": {"allowDangerousHtml": true, "defaultLineEnding": "carriageReturnLineFeed"}
+            }"#,
+        )?;
+
+        assert_eq!(
+            options.parse.constructs,
+            Constructs {
+                gfm_table: true,
+                code_indented: false,
+                ..Constructs::default()
+            },
+            "should support partial constructs, defaulting the rest"
+        );
+        assert!(
+            options.parse.math_text_single_dollar && options.parse.mdx_expression_parse.is_none(),
+            "should default missing parse options"
+        );
+        assert!(
+            options.compile.allow_dangerous_html && !options.compile.allow_dangerous_protocol,
+            "should support partial compile options, defaulting the rest"
+        );
+        assert_eq!(
+            options.compile.default_line_ending,
+            LineEnding::CarriageReturnLineFeed,
+            "should support line endings"
+        );
+
+        let options: Options = serde_json::from_str("{}")?;
+        assert_eq!(
+            options.parse.constructs,
+            Constructs::default(),
+            "should default everything in an empty object"
+        );
+
+        let value = serde_json::to_string(&Options::gfm())?;
+        let options: Options = serde_json::from_str(&value)?;
+        assert_eq!(
+            options.parse.constructs,
+            Constructs::gfm(),
+            "should round-trip constructs"
+        );
+        assert!(
+            options.compile.gfm_tagfilter,
+            "should round-trip compile options"
+        );
+
+        let options: ToMarkdownOptions = serde_json::from_str(r#"{"normalizeHeadings": "atx"}"#)?;
+        assert_eq!(
+            options.normalize_headings,
+            HeadingStyle::Atx,
+            "should support markdown options"
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,462 @@
+//! Abbreviation definition occurs in the [flow][] content type.
+//!
+//! ## Grammar
+//!
+//! Abbreviation definition forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! abbreviation_definition ::= '*' '[' label ']' ':' *space_or_tab title *space_or_tab
+//!
+//! label ::= 1*(line - '[' - ']')
+//! ; Restriction: the title cannot start or end with whitespace.
+//! title ::= 1*line
+//! ```
+//!
+//! As this construct occurs in flow, like all flow constructs, it must be
+//! followed by an eol (line ending) or eof (end of file).
+//!
+//! The `*` must be at the start of the line: it cannot be indented.
+//! Abbreviation definitions can interrupt paragraphs.
+//!
+//! Abbreviation definitions are an extension that is off by default: turn on
+//! [`abbreviation`][crate::Constructs::abbreviation] in
+//! [`Constructs`][crate::Constructs] to use them.
+//!
+//! Each label that is defined is then found in [text][], where it is wrapped
+//! in [`Abbreviation`][Name::Abbreviation].
+//! The label matches case-sensitively, and only as a whole word: it must not
+//! be preceded or followed by a letter or digit, so `HTML` is found in
+//! `HTML.` but not in `HTMLish`.
+//! Labels are not found in code, HTML, and other places where there is no
+//! text, such as destinations, titles, and references.
+//! When a label is defined several times, the first definition is used.
+//!
+//! ## HTML
+//!
+//! Abbreviation definitions are not part of the HTML.
+//! Abbreviations relate to the `<abbr>` element in HTML, with the title of
+//! the definition as its `title` attribute.
+//! See [*§ 4.5.9 The `abbr` element*][html_abbr] in the HTML spec for more
+//! info.
+//!
+//! There are no abbreviation nodes in mdast: abbreviations are turned into
+//! `abbr` JSX elements there, and definitions are dropped.
+//!
+//! ## Tokens
+//!
+//! *   [`Abbreviation`][Name::Abbreviation]
+//! *   [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+//! *   [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel]
+//! *   [`AbbreviationDefinitionLabelMarker`][Name::AbbreviationDefinitionLabelMarker]
+//! *   [`AbbreviationDefinitionLabelString`][Name::AbbreviationDefinitionLabelString]
+//! *   [`AbbreviationDefinitionMarker`][Name::AbbreviationDefinitionMarker]
+//! *   [`AbbreviationDefinitionTitle`][Name::AbbreviationDefinitionTitle]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! ## References
+//!
+//! *   [*Abbreviations* in `PHP Markdown Extra`](https://michelf.ca/projects/php-markdown/extra/#abbr)
+//! *   [`markdown-it-abbr`](https://github.com/markdown-it/markdown-it-abbr)
+//!
+//! [flow]: crate::construct::flow
+//! [text]: crate::construct::text
+//! [html_abbr]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-abbr-element
+
+use crate::construct::partial_space_or_tab::space_or_tab;
+use crate::event::{Event, Kind, Name};
+use crate::state::{Name as StateName, State};
+use crate::subtokenize::Subresult;
+use crate::tokenizer::Tokenizer;
+use crate::util::{
+    char::{after_index, before_index},
+    skip,
+    slice::{Position, Slice},
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec,
+    vec::Vec,
+};
+
+/// Start of abbreviation definition.
+///
+/// ```markdown
+/// > | *[a]: b
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.constructs().abbreviation && tokenizer.current == Some(b'*') {
+        tokenizer.enter(Name::AbbreviationDefinition);
+        tokenizer.enter(Name::AbbreviationDefinitionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionMarker);
+        State::Next(StateName::AbbreviationDefinitionLabelOpen)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `*`, at `[`.
+///
+/// ```markdown
+/// > | *[a]: b
+///      ^
+/// ```
+pub fn label_open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'[') {
+        tokenizer.enter(Name::AbbreviationDefinitionLabel);
+        tokenizer.enter(Name::AbbreviationDefinitionLabelMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionLabelMarker);
+        State::Next(StateName::AbbreviationDefinitionLabelBefore)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `[`, at label.
+///
+/// ```markdown
+/// > | *[a]: b
+///       ^
+/// ```
+pub fn label_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[' | b']') => State::Nok,
+        _ => {
+            tokenizer.enter(Name::AbbreviationDefinitionLabelString);
+            State::Retry(StateName::AbbreviationDefinitionLabelInside)
+        }
+    }
+}
+
+/// In label.
+///
+/// ```markdown
+/// > | *[a]: b
+///       ^
+/// ```
+pub fn label_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[') => State::Nok,
+        Some(b']') => {
+            tokenizer.exit(Name::AbbreviationDefinitionLabelString);
+            tokenizer.enter(Name::AbbreviationDefinitionLabelMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::AbbreviationDefinitionLabelMarker);
+            tokenizer.exit(Name::AbbreviationDefinitionLabel);
+            State::Next(StateName::AbbreviationDefinitionLabelAfter)
+        }
+        _ => {
+            tokenizer.consume();
+            State::Next(StateName::AbbreviationDefinitionLabelInside)
+        }
+    }
+}
+
+/// After label, at `:`.
+///
+/// ```markdown
+/// > | *[a]: b
+///         ^
+/// ```
+pub fn label_after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::AbbreviationDefinitionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionMarker);
+        State::Next(StateName::AbbreviationDefinitionMarkerAfter)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `:`.
+///
+/// ```markdown
+/// > | *[a]: b
+///          ^
+/// ```
+pub fn marker_after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::AbbreviationDefinitionTitleBefore),
+            State::Nok,
+        );
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::AbbreviationDefinitionTitleBefore)
+    }
+}
+
+/// After `:` and optional whitespace, at title.
+///
+/// ```markdown
+/// > | *[a]: b
+///           ^
+/// ```
+pub fn title_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => State::Nok,
+        _ => {
+            tokenizer.enter(Name::AbbreviationDefinitionTitle);
+            State::Retry(StateName::AbbreviationDefinitionTitleInside)
+        }
+    }
+}
+
+/// In title.
+///
+/// ```markdown
+/// > | *[a]: b c
+///           ^^^
+/// ```
+pub fn title_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::AbbreviationDefinitionTitle);
+            State::Retry(StateName::AbbreviationDefinitionAfter)
+        }
+        Some(b'\t' | b' ') => {
+            tokenizer.check(
+                State::Next(StateName::AbbreviationDefinitionTitleEnd),
+                State::Next(StateName::AbbreviationDefinitionTitleWhitespace),
+            );
+            State::Retry(StateName::AbbreviationDefinitionTitleTrailing)
+        }
+        _ => {
+            tokenizer.consume();
+            State::Next(StateName::AbbreviationDefinitionTitleInside)
+        }
+    }
+}
+
+/// In whitespace in title, checking whether it is trailing.
+///
+/// ```markdown
+/// > | *[a]: b␠␠
+///            ^^
+/// ```
+pub fn title_trailing(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => State::Ok,
+        Some(b'\t' | b' ') => {
+            tokenizer.consume();
+            State::Next(StateName::AbbreviationDefinitionTitleTrailing)
+        }
+        _ => State::Nok,
+    }
+}
+
+/// In whitespace in title, that is not trailing.
+///
+/// ```markdown
+/// > | *[a]: b c
+///            ^
+/// ```
+pub fn title_whitespace(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.consume();
+        State::Next(StateName::AbbreviationDefinitionTitleWhitespace)
+    } else {
+        State::Retry(StateName::AbbreviationDefinitionTitleInside)
+    }
+}
+
+/// At trailing whitespace after title.
+///
+/// ```markdown
+/// > | *[a]: b␠␠
+///            ^
+/// ```
+pub fn title_end(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Name::AbbreviationDefinitionTitle);
+    tokenizer.attempt(
+        State::Next(StateName::AbbreviationDefinitionAfter),
+        State::Nok,
+    );
+    State::Retry(space_or_tab(tokenizer))
+}
+
+/// After abbreviation definition.
+///
+/// ```markdown
+/// > | *[a]: b
+///            ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Name::AbbreviationDefinition);
+
+    let label = skip::to_back(
+        &tokenizer.events,
+        tokenizer.events.len() - 1,
+        &[Name::AbbreviationDefinitionLabelString],
+    );
+    tokenizer.tokenize_state.abbreviations.push(
+        Slice::from_position(
+            tokenizer.parse_state.bytes,
+            &Position::from_exit_event(&tokenizer.events, label),
+        )
+        .as_str()
+        .into(),
+    );
+
+    // Feel free to interrupt.
+    tokenizer.interrupt = false;
+    State::Ok
+}
+
+/// Resolve abbreviations.
+///
+/// Abbreviations can be used before they are defined, but as definitions
+/// are in flow, all of them are known when text is resolved.
+/// Data in text that matches a defined label is split, and the match is
+/// wrapped in [`Abbreviation`][Name::Abbreviation].
+pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+    let labels = &tokenizer.parse_state.abbreviations;
+    let bytes = tokenizer.parse_state.bytes;
+    // Number of open places in text where there is no text after all.
+    let mut not_text = 0;
+    let mut index = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        match event.name {
+            Name::GfmFootnoteCall
+            | Name::ReferenceString
+            | Name::ResourceDestinationString
+            | Name::ResourceTitleString => {
+                if event.kind == Kind::Enter {
+                    not_text += 1;
+                } else {
+                    not_text -= 1;
+                }
+            }
+            Name::Data if event.kind == Kind::Exit && not_text == 0 => {
+                let replace = split(&tokenizer.events, index, bytes, labels);
+
+                if !replace.is_empty() {
+                    tokenizer.map.add(index - 1, 2, replace);
+                }
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    tokenizer.map.consume(&mut tokenizer.events);
+    None
+}
+
+/// Split the data that exits at `index` into data and abbreviations.
+///
+/// Labels are looked up by size, longest first, so that `HTML5` is found
+/// instead of `HTML`, and only where a word can start.
+///
+/// Yields nothing if there are no abbreviations in it.
+fn split(
+    events: &[Event],
+    index: usize,
+    bytes: &[u8],
+    labels: &BTreeMap<usize, BTreeSet<Vec<u8>>>,
+) -> Vec<Event> {
+    let start = events[index - 1].point.index;
+    let end = events[index].point.index;
+    let mut point = events[index - 1].point.clone();
+    let mut replace = vec![];
+    let mut byte_index = start;
+
+    while byte_index < end {
+        // Labels cannot start in the middle of a character or a word.
+        let size =
+            if bytes[byte_index] & 0xC0 == 0x80 || !is_boundary(before_index(bytes, byte_index)) {
+                None
+            } else {
+                labels
+                    .range(..=end - byte_index)
+                    .rev()
+                    .find(|(size, labels)| {
+                        labels.contains(&bytes[byte_index..byte_index + **size])
+                            && is_boundary(after_index(bytes, byte_index + **size))
+                    })
+                    .map(|(size, _)| *size)
+            };
+
+        if let Some(size) = size {
+            // Data before.
+            if point.index < byte_index {
+                replace.push(Event {
+                    kind: Kind::Enter,
+                    name: Name::Data,
+                    point: point.clone(),
+                    link: None,
+                });
+                point = point.shift_to(bytes, byte_index);
+                replace.push(Event {
+                    kind: Kind::Exit,
+                    name: Name::Data,
+                    point: point.clone(),
+                    link: None,
+                });
+            }
+
+            let next = point.shift_to(bytes, byte_index + size);
+            replace.push(Event {
+                kind: Kind::Enter,
+                name: Name::Abbreviation,
+                point: point.clone(),
+                link: None,
+            });
+            replace.push(Event {
+                kind: Kind::Enter,
+                name: Name::Data,
+                point: point.clone(),
+                link: None,
+            });
+            replace.push(Event {
+                kind: Kind::Exit,
+                name: Name::Data,
+                point: next.clone(),
+                link: None,
+            });
+            replace.push(Event {
+                kind: Kind::Exit,
+                name: Name::Abbreviation,
+                point: next.clone(),
+                link: None,
+            });
+            point = next;
+            byte_index += size;
+        } else {
+            byte_index += 1;
+        }
+    }
+
+    // Data after.
+    if !replace.is_empty() && point.index < end {
+        replace.push(Event {
+            kind: Kind::Enter,
+            name: Name::Data,
+            point,
+            link: None,
+        });
+        replace.push(Event {
+            kind: Kind::Exit,
+            name: Name::Data,
+            point: events[index].point.clone(),
+            link: None,
+        });
+    }
+
+    replace
+}
+
+/// Check whether a character can occur next to an abbreviation.
+///
+/// Abbreviations are whole words: they cannot be next to letters or digits.
+fn is_boundary(char: Option<char>) -> bool {
+    !char.map_or(false, char::is_alphanumeric)
+}
@@ -1,5 +1,5 @@
-//! Attention (emphasis, strong, optionally GFM strikethrough) occurs in the
-//! [text][] content type.
+//! Attention (emphasis, strong, optionally GFM strikethrough, subscript, and
+//! superscript) occurs in the [text][] content type.
 //!
 //! ## Grammar
 //!
@@ -9,6 +9,7 @@
 //! ```bnf
 //! attention_sequence ::= 1*'*' | 1*'_'
 //! gfm_attention_sequence ::= 1*'~'
+//! superscript_sequence ::= '^'
 //! ```
 //!
 //! Sequences are matched together to form attention based on which character
@@ -16,6 +17,13 @@
 //! each sequence.
 //! Otherwise they are turned into data.
 //!
+//! Subscript (single tildes) and superscript (single carets) follow Pandoc:
+//! the content between the sequences cannot contain whitespace, unless it is
+//! a space escaped with a backslash (`H~a\ b~`).
+//! While `\ ` is not a [character escape][character_escape], the backslash is
+//! not part of the subscript or superscript in HTML.
+//! When subscript is on, single tildes no longer form GFM strikethrough.
+//!
 //! ## HTML
 //!
 //! When asterisk/underscore sequences match, and two markers can be “taken”
@@ -29,6 +37,11 @@
 //! HTML.
 //! See [*§ 4.7.2 The `del` element*][html-del] in the HTML spec for more info.
 //!
+//! When subscript or superscript sequences match, they relate to the `<sub>`
+//! and `<sup>` elements in HTML.
+//! See [*§ 4.5.19 The `sub` and `sup` elements*][html-sub-sup] in the HTML
+//! spec for more info.
+//!
 //! ## Recommendation
 //!
 //! It is recommended to use asterisks for emphasis/strong attention when
@@ -60,6 +73,12 @@
 //! *   [`Strong`][Name::Strong]
 //! *   [`StrongSequence`][Name::StrongSequence]
 //! *   [`StrongText`][Name::StrongText]
+//! *   [`Subscript`][Name::Subscript]
+//! *   [`SubscriptSequence`][Name::SubscriptSequence]
+//! *   [`SubscriptText`][Name::SubscriptText]
+//! *   [`Superscript`][Name::Superscript]
+//! *   [`SuperscriptSequence`][Name::SuperscriptSequence]
+//! *   [`SuperscriptText`][Name::SuperscriptText]
 //!
 //! > 👉 **Note**: while parsing, [`AttentionSequence`][Name::AttentionSequence]
 //! > is used, which is later compiled away.
@@ -70,20 +89,26 @@
 //! *   [`micromark-extension-gfm-strikethrough`](https://github.com/micromark/micromark-extension-gfm-strikethrough)
 //! *   [*§ 6.2 Emphasis and strong emphasis* in `CommonMark`](https://spec.commonmark.org/0.30/#emphasis-and-strong-emphasis)
 //! *   [*§ 6.5 Strikethrough (extension)* in `GFM`](https://github.github.com/gfm/#strikethrough-extension-)
+//! *   [*Superscripts and subscripts* in Pandoc](https://pandoc.org/MANUAL.html#superscripts-and-subscripts)
 //!
 //! [text]: crate::construct::text
+//! [character_escape]: crate::construct::character_escape
 //! [html-em]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-em-element
 //! [html-strong]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-strong-element
 //! [html-del]: https://html.spec.whatwg.org/multipage/edits.html#the-del-element
+//! [html-sub-sup]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-sub-and-sup-elements
 
 use crate::event::{Event, Kind, Name, Point};
 use crate::resolve::Name as ResolveName;
 use crate::state::{Name as StateName, State};
 use crate::subtokenize::Subresult;
 use crate::tokenizer::Tokenizer;
-use crate::util::char::{
-    after_index as char_after_index, before_index as char_before_index, classify_opt,
-    Kind as CharacterKind,
+use crate::util::{
+    char::{
+        after_index as char_after_index, before_index as char_before_index, classify_opt,
+        Kind as CharacterKind,
+    },
+    warning::unmatched_attention,
 };
 use alloc::{vec, vec::Vec};
 
@@ -118,10 +143,14 @@ struct Sequence {
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
     // Emphasis/strong:
-    if (tokenizer.parse_state.options.constructs.attention
+    if (tokenizer.constructs().attention
         && matches!(tokenizer.current, Some(b'*' | b'_')))
         // GFM strikethrough:
-        || (tokenizer.parse_state.options.constructs.gfm_strikethrough && tokenizer.current == Some(b'~'))
+        || ((tokenizer.constructs().gfm_strikethrough
+            || tokenizer.constructs().subscript)
+            && tokenizer.current == Some(b'~'))
+        // Superscript:
+        || (tokenizer.constructs().superscript && tokenizer.current == Some(b'^'))
     {
         tokenizer.tokenize_state.marker = tokenizer.current.unwrap();
         tokenizer.enter(Name::AttentionSequence);
@@ -187,15 +216,31 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                         continue;
                     }
 
+                    // For subscript and superscript:
+                    // * both sequences must be one marker
+                    // * there cannot be whitespace between them
+                    if is_script(tokenizer, sequence_open, sequence_close) {
+                        if sequence_close.size != 1
+                            || sequence_open.size != 1
+                            || contains_whitespace(
+                                tokenizer.parse_state.bytes,
+                                sequence_open.end_point.index,
+                                sequence_close.start_point.index,
+                            )
+                        {
+                            continue;
+                        }
+                    }
                     // For GFM strikethrough:
                     // * both sequences must have the same size
                     // * more than 2 markers don’t work
                     // * one marker is prohibited by the spec, but supported by GH
-                    if sequence_close.marker == b'~'
-                        && (sequence_close.size != sequence_open.size
+                    else if sequence_close.marker == b'~'
+                        && (!tokenizer.constructs().gfm_strikethrough
+                            || sequence_close.size != sequence_open.size
                             || sequence_close.size > 2
                             || sequence_close.size == 1
-                                && !tokenizer.parse_state.options.gfm_strikethrough_single_tilde)
+                                && !tokenizer.options().gfm_strikethrough_single_tilde)
                     {
                         continue;
                     }
@@ -212,16 +257,43 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     }
 
     // Mark remaining sequences as data.
+    let mut warnings = vec![];
     let mut index = 0;
     while index < sequences.len() {
         let sequence = &sequences[index];
+
+        // Warn for emphasis and strong that could have opened or closed.
+        if tokenizer.options().attention_warnings
+            && matches!(sequence.marker, b'*' | b'_')
+            && (sequence.open || sequence.close)
+        {
+            warnings.push(unmatched_attention(
+                sequence.marker,
+                sequence.open,
+                sequence.close,
+                &sequence.start_point,
+                &sequence.end_point,
+            ));
+        }
+
         tokenizer.events[sequence.index].name = Name::Data;
         tokenizer.events[sequence.index + 1].name = Name::Data;
         index += 1;
     }
 
     tokenizer.map.consume(&mut tokenizer.events);
-    None
+
+    if warnings.is_empty() {
+        None
+    } else {
+        Some(Subresult {
+            done: false,
+            gfm_footnote_definitions: vec![],
+            definitions: vec![],
+            abbreviations: vec![],
+            warnings,
+        })
+    }
 }
 
 /// Get sequences.
@@ -323,7 +395,19 @@ fn match_sequences(
         between += 1;
     }
 
-    let (group_name, seq_name, text_name) = if sequences[open].marker == b'~' {
+    let (group_name, seq_name, text_name) = if sequences[open].marker == b'^' {
+        (
+            Name::Superscript,
+            Name::SuperscriptSequence,
+            Name::SuperscriptText,
+        )
+    } else if is_script(tokenizer, &sequences[open], &sequences[close]) {
+        (
+            Name::Subscript,
+            Name::SubscriptSequence,
+            Name::SubscriptText,
+        )
+    } else if sequences[open].marker == b'~' {
         (
             Name::GfmStrikethrough,
             Name::GfmStrikethroughSequence,
@@ -434,3 +518,30 @@ fn match_sequences(
 
     next
 }
+
+/// Check whether two sequences would form subscript or superscript.
+fn is_script(tokenizer: &Tokenizer, open: &Sequence, close: &Sequence) -> bool {
+    close.marker == b'^'
+        || (close.marker == b'~'
+            && tokenizer.constructs().subscript
+            && open.size == 1
+            && close.size == 1)
+}
+
+/// Check whether there is whitespace between two indices.
+///
+/// Spaces escaped with a backslash are not whitespace.
+fn contains_whitespace(bytes: &[u8], start: usize, end: usize) -> bool {
+    let mut index = start;
+
+    while index < end {
+        match bytes[index] {
+            b'\t' | b'\n' | b'\r' | b' ' => return true,
+            // Skip an escaped space, or an escaped backslash.
+            b'\\' if matches!(bytes.get(index + 1), Some(b' ' | b'\\')) => index += 2,
+            _ => index += 1,
+        }
+    }
+
+    false
+}
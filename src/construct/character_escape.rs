@@ -52,7 +52,10 @@ use crate::tokenizer::Tokenizer;
 ///      ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.character_escape && tokenizer.current == Some(b'\\')
+    if tokenizer.constructs().character_escape
+        && tokenizer.current == Some(b'\\')
+        // Backslashes are literal in some destinations.
+        && !tokenizer.tokenize_state.destination_literal_backslash
     {
         tokenizer.enter(Name::CharacterEscape);
         tokenizer.enter(Name::CharacterEscapeMarker);
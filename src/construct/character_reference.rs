@@ -87,9 +87,7 @@ use crate::util::{
 ///      ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.character_reference
-        && tokenizer.current == Some(b'&')
-    {
+    if tokenizer.constructs().character_reference && tokenizer.current == Some(b'&') {
         tokenizer.enter(Name::CharacterReference);
         tokenizer.enter(Name::CharacterReferenceMarker);
         tokenizer.consume();
@@ -72,7 +72,7 @@ use crate::util::constant::TAB_SIZE;
 pub fn start(tokenizer: &mut Tokenizer) -> State {
     // Do not interrupt paragraphs.
     if !tokenizer.interrupt
-        && tokenizer.parse_state.options.constructs.code_indented
+        && tokenizer.constructs().code_indented
         && matches!(tokenizer.current, Some(b'\t' | b' '))
     {
         tokenizer.enter(Name::CodeIndented);
@@ -132,6 +132,7 @@ pub fn inside(tokenizer: &mut Tokenizer) -> State {
 ///            ^
 /// ```
 pub fn after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.end = 0;
     tokenizer.exit(Name::CodeIndented);
     // Feel free to interrupt.
     tokenizer.interrupt = false;
@@ -156,11 +157,65 @@ pub fn further_start(tokenizer: &mut Tokenizer) -> State {
         tokenizer.exit(Name::LineEnding);
         State::Next(StateName::CodeIndentedFurtherStart)
     } else {
-        tokenizer.attempt(State::Ok, State::Next(StateName::CodeIndentedFurtherBegin));
+        tokenizer.attempt(
+            State::Next(StateName::CodeIndentedFurtherIndented),
+            State::Next(StateName::CodeIndentedFurtherBegin),
+        );
         State::Retry(space_or_tab_min_max(tokenizer, TAB_SIZE, TAB_SIZE))
     }
 }
 
+/// After the indent of a further line.
+///
+/// Lines that are only whitespace are only part of the code if more code
+/// follows them, as trailing blank lines are not part of it.
+/// Where that code starts is kept in `end`, so that a run of such lines is
+/// checked once.
+///
+/// ```markdown
+///   |     aaa
+/// > |     bbb
+///         ^
+/// ```
+pub fn further_indented(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\t' | b'\n' | b' ')
+            if tokenizer.point.index >= tokenizer.tokenize_state.end =>
+        {
+            tokenizer.check(State::Ok, State::Nok);
+            State::Retry(StateName::CodeIndentedFurtherBlank)
+        }
+        _ => {
+            tokenizer.tokenize_state.end = tokenizer.point.index;
+            State::Ok
+        }
+    }
+}
+
+/// In whitespace after the indent of a further line, checking whether more
+/// code follows.
+///
+/// ```markdown
+///   |     aaa
+/// > |     ␠␠
+///         ^^
+///   |     bbb
+/// ```
+pub fn further_blank(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None => State::Nok,
+        Some(b'\t' | b' ') => {
+            tokenizer.consume();
+            State::Next(StateName::CodeIndentedFurtherBlank)
+        }
+        Some(b'\n') => State::Retry(StateName::CodeIndentedFurtherStart),
+        Some(_) => {
+            tokenizer.tokenize_state.end = tokenizer.point.index;
+            State::Ok
+        }
+    }
+}
+
 /// At the beginning of a line that is not indented enough.
 ///
 /// ```markdown
@@ -0,0 +1,90 @@
+//! Comment line occurs in the [document][] content type.
+//!
+//! Comment lines are an extension, turned on by passing a prefix in
+//! [`line_comment_prefix`][crate::ParseOptions::line_comment_prefix].
+//!
+//! ## Grammar
+//!
+//! Comment line forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: `prefix` is the configured `line_comment_prefix`.
+//! comment_line ::= prefix *line [eol]
+//! ```
+//!
+//! The eol (line ending) after a comment line is part of it.
+//! Comment lines are found after the prefixes of containers, and are not
+//! passed to [flow][]: they are removed as if they were never there.
+//! That means that flow constructs, such as paragraphs, tables, and code
+//! (indented), continue after a comment line, and that a lazy line after one
+//! continues containers if the line before the comment line could.
+//!
+//! The prefix must be at the start of the line (after the prefixes of
+//! containers): it cannot be indented.
+//! When it occurs elsewhere, such as in the middle of a line, it is not a
+//! comment.
+//! Comment lines are also not found in code (fenced) and other raw flow
+//! constructs.
+//!
+//! ## HTML
+//!
+//! Comment lines are not part of the HTML.
+//! When a comment line is the last line and has no eol, the eol before it is
+//! not part of the HTML either, so that the HTML is the same as without the
+//! comment line.
+//!
+//! ## Tokens
+//!
+//! *   [`CommentLine`][Name::CommentLine]
+//!
+//! [document]: crate::construct::document
+//! [flow]: crate::construct::flow
+
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+
+/// Start of comment line.
+///
+/// ```markdown
+/// > | % a
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    match &tokenizer.options().line_comment_prefix {
+        Some(prefix)
+            if !prefix.is_empty()
+                && tokenizer.parse_state.bytes[tokenizer.point.index..]
+                    .starts_with(prefix.as_bytes()) =>
+        {
+            tokenizer.enter(Name::CommentLine);
+            State::Retry(StateName::CommentLineInside)
+        }
+        _ => State::Nok,
+    }
+}
+
+/// In comment line.
+///
+/// ```markdown
+/// > | % a
+///     ^^^^
+/// ```
+pub fn inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None => {
+            tokenizer.exit(Name::CommentLine);
+            State::Ok
+        }
+        Some(b'\n') => {
+            tokenizer.consume();
+            tokenizer.exit(Name::CommentLine);
+            State::Ok
+        }
+        _ => {
+            tokenizer.consume();
+            State::Next(StateName::CommentLineInside)
+        }
+    }
+}
@@ -132,13 +132,14 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Result<Option<Subresult>, String> {
                 // Skip past line ending.
                 enter_index += 2;
 
-                // Skip past prefix.
+                // Skip past prefix, and comment lines.
                 while enter_index < tokenizer.events.len() {
                     let event = &tokenizer.events[enter_index];
 
                     if event.name != Name::SpaceOrTab
                         && event.name != Name::BlockQuotePrefix
                         && event.name != Name::BlockQuoteMarker
+                        && event.name != Name::CommentLine
                     {
                         break;
                     }
@@ -0,0 +1,354 @@
+//! Details occur in the [document][] content type.
+//!
+//! ## Grammar
+//!
+//! Details form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! details_start ::= fence_open
+//! ; Restriction: a line that is `fence_close` closes the details instead.
+//! details_cont ::= ''
+//!
+//! ; Restriction: the number of markers in the closing fence sequence must be
+//! ; equal to or greater than the number of markers in the opening fence
+//! ; sequence.
+//! fence_open ::= sequence_open *space_or_tab [summary]
+//! fence_close ::= sequence_close *space_or_tab
+//! sequence_open ::= 3*'>'
+//! sequence_close ::= 3*'<'
+//! ; Restriction: the summary is interpreted as the [text][] content type.
+//! summary ::= text *line
+//! ```
+//!
+//! As details are a container, everything after the opening fence until the
+//! closing fence is more containers or flow.
+//! Details are not exited by lines that are not prefixed with something,
+//! such as a blank line or a line that is not indented: only a closing fence
+//! exits them, or the end of the document or of a parent container.
+//! A closing fence in open raw flow, such as fenced code, that is directly in
+//! details is part of that raw flow and does not close the details.
+//!
+//! Details can be nested.
+//! A closing fence closes the innermost open details, and also every directly
+//! enclosing details whose opening fence sequence is not longer than the
+//! closing fence sequence.
+//! To nest details, use longer fences for outer details, so that it is clear
+//! which fence closes which details:
+//!
+//! ````markdown
+//! >>>> outer
+//! >>> inner
+//! a
+//! <<<
+//! b
+//! <<<<
+//! ````
+//!
+//! Here, `b` is in the outer details.
+//! Without the first closing fence, `<<<<` would close both details.
+//!
+//! The `summary` part is interpreted as the [text][] content type.
+//! That means that [character escapes][character_escape],
+//! [character references][character_reference], and other text constructs
+//! are allowed in it.
+//!
+//! Details are an extension that is off by default: turn on
+//! [`details`][crate::Constructs::details] in [`Constructs`][crate::Constructs]
+//! to use them.
+//! When on, details take precedence over block quotes: `>>> a` is no
+//! longer three nested block quotes.
+//!
+//! ## HTML
+//!
+//! Details relate to the `<details>` and `<summary>` elements in HTML.
+//! See [*§ 4.11.1 The `details` element*][html_details] and
+//! [*§ 4.11.2 The `summary` element*][html_summary] in the HTML spec for more
+//! info.
+//!
+//! ## Recommendation
+//!
+//! Always use a summary, so that readers know what is folded away.
+//!
+//! ## Tokens
+//!
+//! *   [`Details`][Name::Details]
+//! *   [`DetailsFence`][Name::DetailsFence]
+//! *   [`DetailsFenceSequence`][Name::DetailsFenceSequence]
+//! *   [`DetailsSummary`][Name::DetailsSummary]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! [document]: crate::construct::document
+//! [text]: crate::construct::text
+//! [character_escape]: crate::construct::character_escape
+//! [character_reference]: crate::construct::character_reference
+//! [html_details]: https://html.spec.whatwg.org/multipage/interactive-elements.html#the-details-element
+//! [html_summary]: https://html.spec.whatwg.org/multipage/interactive-elements.html#the-summary-element
+
+use crate::construct::partial_space_or_tab::space_or_tab_min_max;
+use crate::event::{Content, Link, Name};
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::{Container, Tokenizer};
+use crate::util::constant::{DETAILS_SEQUENCE_SIZE_MIN, TAB_SIZE};
+
+/// Start of details.
+///
+/// ```markdown
+/// > | >>> a
+///     ^
+///   | b
+///   | <<<
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.constructs().details {
+        tokenizer.enter(Name::Details);
+        tokenizer.enter(Name::DetailsFence);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(State::Next(StateName::DetailsBefore), State::Nok);
+            State::Retry(space_or_tab_min_max(tokenizer, 1, indent_max(tokenizer)))
+        } else {
+            State::Retry(StateName::DetailsBefore)
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// At opening fence sequence, after optional whitespace.
+///
+/// ```markdown
+/// > | >>> a
+///     ^
+///   | b
+///   | <<<
+/// ```
+pub fn before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'>') {
+        tokenizer.enter(Name::DetailsFenceSequence);
+        State::Retry(StateName::DetailsSequenceOpen)
+    } else {
+        State::Nok
+    }
+}
+
+/// In opening fence sequence.
+///
+/// ```markdown
+/// > | >>> a
+///      ^
+///   | b
+///   | <<<
+/// ```
+pub fn sequence_open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'>') {
+        tokenizer.tokenize_state.size += 1;
+        tokenizer.consume();
+        State::Next(StateName::DetailsSequenceOpen)
+    } else if tokenizer.tokenize_state.size < DETAILS_SEQUENCE_SIZE_MIN {
+        tokenizer.tokenize_state.size = 0;
+        State::Nok
+    } else {
+        tokenizer.exit(Name::DetailsFenceSequence);
+        tokenizer.tokenize_state.document_container_stack
+            [tokenizer.tokenize_state.document_continued]
+            .size = tokenizer.tokenize_state.size;
+        tokenizer.tokenize_state.size = 0;
+        tokenizer.attempt(State::Next(StateName::DetailsSummaryBefore), State::Nok);
+        State::Retry(space_or_tab_min_max(tokenizer, 0, usize::MAX))
+    }
+}
+
+/// After opening fence sequence and optional whitespace, at optional
+/// summary.
+///
+/// ```markdown
+/// > | >>> a
+///         ^
+///   | b
+///   | <<<
+/// ```
+pub fn summary_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::DetailsFence);
+            State::Ok
+        }
+        Some(_) => {
+            tokenizer.enter(Name::DetailsSummary);
+            tokenizer.enter_link(
+                Name::Data,
+                Link {
+                    previous: None,
+                    next: None,
+                    content: Content::Text,
+                },
+            );
+            State::Retry(StateName::DetailsSummaryInside)
+        }
+    }
+}
+
+/// In summary.
+///
+/// ```markdown
+/// > | >>> a
+///         ^
+///   | b
+///   | <<<
+/// ```
+pub fn summary_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::Data);
+            tokenizer.exit(Name::DetailsSummary);
+            tokenizer.exit(Name::DetailsFence);
+            State::Ok
+        }
+        Some(_) => {
+            tokenizer.consume();
+            State::Next(StateName::DetailsSummaryInside)
+        }
+    }
+}
+
+/// Start of details continuation.
+///
+/// Every line continues open details: only a closing fence closes them.
+/// The closing fence is given to the innermost open details, which closes
+/// enclosing details too (see [`after_sequence_close`][]).
+/// While raw flow (such as fenced code) directly in the details is open, a
+/// closing fence is part of that raw flow instead.
+///
+/// ```markdown
+///   | >>> a
+/// > | b
+///     ^
+/// > | <<<
+///     ^
+/// ```
+pub fn cont_start(tokenizer: &mut Tokenizer) -> State {
+    let stack = &tokenizer.tokenize_state.document_container_stack;
+    let continued = tokenizer.tokenize_state.document_continued;
+
+    // Closed on a previous line.
+    if stack[continued].size == 0 {
+        State::Nok
+    }
+    // Leave the closing fence for open inner details, or for open raw flow
+    // directly in these details.
+    else if stack[continued + 1..]
+        .iter()
+        .any(|container| container.kind == Container::Details && container.size > 0)
+        || (continued + 1 == stack.len()
+            && tokenizer
+                .tokenize_state
+                .document_child
+                .as_ref()
+                .map_or(false, |child| child.concrete))
+    {
+        State::Ok
+    } else {
+        tokenizer.attempt(State::Ok, State::Ok);
+        tokenizer.enter(Name::DetailsFence);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(State::Next(StateName::DetailsContBefore), State::Nok);
+            State::Retry(space_or_tab_min_max(tokenizer, 1, indent_max(tokenizer)))
+        } else {
+            State::Retry(StateName::DetailsContBefore)
+        }
+    }
+}
+
+/// At closing fence sequence, after optional whitespace.
+///
+/// ```markdown
+///   | >>> a
+///   | b
+/// > | <<<
+///     ^
+/// ```
+pub fn cont_before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'<') {
+        tokenizer.enter(Name::DetailsFenceSequence);
+        State::Retry(StateName::DetailsSequenceClose)
+    } else {
+        State::Nok
+    }
+}
+
+/// In closing fence sequence.
+///
+/// ```markdown
+///   | >>> a
+///   | b
+/// > | <<<
+///      ^
+/// ```
+pub fn sequence_close(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'<') {
+        tokenizer.tokenize_state.size += 1;
+        tokenizer.consume();
+        State::Next(StateName::DetailsSequenceClose)
+    } else if tokenizer.tokenize_state.size
+        >= tokenizer.tokenize_state.document_container_stack
+            [tokenizer.tokenize_state.document_continued]
+            .size
+    {
+        tokenizer.exit(Name::DetailsFenceSequence);
+        tokenizer.attempt(
+            State::Next(StateName::DetailsAfterSequenceClose),
+            State::Nok,
+        );
+        State::Retry(space_or_tab_min_max(tokenizer, 0, usize::MAX))
+    } else {
+        tokenizer.tokenize_state.size = 0;
+        State::Nok
+    }
+}
+
+/// After closing fence sequence and optional whitespace.
+///
+/// Closes these details, and the directly enclosing details whose opening
+/// fence sequence is not longer than the closing fence sequence.
+///
+/// ```markdown
+///   | >>> a
+///   | b
+/// > | <<<
+///        ^
+/// ```
+pub fn after_sequence_close(tokenizer: &mut Tokenizer) -> State {
+    let size = tokenizer.tokenize_state.size;
+    tokenizer.tokenize_state.size = 0;
+
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::DetailsFence);
+            // Mark as closed, so that the next line exits the details.
+            let stack = &mut tokenizer.tokenize_state.document_container_stack
+                [..=tokenizer.tokenize_state.document_continued];
+
+            for container in stack.iter_mut().rev() {
+                if container.kind != Container::Details || container.size > size {
+                    break;
+                }
+
+                container.size = 0;
+            }
+
+            State::Ok
+        }
+        _ => State::Nok,
+    }
+}
+
+/// Max size of indent before a fence.
+fn indent_max(tokenizer: &Tokenizer) -> usize {
+    if tokenizer.constructs().is_enabled(&Name::CodeIndented) {
+        TAB_SIZE - 1
+    } else {
+        usize::MAX
+    }
+}
@@ -6,8 +6,13 @@
 //! The constructs found in flow are:
 //!
 //! *   [Block quote][crate::construct::block_quote]
+//! *   [Details][crate::construct::details]
 //! *   [List item][crate::construct::list_item]
 //! *   [GFM: Footnote definition][crate::construct::gfm_footnote_definition]
+//!
+//! Lines in containers can also be
+//! [comment lines][crate::construct::comment_line], which are not passed to
+//! flow.
 
 use crate::event::{Content, Event, Kind, Link, Name};
 use crate::state::{Name as StateName, State};
@@ -100,6 +105,7 @@ pub fn container_existing_before(tokenizer: &mut Tokenizer) -> State {
 
         let name = match container.kind {
             Container::BlockQuote => StateName::BlockQuoteContStart,
+            Container::Details => StateName::DetailsContStart,
             Container::GfmFootnoteDefinition => StateName::GfmFootnoteDefinitionContStart,
             Container::ListItem => StateName::ListItemContStart,
         };
@@ -155,14 +161,16 @@ pub fn container_new_before(tokenizer: &mut Tokenizer) -> State {
     }
 
     // Check for a new container.
-    // Block quote?
+    // Details?
+    // Checked before block quotes, as its fence looks like several block
+    // quote markers.
     // Add a new container at the end of the stack.
     let tail = tokenizer.tokenize_state.document_container_stack.len();
     tokenizer
         .tokenize_state
         .document_container_stack
         .push(ContainerState {
-            kind: Container::BlockQuote,
+            kind: Container::Details,
             blank_initial: false,
             size: 0,
         });
@@ -172,6 +180,29 @@ pub fn container_new_before(tokenizer: &mut Tokenizer) -> State {
         .document_container_stack
         .swap(tokenizer.tokenize_state.document_continued, tail);
 
+    tokenizer.attempt(
+        State::Next(StateName::DocumentContainerNewAfter),
+        State::Next(StateName::DocumentContainerNewBeforeNotDetails),
+    );
+    State::Retry(StateName::DetailsStart)
+}
+
+/// At new container, but not details.
+///
+/// ```markdown
+/// > | > a
+///     ^
+/// ```
+pub fn container_new_before_not_details(tokenizer: &mut Tokenizer) -> State {
+    // Block quote?
+    // We replace the empty details container for this new block quote one.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued] = ContainerState {
+        kind: Container::BlockQuote,
+        blank_initial: false,
+        size: 0,
+    };
+
     tokenizer.attempt(
         State::Next(StateName::DocumentContainerNewAfter),
         State::Next(StateName::DocumentContainerNewBeforeNotBlockQuote),
@@ -233,7 +264,8 @@ pub fn container_new_before_not_list(tokenizer: &mut Tokenizer) -> State {
 ///     ^
 /// ```
 pub fn container_new_before_not_footnote_definition(tokenizer: &mut Tokenizer) -> State {
-    // It wasn’t a new block quote, list item, or footnote definition.
+    // It wasn’t new details, a block quote, list item, or footnote
+    // definition.
     // Swap the new container (in the middle) with the existing one (at the end).
     // Drop what was in the middle.
     tokenizer
@@ -253,7 +285,7 @@ pub fn container_new_before_not_footnote_definition(tokenizer: &mut Tokenizer) -
 ///       ^
 /// ```
 pub fn container_new_after(tokenizer: &mut Tokenizer) -> State {
-    // It was a new block quote, list item, or footnote definition.
+    // It was new details, a block quote, list item, or footnote definition.
     // Swap the new container (in the middle) with the existing one (at the end).
     // Take the new container.
     let container = tokenizer
@@ -288,15 +320,68 @@ pub fn container_new_after(tokenizer: &mut Tokenizer) -> State {
     State::Retry(StateName::DocumentContainerNewBefore)
 }
 
-/// After containers, at flow.
+/// After containers, at comment line or flow.
 //
 /// ```markdown
 /// > | * a
 ///       ^
-/// > | > b
+/// > | > % b
 ///       ^
 /// ```
 pub fn containers_after(tokenizer: &mut Tokenizer) -> State {
+    // Comment lines are not found in raw flow, such as code (fenced).
+    if tokenizer.options().line_comment_prefix.is_some()
+        && !tokenizer
+            .tokenize_state
+            .document_child
+            .as_ref()
+            .unwrap()
+            .concrete
+    {
+        tokenizer.attempt(
+            State::Next(StateName::DocumentCommentLineAfter),
+            State::Next(StateName::DocumentFlowBefore),
+        );
+        State::Retry(StateName::CommentLineStart)
+    } else {
+        State::Retry(StateName::DocumentFlowBefore)
+    }
+}
+
+/// After comment line, which includes its line ending.
+///
+/// The line is not passed to flow, as if it was never there: it continues
+/// containers, and does not change whether the next line can be lazy.
+///
+/// ```markdown
+///   | > % a
+/// > | > b
+///     ^
+/// ```
+pub fn comment_line_after(tokenizer: &mut Tokenizer) -> State {
+    let line = tokenizer.events[tokenizer.events.len() - 2].point.line;
+    let child = tokenizer.tokenize_state.document_child.as_mut().unwrap();
+    child.define_skip_line(line, &tokenizer.point);
+
+    if tokenizer.current.is_none() {
+        child.lazy = false;
+        State::Retry(StateName::DocumentFlowEnd)
+    } else {
+        tokenizer.tokenize_state.document_continued = 0;
+        tokenizer.interrupt = false;
+        State::Retry(StateName::DocumentContainerExistingBefore)
+    }
+}
+
+/// At flow.
+//
+/// ```markdown
+/// > | * a
+///       ^
+/// > | > b
+///       ^
+/// ```
+pub fn flow_before(tokenizer: &mut Tokenizer) -> State {
     let child = tokenizer.tokenize_state.document_child.as_mut().unwrap();
 
     child.lazy = tokenizer.tokenize_state.document_continued
@@ -486,7 +571,11 @@ fn exit_containers(tokenizer: &mut Tokenizer, phase: &Phase) -> Result<(), Strin
             .take()
             .unwrap_or(State::Next(StateName::FlowStart));
 
-        child.flush(state, false)?;
+        let mut result = child.flush(state, false)?;
+        tokenizer
+            .tokenize_state
+            .abbreviations
+            .append(&mut result.abbreviations);
     }
 
     if !stack_close.is_empty() {
@@ -497,6 +586,7 @@ fn exit_containers(tokenizer: &mut Tokenizer, phase: &Phase) -> Result<(), Strin
         while let Some(container) = stack_close.pop() {
             let name = match container.kind {
                 Container::BlockQuote => Name::BlockQuote,
+                Container::Details => Name::Details,
                 Container::GfmFootnoteDefinition => Name::GfmFootnoteDefinition,
                 Container::ListItem => Name::ListItem,
             };
@@ -0,0 +1,103 @@
+//! Emoji occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Emoji forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: the shortcode must be one of the known shortcodes.
+//! emoji ::= ':' 1*28(ascii_alphanumeric | '_' | '+' | '-') ':'
+//! ```
+//!
+//! Like much of markdown, there are no “invalid” emoji.
+//! When an unknown shortcode is found (such as `:nope:`), it is not an emoji
+//! but instead left as data.
+//! The list of known shortcodes is a selection of common ones from GitHub, see
+//! [`EMOJI_SHORTCODES`][emoji_shortcodes].
+//!
+//! As emoji occur in text, they are not recognized in code (text), code
+//! (fenced), and the like.
+//!
+//! ## HTML
+//!
+//! Emoji compile to the emoji they represent.
+//!
+//! ## Tokens
+//!
+//! *   [`Emoji`][Name::Emoji]
+//! *   [`EmojiMarker`][Name::EmojiMarker]
+//! *   [`EmojiShortcode`][Name::EmojiShortcode]
+//!
+//! ## References
+//!
+//! *   [`github/gemoji`](https://github.com/github/gemoji)
+//!
+//! [text]: crate::construct::text
+//! [emoji_shortcodes]: crate::util::constant::EMOJI_SHORTCODES
+
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::{constant::EMOJI_SHORTCODE_SIZE_MAX, emoji::decode, slice::Slice};
+
+/// Start of emoji.
+///
+/// ```markdown
+/// > | a :smile: b
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.constructs().emoji && tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::Emoji);
+        tokenizer.enter(Name::EmojiMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::EmojiMarker);
+        tokenizer.enter(Name::EmojiShortcode);
+        State::Next(StateName::EmojiShortcode)
+    } else {
+        State::Nok
+    }
+}
+
+/// In shortcode, before `:`.
+///
+/// ```markdown
+/// > | a :smile: b
+///        ^^^^^
+/// ```
+pub fn shortcode(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b':') if tokenizer.tokenize_state.size > 0 => {
+            // Guaranteed to be valid ASCII bytes.
+            let slice = Slice::from_indices(
+                tokenizer.parse_state.bytes,
+                tokenizer.point.index - tokenizer.tokenize_state.size,
+                tokenizer.point.index,
+            );
+            tokenizer.tokenize_state.size = 0;
+
+            if decode(slice.as_str()).is_none() {
+                return State::Nok;
+            }
+
+            tokenizer.exit(Name::EmojiShortcode);
+            tokenizer.enter(Name::EmojiMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::EmojiMarker);
+            tokenizer.exit(Name::Emoji);
+            State::Ok
+        }
+        Some(b'+' | b'-' | b'0'..=b'9' | b'A'..=b'Z' | b'_' | b'a'..=b'z')
+            if tokenizer.tokenize_state.size < EMOJI_SHORTCODE_SIZE_MAX =>
+        {
+            tokenizer.tokenize_state.size += 1;
+            tokenizer.consume();
+            State::Next(StateName::EmojiShortcode)
+        }
+        _ => {
+            tokenizer.tokenize_state.size = 0;
+            State::Nok
+        }
+    }
+}
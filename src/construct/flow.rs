@@ -10,6 +10,7 @@
 //!
 //! The constructs found in flow are:
 //!
+//! *   [Abbreviation definition][crate::construct::abbreviation_definition]
 //! *   [Blank line][crate::construct::blank_line]
 //! *   [Code (indented)][crate::construct::code_indented]
 //! *   [Heading (atx)][crate::construct::heading_atx]
@@ -44,7 +45,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::HeadingAtxStart)
         }
-        Some(b'$' | b'`' | b'~') => {
+        Some(b'$' | b'`' | b'\\' | b'~') => {
             tokenizer.attempt(
                 State::Next(StateName::FlowAfter),
                 State::Next(StateName::FlowBeforeContent),
@@ -56,7 +57,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
         Some(b'*' | b'_') => {
             tokenizer.attempt(
                 State::Next(StateName::FlowAfter),
-                State::Next(StateName::FlowBeforeContent),
+                State::Next(StateName::FlowBeforeAbbreviationDefinition),
             );
             State::Retry(StateName::ThematicBreakStart)
         }
@@ -88,6 +89,20 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
+/// At abbreviation definition.
+///
+/// ```markdown
+/// > | *[a]: b
+///     ^
+/// ```
+pub fn before_abbreviation_definition(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::FlowAfter),
+        State::Next(StateName::FlowBeforeContent),
+    );
+    State::Retry(StateName::AbbreviationDefinitionStart)
+}
+
 /// At blank line.
 ///
 /// ```markdown
@@ -161,9 +161,7 @@ use alloc::vec::Vec;
 /// ```
 pub fn protocol_start(tokenizer: &mut Tokenizer) -> State {
     if tokenizer
-        .parse_state
-        .options
-        .constructs
+        .constructs()
         .gfm_autolink_literal &&
         matches!(tokenizer.current, Some(b'H' | b'h'))
             // Source: <https://github.com/github/cmark-gfm/blob/ef1cfcb/extensions/autolink.c#L214>.
@@ -265,9 +263,7 @@ pub fn protocol_slashes_inside(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn www_start(tokenizer: &mut Tokenizer) -> State {
     if tokenizer
-        .parse_state
-        .options
-        .constructs
+        .constructs()
         .gfm_autolink_literal &&
         matches!(tokenizer.current, Some(b'W' | b'w'))
             // Source: <https://github.com/github/cmark-gfm/blob/ef1cfcb/extensions/autolink.c#L156>.
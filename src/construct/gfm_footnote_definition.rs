@@ -183,12 +183,7 @@ use crate::util::{
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer
-        .parse_state
-        .options
-        .constructs
-        .gfm_footnote_definition
-    {
+    if tokenizer.constructs().gfm_footnote_definition {
         tokenizer.enter(Name::GfmFootnoteDefinition);
 
         if matches!(tokenizer.current, Some(b'\t' | b' ')) {
@@ -199,7 +194,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             State::Retry(space_or_tab_min_max(
                 tokenizer,
                 1,
-                if tokenizer.parse_state.options.constructs.code_indented {
+                if tokenizer.constructs().is_enabled(&Name::CodeIndented) {
                     TAB_SIZE - 1
                 } else {
                     usize::MAX
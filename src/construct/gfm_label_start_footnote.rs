@@ -49,13 +49,7 @@ use crate::tokenizer::{LabelKind, LabelStart, Tokenizer};
 ///       ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer
-        .parse_state
-        .options
-        .constructs
-        .gfm_label_start_footnote
-        && tokenizer.current == Some(b'[')
-    {
+    if tokenizer.constructs().gfm_label_start_footnote && tokenizer.current == Some(b'[') {
         tokenizer.enter(Name::GfmFootnoteCallLabel);
         tokenizer.enter(Name::LabelMarker);
         tokenizer.consume();
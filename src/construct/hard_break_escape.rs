@@ -56,9 +56,7 @@ use crate::tokenizer::Tokenizer;
 ///   | b
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.hard_break_escape
-        && tokenizer.current == Some(b'\\')
-    {
+    if tokenizer.constructs().hard_break_escape && tokenizer.current == Some(b'\\') {
         tokenizer.enter(Name::HardBreakEscape);
         tokenizer.consume();
         State::Next(StateName::HardBreakEscapeAfter)
@@ -12,6 +12,13 @@
 //! As this construct occurs in flow, like all flow constructs, it must be
 //! followed by an eol (line ending) or eof (end of file).
 //!
+//! Whitespace before and after the text is not part of it, so `#  a  ` is a
+//! heading with the text `a`.
+//! A heading with only whitespace (`#  `) is an empty heading.
+//! To keep the whitespace after the text in
+//! [`HeadingAtxText`][Name::HeadingAtxText], such as for formatters, pass
+//! [`heading_atx_trailing_whitespace`][crate::ParseOptions::heading_atx_trailing_whitespace].
+//!
 //! `CommonMark` introduced the requirement on whitespace existing after the
 //! opening sequence and before text.
 //! In older markdown versions, this was not required, and headings would form
@@ -78,14 +85,14 @@ use alloc::vec;
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.heading_atx {
+    if tokenizer.constructs().heading_atx {
         tokenizer.enter(Name::HeadingAtx);
         if matches!(tokenizer.current, Some(b'\t' | b' ')) {
             tokenizer.attempt(State::Next(StateName::HeadingAtxBefore), State::Nok);
             State::Retry(space_or_tab_min_max(
                 tokenizer,
                 0,
-                if tokenizer.parse_state.options.constructs.code_indented {
+                if tokenizer.constructs().is_enabled(&Name::CodeIndented) {
                     TAB_SIZE - 1
                 } else {
                     usize::MAX
@@ -230,7 +237,16 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
             } else {
                 if let Some(start) = data_start {
                     // If `start` is some, `end` is too.
-                    let end = data_end.unwrap();
+                    let mut end = data_end.unwrap();
+
+                    // Include whitespace before the closing sequence or eol,
+                    // if configured.
+                    if tokenizer.options().heading_atx_trailing_whitespace
+                        && tokenizer.events[end + 1].name == Name::SpaceOrTab
+                    {
+                        end += 2;
+                        tokenizer.events[end].name = Name::Data;
+                    }
 
                     tokenizer.map.add(
                         start,
@@ -87,7 +87,7 @@ use alloc::vec;
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.heading_setext
+    if tokenizer.constructs().heading_setext
         && !tokenizer.lazy
         && !tokenizer.pierce
         // Require a paragraph before.
@@ -106,7 +106,8 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             State::Retry(space_or_tab_min_max(
                 tokenizer,
                 0,
-                if tokenizer.parse_state.options.constructs.code_indented {
+                // Without indented code, the underline can be indented further.
+                if tokenizer.constructs().is_enabled(&Name::CodeIndented) {
                     TAB_SIZE - 1
                 } else {
                     usize::MAX
@@ -197,7 +198,12 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
         let paragraph_exit_before = skip::opt_back(
             &tokenizer.events,
             enter - 1,
-            &[Name::SpaceOrTab, Name::LineEnding, Name::BlockQuotePrefix],
+            &[
+                Name::SpaceOrTab,
+                Name::LineEnding,
+                Name::BlockQuotePrefix,
+                Name::CommentLine,
+            ],
         );
 
         // There’s a paragraph before: this is a setext heading.
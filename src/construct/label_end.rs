@@ -31,6 +31,12 @@
 //! of the definition after normalizing with
 //! [`normalize_identifier`][normalize_identifier].
 //!
+//! Like the labels of definitions, the text of collapsed and shortcut
+//! references can be at most `999` characters long, see
+//! [`LINK_REFERENCE_SIZE_MAX`][link_reference_size_max].
+//! Longer text is not matched, which also keeps deeply nested brackets
+//! (`[[[[a]]]]`) fast.
+//!
 //! Importantly, while the label of a full reference *can* include [string][]
 //! content, and in case of collapsed and shortcut references even [text][]
 //! content, that content is not considered when matching.
@@ -175,6 +181,7 @@
 //! [autolink]: crate::construct::autolink
 //! [sanitize_uri]: crate::util::sanitize_uri::sanitize
 //! [normalize_identifier]: crate::util::normalize_identifier::normalize_identifier
+//! [link_reference_size_max]: crate::util::constant::LINK_REFERENCE_SIZE_MAX
 //! [html_a]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-a-element
 //! [html_img]: https://html.spec.whatwg.org/multipage/embedded-content.html#the-img-element
 //! [html_sup]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-sub-and-sup-elements
@@ -186,10 +193,11 @@ use crate::state::{Name as StateName, State};
 use crate::subtokenize::Subresult;
 use crate::tokenizer::{Label, LabelKind, LabelStart, Tokenizer};
 use crate::util::{
-    constant::RESOURCE_DESTINATION_BALANCE_MAX,
+    constant::{LINK_REFERENCE_SIZE_MAX, RESOURCE_DESTINATION_BALANCE_MAX},
     normalize_identifier::normalize_identifier,
     skip,
     slice::{Position, Slice},
+    warning::undefined_reference,
 };
 use alloc::{string::String, vec};
 
@@ -205,7 +213,7 @@ use alloc::{string::String, vec};
 /// > | [a] b
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if Some(b']') == tokenizer.current && tokenizer.parse_state.options.constructs.label_end {
+    if Some(b']') == tokenizer.current && tokenizer.constructs().label_end {
         // If there is an okay opening:
         if !tokenizer.tokenize_state.label_starts.is_empty() {
             let label_start = tokenizer.tokenize_state.label_starts.last().unwrap();
@@ -258,26 +266,67 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
         tokenizer.events[tokenizer.tokenize_state.end].point.index,
     );
 
+    // Labels are capped, so longer text cannot match a definition.
+    // Not normalizing it keeps deeply nested brackets (`[[[a]]]`) linear.
     // We don’t care about virtual spaces, so `indices` and `as_str` are fine.
-    let mut id = normalize_identifier(
-        Slice::from_indices(tokenizer.parse_state.bytes, indices.0, indices.1).as_str(),
-    );
+    let mut id = if indices.1 - indices.0 > LINK_REFERENCE_SIZE_MAX {
+        None
+    } else {
+        Some(normalize_identifier(
+            Slice::from_indices(tokenizer.parse_state.bytes, indices.0, indices.1).as_str(),
+        ))
+    };
 
     // See if this matches a footnote definition.
     if start.kind == LabelKind::GfmFootnote {
-        if tokenizer.parse_state.gfm_footnote_definitions.contains(&id) {
-            return State::Retry(StateName::LabelEndOk);
+        if let Some(id) = &id {
+            if tokenizer.parse_state.gfm_footnote_definitions.contains(id) {
+                return State::Retry(StateName::LabelEndOk);
+            }
         }
 
         // Nope, this might be a normal link?
         tokenizer.tokenize_state.label_starts[start_index].kind = LabelKind::GfmUndefinedFootnote;
-        let mut new_id = String::new();
-        new_id.push('^');
-        new_id.push_str(&id);
-        id = new_id;
+        id = id.map(|id| {
+            let mut new_id = String::new();
+            new_id.push('^');
+            new_id.push_str(&id);
+            new_id
+        });
     }
 
-    let defined = tokenizer.parse_state.definitions.contains(&id);
+    // Labels of shortcut and collapsed references are not parsed by
+    // `partial_label`, so check for line endings here.
+    let defined = id.map_or(false, |id| tokenizer.parse_state.definitions.contains(&id))
+        && (tokenizer.options().multiline_labels
+            || !tokenizer.parse_state.bytes[indices.0..indices.1]
+                .iter()
+                .any(|byte| matches!(byte, b'\n' | b'\r')));
+
+    // Warn for collapsed (`[asd][]`) and shortcut (`[asd]`) references that
+    // are not defined.
+    // Full references are checked when their label is parsed, and empty
+    // labels (such as the `[]` of a collapsed reference) are never references.
+    if !defined
+        && indices.1 > indices.0
+        && indices.1 - indices.0 <= LINK_REFERENCE_SIZE_MAX
+        && match tokenizer.current {
+            Some(b'(') => false,
+            Some(b'[') => tokenizer.parse_state.bytes.get(tokenizer.point.index + 1) == Some(&b']'),
+            _ => true,
+        }
+    {
+        let start = &tokenizer.tokenize_state.label_starts[start_index];
+        let mut label = String::new();
+        if start.kind == LabelKind::GfmUndefinedFootnote {
+            label.push('^');
+        }
+        label.push_str(
+            Slice::from_indices(tokenizer.parse_state.bytes, indices.0, indices.1).as_str(),
+        );
+        let enter = start.start.0;
+        warn_undefined(tokenizer, &label, enter, tokenizer.events.len() - 1);
+    }
 
     match tokenizer.current {
         // Resource (`[asd](fgh)`)?
@@ -351,14 +400,20 @@ pub fn ok(tokenizer: &mut Tokenizer) -> State {
     // longer viable for use (as they would otherwise contain a link).
     // These link starts are still looking for balanced closing brackets, so
     // we can’t remove them, but we can mark them.
+    // Starts before an inactive link start are already marked, so we can stop
+    // there, which keeps many links after many brackets linear.
     if label_start.kind != LabelKind::Image {
-        let mut index = 0;
-        while index < tokenizer.tokenize_state.label_starts.len() {
+        let mut index = tokenizer.tokenize_state.label_starts.len();
+        while index > 0 {
+            index -= 1;
             let label_start = &mut tokenizer.tokenize_state.label_starts[index];
             if label_start.kind != LabelKind::Image {
+                if label_start.inactive {
+                    break;
+                }
+
                 label_start.inactive = true;
             }
-            index += 1;
         }
     }
 
@@ -590,27 +645,28 @@ pub fn reference_full_after(tokenizer: &mut Tokenizer) -> State {
     tokenizer.tokenize_state.token_2 = Name::Data;
     tokenizer.tokenize_state.token_3 = Name::Data;
 
+    let string_exit = skip::to_back(
+        &tokenizer.events,
+        tokenizer.events.len() - 1,
+        &[Name::ReferenceString],
+    );
+    // We don’t care about virtual spaces, so `as_str` is fine.
+    let slice = Slice::from_position(
+        tokenizer.parse_state.bytes,
+        &Position::from_exit_event(&tokenizer.events, string_exit),
+    );
+    let label = slice.as_str();
+
     if tokenizer
         .parse_state
         .definitions
-        // We don’t care about virtual spaces, so `as_str` is fine.
-        .contains(&normalize_identifier(
-            Slice::from_position(
-                tokenizer.parse_state.bytes,
-                &Position::from_exit_event(
-                    &tokenizer.events,
-                    skip::to_back(
-                        &tokenizer.events,
-                        tokenizer.events.len() - 1,
-                        &[Name::ReferenceString],
-                    ),
-                ),
-            )
-            .as_str(),
-        ))
+        .contains(&normalize_identifier(label))
     {
         State::Ok
     } else {
+        let exit = tokenizer.events.len() - 1;
+        let enter = skip::to_back(&tokenizer.events, exit - 1, &[Name::Reference]);
+        warn_undefined(tokenizer, label, enter, exit);
         State::Nok
     }
 }
@@ -667,6 +723,23 @@ pub fn reference_collapsed_open(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
+/// Warn for an undefined reference, from the event at `enter` to the one at
+/// `exit`, unless that was already done.
+fn warn_undefined(tokenizer: &mut Tokenizer, label: &str, enter: usize, exit: usize) {
+    let start = &tokenizer.events[enter];
+    let end = &tokenizer.events[exit];
+
+    // Attempts can try the same reference again.
+    if tokenizer
+        .tokenize_state
+        .warned_references
+        .insert((start.point.index, end.point.index))
+    {
+        let warning = undefined_reference(label, start, end);
+        tokenizer.tokenize_state.warnings.push(warning);
+    }
+}
+
 /// Resolve images, links, and footnotes.
 ///
 /// This turns matching label starts and label ends into links, images, and
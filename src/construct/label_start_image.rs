@@ -44,8 +44,7 @@ use crate::tokenizer::{LabelKind, LabelStart, Tokenizer};
 ///       ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.label_start_image && tokenizer.current == Some(b'!')
-    {
+    if tokenizer.constructs().label_start_image && tokenizer.current == Some(b'!') {
         tokenizer.enter(Name::LabelImage);
         tokenizer.enter(Name::LabelImageMarker);
         tokenizer.consume();
@@ -100,10 +99,8 @@ pub fn open(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn after(tokenizer: &mut Tokenizer) -> State {
     if tokenizer
-        .parse_state
-        .options
-        .constructs
-        .gfm_label_start_footnote
+        .constructs()
+        .is_enabled(&Name::GfmFootnoteCallLabel)
         && tokenizer.current == Some(b'^')
     {
         State::Nok
@@ -25,6 +25,11 @@
 //! As list item is a container, it takes several bytes from the start of the
 //! line, while the rest of the line includes more containers or flow.
 //!
+//! The whitespace after the marker is a separate
+//! [`SpaceOrTab`][Name::SpaceOrTab] in the prefix.
+//! The content column of the list item, which further lines must be indented
+//! to, is the width of the marker plus the width of that whitespace.
+//!
 //! ## HTML
 //!
 //! List item relates to the `<li>`, `<ol>`, and `<ul>` elements in HTML.
@@ -66,8 +71,8 @@ use crate::subtokenize::Subresult;
 use crate::tokenizer::Tokenizer;
 use crate::util::{
     constant::{LIST_ITEM_VALUE_SIZE_MAX, TAB_SIZE},
+    infer::{list_item_marker, ListItemMarker},
     skip,
-    slice::{Position, Slice},
 };
 use alloc::{vec, vec::Vec};
 
@@ -78,7 +83,7 @@ use alloc::{vec, vec::Vec};
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.list_item {
+    if tokenizer.constructs().list_item {
         tokenizer.enter(Name::ListItem);
 
         if matches!(tokenizer.current, Some(b'\t' | b' ')) {
@@ -86,7 +91,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             State::Retry(space_or_tab_min_max(
                 tokenizer,
                 0,
-                if tokenizer.parse_state.options.constructs.code_indented {
+                if tokenizer.constructs().is_enabled(&Name::CodeIndented) {
                     TAB_SIZE - 1
                 } else {
                     usize::MAX
@@ -285,14 +290,9 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
             tokenizer.events.len() - 1,
             &[Name::ListItem],
         );
-        let mut prefix = Slice::from_position(
-            tokenizer.parse_state.bytes,
-            &Position {
-                start: &tokenizer.events[start].point,
-                end: &tokenizer.point,
-            },
-        )
-        .len();
+        // Columns expand tabs to the next tab stop, so this is the width of
+        // the prefix, not its size in bytes.
+        let mut prefix = tokenizer.point().column - tokenizer.events[start].point.column;
 
         if blank {
             prefix += 1;
@@ -372,8 +372,8 @@ pub fn cont_filled(tokenizer: &mut Tokenizer) -> State {
 
 /// Find adjacent list items with the same marker.
 pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
-    let mut lists_wip: Vec<(u8, usize, usize, usize)> = vec![];
-    let mut lists: Vec<(u8, usize, usize, usize)> = vec![];
+    let mut lists_wip: Vec<(ListItemMarker, usize, usize, usize)> = vec![];
+    let mut lists: Vec<(ListItemMarker, usize, usize, usize)> = vec![];
     let mut index = 0;
     let mut balance = 0;
 
@@ -384,9 +384,10 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
         if event.name == Name::ListItem {
             if event.kind == Kind::Enter {
                 let end = skip::opt(&tokenizer.events, index, &[Name::ListItem]) - 1;
-                let marker = skip::to(&tokenizer.events, index, &[Name::ListItemMarker]);
-                // Guaranteed to be a valid ASCII byte.
-                let marker = tokenizer.parse_state.bytes[tokenizer.events[marker].point.index];
+                // Guaranteed to be a list item with a valid marker.
+                let marker =
+                    list_item_marker(&tokenizer.events, tokenizer.parse_state.bytes, index)
+                        .unwrap();
                 let current = (marker, balance, index, end);
 
                 let mut list_index = lists_wip.len();
@@ -403,6 +404,7 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                             Name::LineEnding,
                             Name::BlankLineEnding,
                             Name::BlockQuotePrefix,
+                            Name::CommentLine,
                         ],
                     );
 
@@ -456,9 +458,10 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
         let list_item = &lists[index];
         let mut list_start = tokenizer.events[list_item.2].clone();
         let mut list_end = tokenizer.events[list_item.3].clone();
-        let name = match list_item.0 {
-            b'.' | b')' => Name::ListOrdered,
-            _ => Name::ListUnordered,
+        let name = if list_item.0.ordered() {
+            Name::ListOrdered
+        } else {
+            Name::ListUnordered
         };
         list_start.name = name.clone();
         list_end.name = name;
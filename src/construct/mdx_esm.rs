@@ -44,9 +44,9 @@ use alloc::format;
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
     // If it’s turned on.
-    if tokenizer.parse_state.options.constructs.mdx_esm
+    if tokenizer.constructs().mdx_esm
         // If there is a gnostic parser.
-        && tokenizer.parse_state.options.mdx_esm_parse.is_some()
+        && tokenizer.options().mdx_esm_parse.is_some()
         // When not interrupting.
         && !tokenizer.interrupt
         // Only at the start of a line, not at whitespace or in a container.
@@ -186,12 +186,7 @@ pub fn at_end(tokenizer: &mut Tokenizer) -> State {
 /// Parse ESM with a given function.
 fn parse_esm(tokenizer: &mut Tokenizer) -> State {
     // We can `unwrap` because we don’t parse if this is `None`.
-    let parse = tokenizer
-        .parse_state
-        .options
-        .mdx_esm_parse
-        .as_ref()
-        .unwrap();
+    let parse = tokenizer.options().mdx_esm_parse.as_ref().unwrap();
 
     // Collect the body of the ESM and positional info for each run of it.
     let result = collect(
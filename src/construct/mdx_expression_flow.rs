@@ -47,14 +47,14 @@ use crate::util::constant::TAB_SIZE;
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.mdx_expression_flow {
+    if tokenizer.constructs().mdx_expression_flow {
         tokenizer.tokenize_state.token_1 = Name::MdxFlowExpression;
         if matches!(tokenizer.current, Some(b'\t' | b' ')) {
             tokenizer.attempt(State::Next(StateName::MdxExpressionFlowBefore), State::Nok);
             State::Retry(space_or_tab_min_max(
                 tokenizer,
                 0,
-                if tokenizer.parse_state.options.constructs.code_indented {
+                if tokenizer.constructs().is_enabled(&Name::CodeIndented) {
                     TAB_SIZE - 1
                 } else {
                     usize::MAX
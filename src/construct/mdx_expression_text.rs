@@ -41,9 +41,7 @@ use crate::tokenizer::Tokenizer;
 ///       ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if Some(b'{') == tokenizer.current
-        && tokenizer.parse_state.options.constructs.mdx_expression_text
-    {
+    if Some(b'{') == tokenizer.current && tokenizer.constructs().mdx_expression_text {
         tokenizer.tokenize_state.token_1 = Name::MdxTextExpression;
         tokenizer.attempt(State::Next(StateName::MdxExpressionTextAfter), State::Nok);
         State::Retry(StateName::MdxExpressionStart)
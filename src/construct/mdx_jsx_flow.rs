@@ -49,7 +49,7 @@ use crate::util::constant::TAB_SIZE;
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.mdx_jsx_flow {
+    if tokenizer.constructs().mdx_jsx_flow {
         tokenizer.tokenize_state.token_1 = Name::MdxJsxFlowTag;
         tokenizer.concrete = true;
         if matches!(tokenizer.current, Some(b'\t' | b' ')) {
@@ -57,7 +57,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             State::Retry(space_or_tab_min_max(
                 tokenizer,
                 0,
-                if tokenizer.parse_state.options.constructs.code_indented {
+                if tokenizer.constructs().is_enabled(&Name::CodeIndented) {
                     TAB_SIZE - 1
                 } else {
                     usize::MAX
@@ -41,7 +41,7 @@ use crate::tokenizer::Tokenizer;
 ///       ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if Some(b'<') == tokenizer.current && tokenizer.parse_state.options.constructs.mdx_jsx_text {
+    if Some(b'<') == tokenizer.current && tokenizer.constructs().mdx_jsx_text {
         tokenizer.tokenize_state.token_1 = Name::MdxJsxTextTag;
         tokenizer.attempt(
             State::Next(StateName::MdxJsxTextAfter),
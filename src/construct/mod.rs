@@ -34,7 +34,7 @@
 //!
 //! The following constructs are found in markdown (`CommonMark`):
 //!
-//! *   [attention][attention] (strong, emphasis, extension: GFM strikethrough)
+//! *   [attention][attention] (strong, emphasis, extension: GFM strikethrough, subscript, superscript)
 //! *   [autolink][]
 //! *   [blank line][blank_line]
 //! *   [block quote][block_quote]
@@ -62,6 +62,10 @@
 //!
 //! The following constructs are extensions found in markdown:
 //!
+//! *   [abbreviation definition][abbreviation_definition]
+//! *   [comment line][comment_line]
+//! *   [details][]
+//! *   [emoji][]
 //! *   [frontmatter][]
 //! *   [gfm autolink literal][gfm_autolink_literal]
 //! *   [gfm footnote definition][gfm_footnote_definition]
@@ -73,6 +77,7 @@
 //! *   [mdx expression (text)][mdx_expression_text]
 //! *   [mdx jsx (flow)][mdx_jsx_flow]
 //! *   [mdx jsx (text)][mdx_jsx_text]
+//! *   [text construct][text_construct] (custom)
 //!
 //! There are also several small subroutines typically used in different places:
 //!
@@ -148,6 +153,7 @@
 //!
 //! [bnf]: http://trevorjim.com/a-specification-for-markdown/
 
+pub mod abbreviation_definition;
 pub mod attention;
 pub mod autolink;
 pub mod blank_line;
@@ -155,9 +161,12 @@ pub mod block_quote;
 pub mod character_escape;
 pub mod character_reference;
 pub mod code_indented;
+pub mod comment_line;
 pub mod content;
 pub mod definition;
+pub mod details;
 pub mod document;
+pub mod emoji;
 pub mod flow;
 pub mod frontmatter;
 pub mod gfm_autolink_literal;
@@ -195,4 +204,5 @@ pub mod raw_flow;
 pub mod raw_text;
 pub mod string;
 pub mod text;
+pub mod text_construct;
 pub mod thematic_break;
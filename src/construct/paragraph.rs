@@ -41,9 +41,8 @@
 //! [raw_text]: crate::construct::raw_text
 //! [html]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-p-element
 
-use crate::event::{Content, Link, Name};
+use crate::event::{Content, Name};
 use crate::state::{Name as StateName, State};
-use crate::subtokenize::link;
 use crate::tokenizer::Tokenizer;
 
 /// Paragraph start.
@@ -69,21 +68,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn line_start(tokenizer: &mut Tokenizer) -> State {
     debug_assert!(tokenizer.current.is_some());
-    tokenizer.enter_link(
-        Name::Data,
-        Link {
-            previous: None,
-            next: None,
-            content: Content::Text,
-        },
-    );
-
-    if tokenizer.tokenize_state.connect {
-        let index = tokenizer.events.len() - 1;
-        link(&mut tokenizer.events, index);
-    } else {
-        tokenizer.tokenize_state.connect = true;
-    }
+    tokenizer.enter_with_content(Name::Data, Content::Text);
 
     State::Retry(StateName::ParagraphInside)
 }
@@ -64,6 +64,9 @@ pub fn inside(tokenizer: &mut Tokenizer) -> State {
     if let Some(byte) = tokenizer.current {
         if byte != b'\n' && !tokenizer.tokenize_state.markers.contains(&byte) {
             tokenizer.consume();
+            // Move over the following plain bytes at once.
+            let size = plain_size(tokenizer.bytes_ahead(), tokenizer.tokenize_state.markers);
+            tokenizer.consume_ahead(size);
             return State::Next(StateName::DataInside);
         }
     }
@@ -108,3 +111,87 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     tokenizer.map.consume(&mut tokenizer.events);
     None
 }
+
+/// Get the number of plain bytes at the start of `bytes`.
+///
+/// Plain bytes are ASCII bytes that are not line endings, tabs, or one of
+/// `markers`, which data consumes without anything else happening.
+/// Other bytes are left to the state machine.
+#[cfg(not(feature = "swar"))]
+fn plain_size(bytes: &[u8], markers: &[u8]) -> usize {
+    plain_size_scalar(bytes, markers)
+}
+
+/// Get the number of plain bytes at the start of `bytes`, a word at a time.
+///
+/// This is SWAR (SIMD within a register): each byte in a `usize` is checked
+/// at once with integer operations.
+/// See [`plain_size_scalar`][] for a byte at a time.
+#[cfg(feature = "swar")]
+fn plain_size(bytes: &[u8], markers: &[u8]) -> usize {
+    const SIZE: usize = core::mem::size_of::<usize>();
+    /// `0x01` in every byte.
+    const ONES: usize = usize::MAX / 0xFF;
+    /// `0x80` in every byte.
+    const HIGHS: usize = ONES * 0x80;
+
+    let mut index = 0;
+
+    while index + SIZE <= bytes.len() {
+        let mut word = [0; SIZE];
+        word.copy_from_slice(&bytes[index..index + SIZE]);
+        let word = usize::from_ne_bytes(word);
+        // Non-ASCII.
+        let mut found = word & HIGHS;
+
+        for byte in [b'\t', b'\n', b'\r'].iter().chain(markers) {
+            // Bytes equal to `byte` are zero after `xor`, which sets their
+            // high bit here.
+            let xor = word ^ (ONES * usize::from(*byte));
+            found |= xor.wrapping_sub(ONES) & !xor & HIGHS;
+        }
+
+        if found != 0 {
+            break;
+        }
+
+        index += SIZE;
+    }
+
+    index + plain_size_scalar(&bytes[index..], markers)
+}
+
+/// Get the number of plain bytes at the start of `bytes`, a byte at a time.
+fn plain_size_scalar(bytes: &[u8], markers: &[u8]) -> usize {
+    bytes
+        .iter()
+        .position(|byte| {
+            !byte.is_ascii() || matches!(byte, b'\t' | b'\n' | b'\r') || markers.contains(byte)
+        })
+        .unwrap_or(bytes.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{plain_size, plain_size_scalar};
+    use crate::construct::text::MARKERS;
+
+    #[test]
+    fn test_plain_size() {
+        // Prose, with things that stop plain bytes at different places in
+        // words.
+        let value = "# Alpha *beta* gamma ##\n\nDelta epsilon zeta eta theta iota kappa lambda mu nu xi omicron pi rho.\nSigma\ttau upsilon phi chi psi omega, [a](b) and `c` and &amp; and <d> and ~e~.\r\n\n> Ä block quote with non-ASCII — characters, like “quotes”, in it.\n> And a lazy\nline.\n\n| Alpha | Beta gamma delta |\n| - | - |\n| Epsilon zeta | Eta \\| theta |\n\n- List item\n  continued\twith a tab.\n\n";
+        let bytes = value.as_bytes();
+        let mut index = 0;
+
+        while index < bytes.len() {
+            assert_eq!(
+                plain_size(&bytes[index..], &MARKERS),
+                plain_size_scalar(&bytes[index..], &MARKERS),
+                "should find the same plain bytes as a byte at a time (at {})",
+                index
+            );
+            index += 1;
+        }
+    }
+}
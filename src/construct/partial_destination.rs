@@ -25,6 +25,10 @@
 //! before it.
 //! Escaped parens do not count in balancing.
 //!
+//! When [`literal_backslash_in_destinations`][literal_backslash] is on,
+//! backslashes are literal: they do not escape anything, in the destination
+//! itself or in the [string][] content type.
+//!
 //! The destination is interpreted as the [string][] content type.
 //! That means that [character escapes][character_escape] and
 //! [character references][character_reference] are allowed.
@@ -75,6 +79,7 @@
 //! *   [`micromark-factory-destination/index.js` in `micromark`](https://github.com/micromark/micromark/blob/main/packages/micromark-factory-destination/dev/index.js)
 //!
 //! [definition]: crate::construct::definition
+//! [literal_backslash]: crate::ParseOptions::literal_backslash_in_destinations
 //! [string]: crate::construct::string
 //! [character_escape]: crate::construct::character_escape
 //! [character_reference]: crate::construct::character_reference
@@ -164,7 +169,7 @@ pub fn enclosed(tokenizer: &mut Tokenizer) -> State {
             tokenizer.exit(tokenizer.tokenize_state.token_5.clone());
             State::Retry(StateName::DestinationEnclosedBefore)
         }
-        Some(b'\\') => {
+        Some(b'\\') if !tokenizer.options().literal_backslash_in_destinations => {
             tokenizer.consume();
             State::Next(StateName::DestinationEnclosedEscape)
         }
@@ -225,7 +230,9 @@ pub fn raw(tokenizer: &mut Tokenizer) -> State {
     ) {
         tokenizer.tokenize_state.size = 0;
         State::Nok
-    } else if tokenizer.current == Some(b'\\') {
+    } else if tokenizer.current == Some(b'\\')
+        && !tokenizer.options().literal_backslash_in_destinations
+    {
         tokenizer.consume();
         State::Next(StateName::DestinationRawEscape)
     } else {
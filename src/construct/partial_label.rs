@@ -21,6 +21,11 @@
 //! Labels can contain line endings and whitespace, but they are not allowed to
 //! contain blank lines, and they must not be blank themselves.
 //!
+//! Labels cannot contain unescaped brackets, not even balanced ones:
+//! `[a[b]c]` is not a label, but `[a\[b\]c]` is.
+//! That differs from the thing that contains `x` in references (see below),
+//! where balanced brackets are fine.
+//!
 //! The label is interpreted as the [string][] content type.
 //! That means that [character escapes][character_escape] and
 //! [character references][character_reference] are allowed.
@@ -62,9 +67,8 @@
 //! [link_reference_size_max]: crate::util::constant::LINK_REFERENCE_SIZE_MAX
 
 use crate::construct::partial_space_or_tab_eol::{space_or_tab_eol_with_options, Options};
-use crate::event::{Content, Link, Name};
+use crate::event::{Content, Name};
 use crate::state::{Name as StateName, State};
-use crate::subtokenize::link;
 use crate::tokenizer::Tokenizer;
 use crate::util::constant::LINK_REFERENCE_SIZE_MAX;
 
@@ -98,6 +102,9 @@ pub fn at_break(tokenizer: &mut Tokenizer) -> State {
         State::Retry(StateName::LabelNok)
     } else {
         match tokenizer.current {
+            Some(b'\n') if !tokenizer.options().multiline_labels => {
+                State::Retry(StateName::LabelNok)
+            }
             Some(b'\n') => {
                 tokenizer.attempt(
                     State::Next(StateName::LabelEolAfter),
@@ -123,21 +130,7 @@ pub fn at_break(tokenizer: &mut Tokenizer) -> State {
                 State::Ok
             }
             _ => {
-                tokenizer.enter_link(
-                    Name::Data,
-                    Link {
-                        previous: None,
-                        next: None,
-                        content: Content::String,
-                    },
-                );
-
-                if tokenizer.tokenize_state.connect {
-                    let index = tokenizer.events.len() - 1;
-                    link(&mut tokenizer.events, index);
-                } else {
-                    tokenizer.tokenize_state.connect = true;
-                }
+                tokenizer.enter_with_content(Name::Data, Content::String);
 
                 State::Retry(StateName::LabelInside)
             }
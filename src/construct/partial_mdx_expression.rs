@@ -103,7 +103,7 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             State::Next(StateName::MdxExpressionEolAfter)
         }
         Some(b'}') if tokenizer.tokenize_state.size == 0 => {
-            let state = if let Some(ref parse) = tokenizer.parse_state.options.mdx_expression_parse
+            let state = if let Some(ref parse) = tokenizer.options().mdx_expression_parse
             {
                 parse_expression(tokenizer, parse)
             } else {
@@ -141,9 +141,7 @@ pub fn inside(tokenizer: &mut Tokenizer) -> State {
         State::Retry(StateName::MdxExpressionBefore)
     } else {
         // Don’t count if gnostic.
-        if tokenizer.current == Some(b'{')
-            && tokenizer.parse_state.options.mdx_expression_parse.is_none()
-        {
+        if tokenizer.current == Some(b'{') && tokenizer.options().mdx_expression_parse.is_none() {
             tokenizer.tokenize_state.size += 1;
         } else if tokenizer.current == Some(b'}') {
             tokenizer.tokenize_state.size -= 1;
@@ -36,9 +36,8 @@
 //! [label_end]: crate::construct::label_end
 
 use crate::construct::partial_space_or_tab_eol::{space_or_tab_eol_with_options, Options};
-use crate::event::{Content, Link, Name};
+use crate::event::{Content, Name};
 use crate::state::{Name as StateName, State};
-use crate::subtokenize::link;
 use crate::tokenizer::Tokenizer;
 
 /// Start of title.
@@ -109,21 +108,7 @@ pub fn at_break(tokenizer: &mut Tokenizer) -> State {
                 },
             ))
         } else {
-            tokenizer.enter_link(
-                Name::Data,
-                Link {
-                    previous: None,
-                    next: None,
-                    content: Content::String,
-                },
-            );
-
-            if tokenizer.tokenize_state.connect {
-                let index = tokenizer.events.len() - 1;
-                link(&mut tokenizer.events, index);
-            } else {
-                tokenizer.tokenize_state.connect = true;
-            }
+            tokenizer.enter_with_content(Name::Data, Content::String);
 
             State::Retry(StateName::TitleInside)
         }
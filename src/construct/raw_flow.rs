@@ -22,8 +22,15 @@
 //! info ::= 1*text
 //! ; Restriction: the marker cannot occur in `meta` if it is the `$` or `` ` `` character.
 //! meta ::= 1*text *(*space_or_tab 1*text)
+//!
+//! ; Restriction: LaTeX delimiters do not support `info` or `meta`.
+//! latex_fence_open ::= '\\[' *space_or_tab
+//! latex_fence_close ::= '\\]' *space_or_tab
 //! ```
 //!
+//! Math (flow) can also be formed with LaTeX delimiters (`\[` and `\]`)
+//! instead of sequences, when `constructs.math_flow_latex` is on.
+//!
 //! As this construct occurs in flow, like all flow constructs, it must be
 //! followed by an eol (line ending) or eof (end of file).
 //!
@@ -156,8 +163,9 @@ use crate::util::{
 ///   | ~~~
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.code_fenced
-        || tokenizer.parse_state.options.constructs.math_flow
+    if tokenizer.constructs().code_fenced
+        || tokenizer.constructs().math_flow
+        || tokenizer.constructs().math_flow_latex
     {
         if matches!(tokenizer.current, Some(b'\t' | b' ')) {
             tokenizer.attempt(
@@ -167,7 +175,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             return State::Retry(space_or_tab_min_max(
                 tokenizer,
                 0,
-                if tokenizer.parse_state.options.constructs.code_indented {
+                if tokenizer.constructs().is_enabled(&Name::CodeIndented) {
                     TAB_SIZE - 1
                 } else {
                     usize::MAX
@@ -175,7 +183,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             ));
         }
 
-        if matches!(tokenizer.current, Some(b'$' | b'`' | b'~')) {
+        if matches!(tokenizer.current, Some(b'$' | b'`' | b'\\' | b'~')) {
             return State::Retry(StateName::RawFlowBeforeSequenceOpen);
         }
     }
@@ -206,14 +214,15 @@ pub fn before_sequence_open(tokenizer: &mut Tokenizer) -> State {
     }
 
     // Code (fenced).
-    if (tokenizer.parse_state.options.constructs.code_fenced
-        && matches!(tokenizer.current, Some(b'`' | b'~')))
+    if (tokenizer.constructs().code_fenced && matches!(tokenizer.current, Some(b'`' | b'~')))
         // Math (flow).
-        || (tokenizer.parse_state.options.constructs.math_flow && tokenizer.current == Some(b'$'))
+        || (tokenizer.constructs().math_flow && tokenizer.current == Some(b'$'))
+        // Math (flow) with LaTeX delimiters.
+        || (tokenizer.constructs().math_flow_latex && tokenizer.current == Some(b'\\'))
     {
         tokenizer.tokenize_state.marker = tokenizer.current.unwrap();
         tokenizer.tokenize_state.size_c = prefix;
-        if tokenizer.tokenize_state.marker == b'$' {
+        if matches!(tokenizer.tokenize_state.marker, b'$' | b'\\') {
             tokenizer.tokenize_state.token_1 = Name::MathFlow;
             tokenizer.tokenize_state.token_2 = Name::MathFlowFence;
             tokenizer.tokenize_state.token_3 = Name::MathFlowFenceSequence;
@@ -248,15 +257,25 @@ pub fn before_sequence_open(tokenizer: &mut Tokenizer) -> State {
 ///   | ~~~
 /// ```
 pub fn sequence_open(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.current == Some(tokenizer.tokenize_state.marker) {
+    let marker = tokenizer.tokenize_state.marker;
+    let expected = match (marker, tokenizer.tokenize_state.size) {
+        // LaTeX delimiters: `\[`.
+        (b'\\', 0) => Some(b'\\'),
+        (b'\\', 1) => Some(b'['),
+        (b'\\', _) => None,
+        _ => Some(marker),
+    };
+
+    if expected.is_some() && tokenizer.current == expected {
         tokenizer.tokenize_state.size += 1;
         tokenizer.consume();
         State::Next(StateName::RawFlowSequenceOpen)
     } else if tokenizer.tokenize_state.size
-        < (if tokenizer.tokenize_state.marker == b'$' {
-            MATH_FLOW_SEQUENCE_SIZE_MIN
-        } else {
-            CODE_FENCED_SEQUENCE_SIZE_MIN
+        < (match marker {
+            b'$' => MATH_FLOW_SEQUENCE_SIZE_MIN,
+            // Both `\` and `[`.
+            b'\\' => 2,
+            _ => CODE_FENCED_SEQUENCE_SIZE_MIN,
         })
     {
         tokenizer.tokenize_state.marker = 0;
@@ -309,6 +328,20 @@ pub fn info_before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::NonLazyContinuationStart)
         }
+        // LaTeX delimiters do not support `info` or `meta`.
+        _ if tokenizer.tokenize_state.marker == b'\\' => {
+            tokenizer.concrete = false;
+            tokenizer.tokenize_state.marker = 0;
+            tokenizer.tokenize_state.size_c = 0;
+            tokenizer.tokenize_state.size = 0;
+            tokenizer.tokenize_state.token_1 = Name::Data;
+            tokenizer.tokenize_state.token_2 = Name::Data;
+            tokenizer.tokenize_state.token_3 = Name::Data;
+            tokenizer.tokenize_state.token_4 = Name::Data;
+            tokenizer.tokenize_state.token_5 = Name::Data;
+            tokenizer.tokenize_state.token_6 = Name::Data;
+            State::Nok
+        }
         _ => {
             tokenizer.enter(tokenizer.tokenize_state.token_4.clone());
             tokenizer.enter_link(
@@ -474,7 +507,7 @@ pub fn close_start(tokenizer: &mut Tokenizer) -> State {
         State::Retry(space_or_tab_min_max(
             tokenizer,
             0,
-            if tokenizer.parse_state.options.constructs.code_indented {
+            if tokenizer.constructs().is_enabled(&Name::CodeIndented) {
                 TAB_SIZE - 1
             } else {
                 usize::MAX
@@ -511,7 +544,16 @@ pub fn before_sequence_close(tokenizer: &mut Tokenizer) -> State {
 ///     ^
 /// ```
 pub fn sequence_close(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.current == Some(tokenizer.tokenize_state.marker) {
+    let marker = tokenizer.tokenize_state.marker;
+    let expected = match (marker, tokenizer.tokenize_state.size_b) {
+        // LaTeX delimiters: `\]`.
+        (b'\\', 0) => Some(b'\\'),
+        (b'\\', 1) => Some(b']'),
+        (b'\\', _) => None,
+        _ => Some(marker),
+    };
+
+    if expected.is_some() && tokenizer.current == expected {
         tokenizer.tokenize_state.size_b += 1;
         tokenizer.consume();
         State::Next(StateName::RawFlowSequenceClose)
@@ -9,12 +9,19 @@
 //! ```bnf
 //! ; Restriction: the number of markers in the closing sequence must be equal
 //! ; to the number of markers in the opening sequence.
-//! raw_text ::= sequence 1*byte sequence
+//! raw_text ::= sequence 1*byte sequence | latex_math_text
 //!
 //! ; Restriction: not preceded or followed by the same marker.
 //! sequence ::= 1*'`' | 1*'$'
+//!
+//! ; Restriction: a backslash in the content escapes the next byte.
+//! latex_math_text ::= '\\(' *byte '\\)'
 //! ```
 //!
+//! Math (text) with LaTeX delimiters (`\(` and `\)`) is off by default, and
+//! can be turned on with `constructs.math_text_latex`.
+//! As it starts with a backslash, it takes precedence over character escapes.
+//!
 //! The above grammar shows that it is not possible to create empty raw (text).
 //! It is possible to include the sequence marker (grave accent for code,
 //! dollar for math) in raw (text), by wrapping it in bigger or smaller
@@ -129,9 +136,11 @@ use crate::tokenizer::Tokenizer;
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
     // Code (text):
-    if ((tokenizer.parse_state.options.constructs.code_text && tokenizer.current == Some(b'`'))
+    if ((tokenizer.constructs().code_text && tokenizer.current == Some(b'`'))
         // Math (text):
-        || (tokenizer.parse_state.options.constructs.math_text && tokenizer.current == Some(b'$')))
+        || (tokenizer.constructs().math_text && tokenizer.current == Some(b'$'))
+        // Math (text) with LaTeX delimiters:
+        || (tokenizer.constructs().math_text_latex && tokenizer.current == Some(b'\\')))
         // Not the same marker (except when escaped).
         && (tokenizer.previous != tokenizer.current
             || (!tokenizer.events.is_empty()
@@ -163,15 +172,25 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 ///     ^
 /// ```
 pub fn sequence_open(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.current == Some(tokenizer.tokenize_state.marker) {
+    let marker = tokenizer.tokenize_state.marker;
+    let expected = match (marker, tokenizer.tokenize_state.size) {
+        // LaTeX delimiters: `\(`.
+        (b'\\', 0) => Some(b'\\'),
+        (b'\\', 1) => Some(b'('),
+        (b'\\', _) => None,
+        _ => Some(marker),
+    };
+
+    if expected.is_some() && tokenizer.current == expected {
         tokenizer.tokenize_state.size += 1;
         tokenizer.consume();
         State::Next(StateName::RawTextSequenceOpen)
     }
     // Not enough markers in the sequence.
-    else if tokenizer.tokenize_state.marker == b'$'
+    else if (marker == b'$'
         && tokenizer.tokenize_state.size == 1
-        && !tokenizer.parse_state.options.math_text_single_dollar
+        && !tokenizer.options().math_text_single_dollar)
+        || (marker == b'\\' && tokenizer.tokenize_state.size < 2)
     {
         tokenizer.tokenize_state.marker = 0;
         tokenizer.tokenize_state.size = 0;
@@ -244,7 +263,16 @@ pub fn data(tokenizer: &mut Tokenizer) -> State {
 ///       ^
 /// ```
 pub fn sequence_close(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.current == Some(tokenizer.tokenize_state.marker) {
+    let marker = tokenizer.tokenize_state.marker;
+    let expected = match (marker, tokenizer.tokenize_state.size_b) {
+        // LaTeX delimiters: `\)`.
+        (b'\\', 0) => Some(b'\\'),
+        (b'\\', 1) => Some(b')'),
+        (b'\\', _) => None,
+        _ => Some(marker),
+    };
+
+    if expected.is_some() && tokenizer.current == expected {
         tokenizer.tokenize_state.size_b += 1;
         tokenizer.consume();
         State::Next(StateName::RawTextSequenceClose)
@@ -265,7 +293,16 @@ pub fn sequence_close(tokenizer: &mut Tokenizer) -> State {
             tokenizer.events[len - 2].name = tokenizer.tokenize_state.token_3.clone();
             tokenizer.events[len - 1].name = tokenizer.tokenize_state.token_3.clone();
             tokenizer.tokenize_state.size_b = 0;
-            State::Retry(StateName::RawTextBetween)
+
+            // With LaTeX delimiters, a backslash escapes the next byte, so
+            // that `\\)` does not close.
+            if marker == b'\\' && !matches!(tokenizer.current, None | Some(b'\n')) {
+                tokenizer.enter(tokenizer.tokenize_state.token_3.clone());
+                tokenizer.consume();
+                State::Next(StateName::RawTextData)
+            } else {
+                State::Retry(StateName::RawTextBetween)
+            }
         }
     }
 }
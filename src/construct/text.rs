@@ -1,15 +1,17 @@
 //! The text content type.
 //!
 //! **Text** contains phrasing content such as
-//! [attention][crate::construct::attention] (emphasis, gfm strikethrough, strong),
+//! [attention][crate::construct::attention] (emphasis, gfm strikethrough, strong,
+//! subscript, superscript),
 //! [raw (text)][crate::construct::raw_text] (code (text), math (text)), and actual text.
 //!
 //! The constructs found in text are:
 //!
-//! *   [Attention][crate::construct::attention] (emphasis, gfm strikethrough, strong)
+//! *   [Attention][crate::construct::attention] (emphasis, gfm strikethrough, strong, subscript, superscript)
 //! *   [Autolink][crate::construct::autolink]
 //! *   [Character escape][crate::construct::character_escape]
 //! *   [Character reference][crate::construct::character_reference]
+//! *   [Emoji][crate::construct::emoji]
 //! *   [Raw (text)][crate::construct::raw_text] (code (text), math (text))
 //! *   [GFM: Label start (footnote)][crate::construct::gfm_label_start_footnote]
 //! *   [GFM: Task list item check][crate::construct::gfm_task_list_item_check]
@@ -20,35 +22,39 @@
 //! *   [Label end][crate::construct::label_end]
 //! *   [MDX: expression (text)][crate::construct::mdx_expression_text]
 //! *   [MDX: JSX (text)][crate::construct::mdx_jsx_text]
+//! *   [Text construct][crate::construct::text_construct] (custom)
 //!
 //! > 👉 **Note**: for performance reasons, hard break (trailing) is formed by
 //! > [whitespace][crate::construct::partial_whitespace].
 
 use crate::construct::gfm_autolink_literal::resolve as resolve_gfm_autolink_literal;
 use crate::construct::partial_whitespace::resolve_whitespace;
+use crate::event::Name;
 use crate::resolve::Name as ResolveName;
 use crate::state::{Name as StateName, State};
 use crate::subtokenize::Subresult;
 use crate::tokenizer::Tokenizer;
 
 /// Characters that can start something in text.
-const MARKERS: [u8; 16] = [
+pub const MARKERS: [u8; 18] = [
     b'!',  // `label_start_image`
     b'$',  // `raw_text` (math (text))
     b'&',  // `character_reference`
     b'*',  // `attention` (emphasis, strong)
+    b':',  // `emoji`
     b'<',  // `autolink`, `html_text`, `mdx_jsx_text`
     b'H',  // `gfm_autolink_literal` (`protocol` kind)
     b'W',  // `gfm_autolink_literal` (`www.` kind)
     b'[',  // `label_start_link`
-    b'\\', // `character_escape`, `hard_break_escape`
+    b'\\', // `character_escape`, `hard_break_escape`, `raw_text` (math (text))
     b']',  // `label_end`, `gfm_label_start_footnote`
+    b'^',  // `attention` (superscript)
     b'_',  // `attention` (emphasis, strong)
     b'`',  // `raw_text` (code (text))
     b'h',  // `gfm_autolink_literal` (`protocol` kind)
     b'w',  // `gfm_autolink_literal` (`www.` kind)
     b'{',  // `mdx_expression_text`
-    b'~',  // `attention` (gfm strikethrough)
+    b'~',  // `attention` (gfm strikethrough, subscript)
 ];
 
 /// Start of text.
@@ -62,7 +68,12 @@ const MARKERS: [u8; 16] = [
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.tokenize_state.markers = &MARKERS;
+    let parse_state = tokenizer.parse_state;
+    tokenizer.tokenize_state.markers = if parse_state.text_markers.is_empty() {
+        &MARKERS
+    } else {
+        &parse_state.text_markers
+    };
     tokenizer.attempt(
         State::Next(StateName::TextBefore),
         State::Next(StateName::TextBefore),
@@ -77,10 +88,35 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 ///     ^
 /// ```
 pub fn before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current.is_some() && !tokenizer.options().text_constructs.is_empty() {
+        tokenizer.attempt(
+            State::Next(StateName::TextBefore),
+            State::Next(StateName::TextBeforeBuiltin),
+        );
+        State::Retry(StateName::TextConstructStart)
+    } else {
+        State::Retry(StateName::TextBeforeBuiltin)
+    }
+}
+
+/// Before built-in constructs.
+///
+/// At something that wasn’t a custom text construct.
+///
+/// ```markdown
+/// > | abc
+///     ^
+/// ```
+pub fn before_builtin(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
         None => {
             tokenizer.register_resolver(ResolveName::Data);
             tokenizer.register_resolver(ResolveName::Text);
+
+            if !tokenizer.parse_state.abbreviations.is_empty() {
+                tokenizer.register_resolver(ResolveName::Abbreviation);
+            }
+
             State::Ok
         }
         Some(b'!') => {
@@ -105,8 +141,8 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::CharacterReferenceStart)
         }
-        // attention (emphasis, gfm strikethrough, strong)
-        Some(b'*' | b'_' | b'~') => {
+        // attention (emphasis, gfm strikethrough, strong, subscript, superscript)
+        Some(b'*' | b'^' | b'_' | b'~') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
                 State::Next(StateName::TextBeforeData),
@@ -114,6 +150,13 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             State::Retry(StateName::AttentionStart)
         }
         // `autolink`, `html_text` (order does not matter), `mdx_jsx_text` (order matters).
+        Some(b':') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeData),
+            );
+            State::Retry(StateName::EmojiStart)
+        }
         Some(b'<') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
@@ -142,12 +185,14 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::GfmLabelStartFootnoteStart)
         }
+        // `raw_text` (math (text) with LaTeX delimiters), `character_escape`,
+        // `hard_break_escape` (order matters).
         Some(b'\\') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
-                State::Next(StateName::TextBeforeHardBreakEscape),
+                State::Next(StateName::TextBeforeCharacterEscape),
             );
-            State::Retry(StateName::CharacterEscapeStart)
+            State::Retry(StateName::RawTextStart)
         }
         Some(b']') => {
             tokenizer.attempt(
@@ -199,6 +244,22 @@ pub fn before_mdx_jsx(tokenizer: &mut Tokenizer) -> State {
     State::Retry(StateName::MdxJsxTextStart)
 }
 
+/// Before character escape.
+///
+/// At `\`, which wasn’t math (text).
+///
+/// ```markdown
+/// > | a \*
+///       ^
+/// ```
+pub fn before_character_escape(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeHardBreakEscape),
+    );
+    State::Retry(StateName::CharacterEscapeStart)
+}
+
 /// Before hard break escape.
 ///
 /// At `\`, which wasn’t a character escape.
@@ -246,15 +307,13 @@ pub fn before_data(tokenizer: &mut Tokenizer) -> State {
 pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     resolve_whitespace(
         tokenizer,
-        tokenizer.parse_state.options.constructs.hard_break_trailing,
+        tokenizer.constructs().is_enabled(&Name::HardBreakTrailing),
         true,
     );
 
     if tokenizer
-        .parse_state
-        .options
-        .constructs
-        .gfm_autolink_literal
+        .constructs()
+        .is_enabled(&Name::GfmAutolinkLiteralProtocol)
     {
         resolve_gfm_autolink_literal(tokenizer);
     }
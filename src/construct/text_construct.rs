@@ -0,0 +1,88 @@
+//! Text construct occurs in the [text][] content type.
+//!
+//! Text constructs are custom constructs, passed in
+//! [`text_constructs`][crate::ParseOptions::text_constructs].
+//! See [`TextConstruct`][crate::TextConstruct] for more info.
+//!
+//! ## Grammar
+//!
+//! Text constructs form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: the first custom construct, with a marker matching the
+//! ; current byte, that matches the rest of the line.
+//! text_construct ::= marker *(byte - eol)
+//! ```
+//!
+//! When the match cannot be used, such as when it goes past the end of a
+//! table cell, the built-in constructs are tried instead.
+//!
+//! ## HTML
+//!
+//! Text constructs compile to what their
+//! [`to_html`][crate::TextConstruct::to_html] returns.
+//!
+//! ## Tokens
+//!
+//! *   [`TextConstruct`][Name::TextConstruct]
+//!
+//! [text]: crate::construct::text
+
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::text_construct::find;
+
+/// Start of text construct.
+///
+/// ```markdown
+/// > | a ==b== c
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    let parse_state = tokenizer.parse_state;
+    let options = tokenizer.options();
+    let index = tokenizer.point().index;
+
+    if let Some((_, size)) = parse_state
+        .bytes
+        .get(index)
+        // Quick check on the precomputed markers.
+        .filter(|byte| parse_state.text_construct_markers[usize::from(**byte)])
+        .and_then(|_| {
+            find(
+                &options.text_constructs,
+                parse_state.bytes,
+                &mut tokenizer.tokenize_state.text_construct_line,
+                index,
+            )
+        })
+    {
+        tokenizer.tokenize_state.end = index + size;
+        tokenizer.enter(Name::TextConstruct);
+        State::Retry(StateName::TextConstructInside)
+    } else {
+        State::Nok
+    }
+}
+
+/// In text construct.
+///
+/// ```markdown
+/// > | a ==b== c
+///       ^^^^^
+/// ```
+pub fn inside(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.point().index == tokenizer.tokenize_state.end {
+        tokenizer.tokenize_state.end = 0;
+        tokenizer.exit(Name::TextConstruct);
+        State::Ok
+    } else if matches!(tokenizer.current, None | Some(b'\n')) {
+        tokenizer.tokenize_state.end = 0;
+        State::Nok
+    } else {
+        tokenizer.consume();
+        State::Next(StateName::TextConstructInside)
+    }
+}
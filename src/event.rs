@@ -1,10 +1,144 @@
 //! Semantic labels of things happening.
 
+use crate::unist::Point as UnistPoint;
 use crate::util::constant::TAB_SIZE;
 
 /// Semantic label of a span.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Name {
+    /// Whole abbreviation.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`Data`][Name::Data]
+    /// *   **Construct**:
+    ///     [`abbreviation_definition`][crate::construct::abbreviation_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a HTML b
+    ///       ^^^^
+    ///   |
+    ///   | *[HTML]: HyperText Markup Language
+    /// ```
+    Abbreviation,
+    /// Whole abbreviation definition.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel],
+    ///     [`AbbreviationDefinitionMarker`][Name::AbbreviationDefinitionMarker],
+    ///     [`AbbreviationDefinitionTitle`][Name::AbbreviationDefinitionTitle],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`abbreviation_definition`][crate::construct::abbreviation_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: HyperText Markup Language
+    ///     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    AbbreviationDefinition,
+    /// Abbreviation definition label.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+    /// *   **Content model**:
+    ///     [`AbbreviationDefinitionLabelMarker`][Name::AbbreviationDefinitionLabelMarker],
+    ///     [`AbbreviationDefinitionLabelString`][Name::AbbreviationDefinitionLabelString]
+    /// *   **Construct**:
+    ///     [`abbreviation_definition`][crate::construct::abbreviation_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: HyperText Markup Language
+    ///      ^^^^^^
+    /// ```
+    AbbreviationDefinitionLabel,
+    /// Abbreviation definition label marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation_definition`][crate::construct::abbreviation_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: HyperText Markup Language
+    ///      ^    ^
+    /// ```
+    AbbreviationDefinitionLabelMarker,
+    /// Abbreviation definition label data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation_definition`][crate::construct::abbreviation_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: HyperText Markup Language
+    ///       ^^^^
+    /// ```
+    AbbreviationDefinitionLabelString,
+    /// Abbreviation definition marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation_definition`][crate::construct::abbreviation_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: HyperText Markup Language
+    ///     ^      ^
+    /// ```
+    AbbreviationDefinitionMarker,
+    /// Abbreviation definition title.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation_definition`][crate::construct::abbreviation_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: HyperText Markup Language
+    ///              ^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    AbbreviationDefinitionTitle,
     /// Attention sequence.
     ///
     /// > 👉 **Note**: this is used while parsing but compiled away.
@@ -554,6 +688,25 @@ pub enum Name {
     ///       ^ ^
     /// ```
     CodeTextSequence,
+    /// Comment line.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [document content][crate::construct::document]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`comment_line`][crate::construct::comment_line]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | % a
+    ///     ^^^
+    ///   | b
+    /// ```
+    CommentLine,
     /// Content.
     ///
     /// ## Info
@@ -846,6 +999,148 @@ pub enum Name {
     ///             ^
     /// ```
     DefinitionTitleString,
+    /// Whole details.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [document content][crate::construct::document]
+    /// *   **Content model**:
+    ///     [`DetailsFence`][Name::DetailsFence],
+    ///     [flow content][crate::construct::flow]
+    /// *   **Construct**:
+    ///     [`details`][crate::construct::details]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | >>> a
+    ///     ^^^^^
+    /// > | b
+    ///     ^
+    /// > | <<<
+    ///     ^^^
+    /// ```
+    Details,
+    /// Details fence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Details`][Name::Details]
+    /// *   **Content model**:
+    ///     [`DetailsFenceSequence`][Name::DetailsFenceSequence],
+    ///     [`DetailsSummary`][Name::DetailsSummary],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`details`][crate::construct::details]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | >>> a
+    ///     ^^^^^
+    ///   | b
+    /// > | <<<
+    ///     ^^^
+    /// ```
+    DetailsFence,
+    /// Details fence sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DetailsFence`][Name::DetailsFence]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`details`][crate::construct::details]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | >>> a
+    ///     ^^^
+    ///   | b
+    /// > | <<<
+    ///     ^^^
+    /// ```
+    DetailsFenceSequence,
+    /// Details summary.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DetailsFence`][Name::DetailsFence]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`details`][crate::construct::details]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | >>> a
+    ///         ^
+    ///   | b
+    ///   | <<<
+    /// ```
+    DetailsSummary,
+    /// Whole emoji.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`EmojiMarker`][Name::EmojiMarker],
+    ///     [`EmojiShortcode`][Name::EmojiShortcode]
+    /// *   **Construct**:
+    ///     [`emoji`][crate::construct::emoji]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a :smile: b
+    ///       ^^^^^^^
+    /// ```
+    Emoji,
+    /// Emoji marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Emoji`][Name::Emoji]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`emoji`][crate::construct::emoji]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a :smile: b
+    ///       ^     ^
+    /// ```
+    EmojiMarker,
+    /// Emoji shortcode.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Emoji`][Name::Emoji]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`emoji`][crate::construct::emoji]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a :smile: b
+    ///        ^^^^^
+    /// ```
+    EmojiShortcode,
     /// Emphasis.
     ///
     /// ## Info
@@ -2151,6 +2446,10 @@ pub enum Name {
     /// *   **Construct**:
     ///     [`list item`][crate::construct::list_item]
     ///
+    /// The `SpaceOrTab` after the marker is the whitespace between the marker
+    /// and the content: the content column of the list item derives from it.
+    /// See [`Span::list_item_spacing`][crate::Span::list_item_spacing].
+    ///
     /// ## Example
     ///
     /// ```markdown
@@ -3335,6 +3634,134 @@ pub enum Name {
     ///       ^
     /// ```
     StrongText,
+    /// Subscript.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`SubscriptSequence`][Name::SubscriptSequence],
+    ///     [`SubscriptText`][Name::SubscriptText]
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | H~2~O
+    ///      ^^^
+    /// ```
+    Subscript,
+    /// Subscript sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Subscript`][Name::Subscript]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | H~2~O
+    ///      ^ ^
+    /// ```
+    SubscriptSequence,
+    /// Subscript text.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Subscript`][Name::Subscript]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | H~2~O
+    ///       ^
+    /// ```
+    SubscriptText,
+    /// Superscript.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`SuperscriptSequence`][Name::SuperscriptSequence],
+    ///     [`SuperscriptText`][Name::SuperscriptText]
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | x^2^
+    ///      ^^^
+    /// ```
+    Superscript,
+    /// Superscript sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Superscript`][Name::Superscript]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | x^2^
+    ///      ^ ^
+    /// ```
+    SuperscriptSequence,
+    /// Superscript text.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Superscript`][Name::Superscript]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | x^2^
+    ///       ^
+    /// ```
+    SuperscriptText,
+    /// Custom text construct.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`text_construct`][crate::construct::text_construct]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ==b== c
+    ///       ^^^^^
+    /// ```
+    TextConstruct,
     /// Whole thematic break.
     ///
     /// ## Info
@@ -3375,7 +3802,11 @@ pub enum Name {
 }
 
 /// List of void events, used to make sure everything is working well.
-pub const VOID_EVENTS: [Name; 76] = [
+pub const VOID_EVENTS: [Name; 87] = [
+    Name::AbbreviationDefinitionLabelMarker,
+    Name::AbbreviationDefinitionLabelString,
+    Name::AbbreviationDefinitionMarker,
+    Name::AbbreviationDefinitionTitle,
     Name::AttentionSequence,
     Name::AutolinkEmail,
     Name::AutolinkMarker,
@@ -3394,11 +3825,15 @@ pub const VOID_EVENTS: [Name; 76] = [
     Name::CodeFlowChunk,
     Name::CodeTextData,
     Name::CodeTextSequence,
+    Name::CommentLine,
     Name::Data,
     Name::DefinitionDestinationLiteralMarker,
     Name::DefinitionLabelMarker,
     Name::DefinitionMarker,
     Name::DefinitionTitleMarker,
+    Name::DetailsFenceSequence,
+    Name::EmojiMarker,
+    Name::EmojiShortcode,
     Name::EmphasisSequence,
     Name::FrontmatterChunk,
     Name::GfmAutolinkLiteralEmail,
@@ -3451,10 +3886,14 @@ pub const VOID_EVENTS: [Name; 76] = [
     Name::ResourceTitleMarker,
     Name::SpaceOrTab,
     Name::StrongSequence,
+    Name::SubscriptSequence,
+    Name::SuperscriptSequence,
+    Name::TextConstruct,
     Name::ThematicBreakSequence,
 ];
 
 /// Embedded content type.
+#[allow(clippy::enum_variant_names)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Content {
     /// Represents [flow content][crate::construct::flow].
@@ -3491,6 +3930,7 @@ pub struct Point {
     /// This is increased up to a tab stop for tabs.
     /// Some editors count tabs as 1 character, so this position is not the
     /// same as editors.
+    /// Use [`editor_column`][Point::editor_column] for that.
     pub column: usize,
     /// 0-indexed position in the document.
     ///
@@ -3501,9 +3941,72 @@ pub struct Point {
 }
 
 impl Point {
+    /// Create a new point, without virtual spaces.
+    #[must_use]
+    pub fn new(line: usize, column: usize, index: usize) -> Point {
+        Point {
+            line,
+            column,
+            index,
+            vs: 0,
+        }
+    }
+
+    /// Turn this point into a [unist point][crate::unist::Point].
+    ///
+    /// > 👉 **Note**: unist points cannot represent virtual spaces.
+    pub fn to_unist(&self) -> UnistPoint {
+        UnistPoint::new(self.line, self.column, self.index)
+    }
+
+    /// Get the 1-indexed column number, where tabs count as 1 column.
+    ///
+    /// This is like [`column`][Point::column], which is increased up to a
+    /// tab stop for tabs, but instead counts each character (Unicode code
+    /// point) on the line as 1 column, which is what some editors do.
+    /// `bytes` must be the document that this point is in.
+    ///
+    /// > 👉 **Note**: virtual spaces (parts of a tab) are at the column of
+    /// > their tab.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{EventName, Markdown, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let value = "a\t*b*";
+    /// let markdown = Markdown::new(value, &ParseOptions::default())?;
+    /// let point = &markdown
+    ///     .spans(&[EventName::Emphasis])
+    ///     .next()
+    ///     .unwrap()
+    ///     .events()[0]
+    ///     .point;
+    ///
+    /// assert_eq!(point.column, 5);
+    /// assert_eq!(point.editor_column(value.as_bytes()), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn editor_column(&self, bytes: &[u8]) -> usize {
+        let mut start = self.index;
+
+        while start > 0 && !matches!(bytes[start - 1], b'\n' | b'\r') {
+            start -= 1;
+        }
+
+        // Count the bytes that start characters: not UTF-8 continuation bytes.
+        bytes[start..self.index]
+            .iter()
+            .filter(|byte| **byte & 0b1100_0000 != 0b1000_0000)
+            .count()
+            + 1
+    }
+
     /// Create a new point, that is shifted from the close earlier current
     /// point, to `index.`
-    pub fn shift_to(&self, bytes: &[u8], index: usize) -> Point {
+    pub(crate) fn shift_to(&self, bytes: &[u8], index: usize) -> Point {
         let mut next = self.clone();
         debug_assert!(index > next.index, "expected to shift forward");
 
@@ -3552,3 +4055,45 @@ pub struct Event {
     /// Link to another event.
     pub link: Option<Link>,
 }
+
+impl Event {
+    /// Create a new enter event, without link.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{
+    ///     to_html_from_events, Event, EventName, EventPoint, Options,
+    /// };
+    ///
+    /// let events = vec![
+    ///     Event::enter(EventName::Paragraph, EventPoint::new(1, 1, 0)),
+    ///     Event::enter(EventName::Data, EventPoint::new(1, 1, 0)),
+    ///     Event::exit(EventName::Data, EventPoint::new(1, 2, 1)),
+    ///     Event::exit(EventName::Paragraph, EventPoint::new(1, 2, 1)),
+    /// ];
+    ///
+    /// assert_eq!(to_html_from_events("a", &events, &Options::default())?, "<p>a</p>");
+    /// # Ok::<(), String>(())
+    /// ```
+    #[must_use]
+    pub fn enter(name: Name, point: Point) -> Event {
+        Event {
+            kind: Kind::Enter,
+            name,
+            point,
+            link: None,
+        }
+    }
+
+    /// Create a new exit event, without link.
+    #[must_use]
+    pub fn exit(name: Name, point: Point) -> Event {
+        Event {
+            kind: Kind::Exit,
+            name,
+            point,
+            link: None,
+        }
+    }
+}
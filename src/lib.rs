@@ -9,18 +9,49 @@
 //!     — like `to_html` but lets you configure how markdown is turned into
 //!     HTML, such as allowing dangerous HTML or turning on/off different
 //!     constructs (GFM, MDX, and the like)
+//! *   [`to_html_into()`][]
+//!     — like `to_html_with_options` but writes into an existing string,
+//!     so that its allocation can be reused
+//! *   [`to_html_with_report()`][]
+//!     — like `to_html_with_options` but also returns warnings about things
+//!     that are likely mistakes
+//! *   [`to_html_from_events()`][]
+//!     — turn events (such as changed ones) into HTML
 //! *   [`to_mdast()`][]
 //!     — turn markdown into a syntax tree
+//! *   [`to_markdown()`][]
+//!     — turn markdown into markdown, normalizing some things
+//! *   [`frontmatter()`][]
+//!     — get the frontmatter (YAML or TOML) of a document, if any
+//! *   [`Markdown`][]
+//!     — parse markdown into events, to find headings, links, and the like
+//! *   [`EventIndex`][]
+//!     — index events by name, to find things of a kind without going
+//!     through all events each time
+//! *   [`diff_events()`][]
+//!     — find which events changed between two versions of a document, such
+//!     as to re-render only part of it
+//! *   [`tokenize()`][], [`resolve()`][]
+//!     — parse markdown into events in two phases, to look at or change
+//!     events in between
+//! *   [`is_inline_only()`][]
+//!     — check whether a document is a single paragraph, to render it inline
 //!
 //! ## Features
 //!
 //! *   **`default`**
 //!     — nothing is enabled by default
 //! *   **`serde`**
-//!     — enable serde to serialize the AST (includes `dep:serde`)
+//!     — enable serde to serialize the AST and to (de)serialize options
+//!     (includes `dep:serde`)
 //! *   **`log`**
 //!     — enable logging (includes `dep:log`);
 //!     you can show logs with `RUST_LOG=debug`
+//! *   **`swar`**
+//!     — scan text for interesting bytes a word (`usize`) at a time instead
+//!     of a byte at a time, with SWAR (SIMD within a register): this uses
+//!     plain integer operations, not SIMD instructions, so there are no
+//!     dependencies or platform-specific code
 
 #![no_std]
 #![deny(clippy::pedantic)]
@@ -41,6 +72,7 @@ mod resolve;
 mod state;
 mod subtokenize;
 mod to_html;
+mod to_markdown;
 mod to_mdast;
 mod tokenizer;
 mod util;
@@ -57,16 +89,38 @@ pub use util::sanitize_uri::sanitize;
 #[doc(hidden)]
 pub use util::location::Location;
 
+pub use util::frontmatter::{Frontmatter, Kind as FrontmatterKind};
+
 pub use util::line_ending::LineEnding;
 
+pub use util::diff::{diff_events, EventChange};
+
+pub use util::markdown::{is_inline_only, EventIndex, Markdown, Span, Spans};
+
+pub use util::slice::str_between;
+
+pub use util::infer::{list_item_marker, ListItemMarker};
+
+pub use event::{Event, Kind as EventKind, Name as EventName, Point as EventPoint};
+
+pub use util::text_construct::TextConstruct;
+
+pub use parser::{Parser, Tokenized};
+
+pub use util::warning::Warning;
+
 pub use util::mdx::{
     EsmParse as MdxEsmParse, ExpressionKind as MdxExpressionKind,
     ExpressionParse as MdxExpressionParse, Signal as MdxSignal,
 };
 
-pub use configuration::{CompileOptions, Constructs, Options, ParseOptions};
+pub use configuration::{
+    CompileOptions, Constructs, HeadingStyle, ImageSrcResolver, LinkAttributes, Options,
+    ParseOptions, StrikethroughElement, ThematicBreakStyle, ToMarkdownOptions,
+};
 
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
+use util::markdown::check_events;
 
 /// Turn markdown into HTML.
 ///
@@ -126,9 +180,228 @@ pub fn to_html_with_options(value: &str, options: &Options) -> Result<String, St
         &events,
         parse_state.bytes,
         &options.compile,
+        &options.parse.text_constructs,
     ))
 }
 
+/// Turn markdown into HTML, with configuration, appended to `out`.
+///
+/// The HTML is the same as what [`to_html_with_options()`][] returns.
+/// Passing the same string (after clearing it) when turning many documents
+/// into HTML lets it reuse its allocation.
+///
+/// ## Errors
+///
+/// `to_html_into()` errors in the same cases as
+/// [`to_html_with_options()`][], so only with MDX.
+/// When it errors, `out` is not changed.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_into, Options};
+/// # fn main() -> Result<(), String> {
+///
+/// let mut html = String::with_capacity(64);
+///
+/// to_html_into("# Hi, *Saturn*!", &Options::default(), &mut html)?;
+///
+/// assert_eq!(html, "<h1>Hi, <em>Saturn</em>!</h1>");
+///
+/// html.clear();
+/// to_html_into("Hi, *Jupiter*!", &Options::default(), &mut html)?;
+///
+/// assert_eq!(html, "<p>Hi, <em>Jupiter</em>!</p>");
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_html_into(value: &str, options: &Options, out: &mut String) -> Result<(), String> {
+    let (events, parse_state) = parser::parse(value, &options.parse)?;
+    to_html::compile_into(
+        &events,
+        parse_state.bytes,
+        &options.compile,
+        &options.parse.text_constructs,
+        out,
+    );
+    Ok(())
+}
+
+/// Turn markdown into HTML, with configuration, and get warnings.
+///
+/// Warnings are found for things that are likely mistakes, such as references
+/// to undefined definitions (`[a][b]`, `[b][]`, or `[b]` without `[b]: c`) and
+/// code (fenced) that is never closed.
+/// They do not change the HTML.
+///
+/// ## Errors
+///
+/// `to_html_with_report()` errors in the same cases as
+/// [`to_html_with_options()`][], so only with MDX.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_with_report, Options};
+/// # fn main() -> Result<(), String> {
+///
+/// let (html, warnings) = to_html_with_report("[a][b]", &Options::default())?;
+///
+/// assert_eq!(html, "<p>[a][b]</p>");
+/// assert_eq!(
+///     warnings[0].message,
+///     "Undefined reference `b`, expected a definition with that label"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_html_with_report(
+    value: &str,
+    options: &Options,
+) -> Result<(String, Vec<Warning>), String> {
+    let (events, parse_state) = parser::parse(value, &options.parse)?;
+    let html = to_html::compile(
+        &events,
+        parse_state.bytes,
+        &options.compile,
+        &options.parse.text_constructs,
+    );
+    Ok((html, parse_state.warnings))
+}
+
+/// Turn events into HTML.
+///
+/// This is like [`to_html_with_options()`][], but compiles events instead of
+/// parsing `value` first.
+/// That is useful to change events, such as found with [`Markdown`][],
+/// before compiling them.
+/// `value` must be the document that the events point into.
+///
+/// > 👉 **Note**: events must form valid markdown: enter and exit events of
+/// > the things that `to_html_with_options()` would yield.
+///
+/// ## Errors
+///
+/// This errors when events are not balanced, such as an exit without an
+/// enter, or when they point past the end of `value`.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_from_events, Markdown, Options, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let markdown = Markdown::new("*a*", &ParseOptions::default())?;
+///
+/// assert_eq!(
+///     to_html_from_events(markdown.value(), markdown.events(), &Options::default())?,
+///     "<p><em>a</em></p>"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_html_from_events(
+    value: &str,
+    events: &[Event],
+    options: &Options,
+) -> Result<String, String> {
+    check_events(events, value.len())?;
+    Ok(to_html::compile(
+        events,
+        value.as_bytes(),
+        &options.compile,
+        &options.parse.text_constructs,
+    ))
+}
+
+/// Turn markdown into events, without resolving them.
+///
+/// Parsing markdown happens in two phases.
+/// This is the first, which tokenizes the document, and yields
+/// [`Tokenized`][], which is then passed to [`resolve()`][].
+/// Its events are in the `events` field.
+/// This is for advanced users, who want to look at or change events before
+/// they are resolved.
+/// `to_html()` and friends do both phases at once.
+///
+/// The events are balanced: each enter has a matching exit.
+/// They contain the structure of the document: containers, such as block
+/// quotes and list items, and flow, such as headings and code.
+/// But they are not resolved: for example, list items are not yet grouped
+/// into lists, and the lines of paragraphs and definitions are separate
+/// [`Content`][EventName::Content] events.
+/// Inline things, such as emphasis and links, are not yet parsed at all.
+///
+/// ## Errors
+///
+/// This errors in the same cases as [`to_mdast()`][], so only with MDX.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{tokenize, EventName, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let tokenized = tokenize("*a*", &ParseOptions::default())?;
+///
+/// assert_eq!(
+///     tokenized.events.iter().map(|event| &event.name).collect::<Vec<_>>(),
+///     vec![&EventName::Content, &EventName::Content]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn tokenize(value: &str, options: &ParseOptions) -> Result<Tokenized, String> {
+    parser::tokenize(value, options)
+}
+
+/// Resolve events from [`tokenize()`][].
+///
+/// This is the second phase of parsing markdown.
+/// It runs the resolvers on the events, such as to group list items into
+/// lists and to turn content into paragraphs and definitions, and then
+/// parses and resolves what is inside, such as emphasis, links, and
+/// character references.
+/// `value` must be the document that the events point into.
+///
+/// The events are then the same as what [`Markdown`][] has, and can be
+/// turned into HTML with [`to_html_from_events()`][].
+/// When the events come unchanged from `tokenize()`, the result is the same
+/// as parsing `value` at once.
+/// The resolvers that run are the ones that tokenizing registered.
+///
+/// > 👉 **Note**: events must be balanced and form valid markdown: enter and
+/// > exit events of the things that `tokenize()` would yield.
+///
+/// ## Errors
+///
+/// This errors in the same cases as [`to_mdast()`][], so only with MDX.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{resolve, to_html_from_events, tokenize, Options, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let tokenized = tokenize("*a*", &ParseOptions::default())?;
+/// let events = resolve("*a*", tokenized, &ParseOptions::default())?;
+///
+/// assert_eq!(
+///     to_html_from_events("*a*", &events, &Options::default())?,
+///     "<p><em>a</em></p>"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn resolve(
+    value: &str,
+    tokenized: Tokenized,
+    options: &ParseOptions,
+) -> Result<Vec<Event>, String> {
+    let (events, _) = parser::resolve(value, tokenized, options)?;
+    Ok(events)
+}
+
 /// Turn markdown into a syntax tree.
 ///
 /// ## Errors
@@ -157,3 +430,92 @@ pub fn to_mdast(value: &str, options: &ParseOptions) -> Result<mdast::Node, Stri
     let node = to_mdast::compile(&events, parse_state.bytes)?;
     Ok(node)
 }
+
+/// Turn markdown into markdown, with some things normalized.
+///
+/// Markdown is not rebuilt from a syntax tree: everything is kept as it is
+/// in `value`, except for the things that `options` normalizes.
+///
+/// ## Errors
+///
+/// `to_markdown()` errors in the same cases as [`to_mdast()`][], so only with
+/// MDX.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_markdown, HeadingStyle, ParseOptions, ToMarkdownOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let options = ToMarkdownOptions {
+///     normalize_headings: HeadingStyle::Atx,
+///     ..ToMarkdownOptions::default()
+/// };
+///
+/// let result = to_markdown("Hey, *you*!\n-----------\n\nb", &ParseOptions::default(), &options)?;
+///
+/// assert_eq!(result, "## Hey, *you*!\n\nb");
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_markdown(
+    value: &str,
+    parse_options: &ParseOptions,
+    options: &ToMarkdownOptions,
+) -> Result<String, String> {
+    let (events, parse_state) = parser::parse(value, parse_options)?;
+    Ok(to_markdown::compile(
+        &events,
+        parse_state.bytes,
+        parse_options,
+        options,
+    ))
+}
+
+/// Get the frontmatter of a document.
+///
+/// Frontmatter is not part of the HTML output, but tools often need it to
+/// parse it themselves, with a YAML or TOML parser.
+/// Returns `None` if there is no frontmatter.
+/// The value is what is between the fences, without empty lines at its start
+/// and end.
+///
+/// > 👉 **Note**: frontmatter is not enabled by default, pass `frontmatter:
+/// > true` in [`Constructs`][] to find it.
+///
+/// ## Errors
+///
+/// `frontmatter()` never errors with normal markdown because markdown does
+/// not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{frontmatter, Constructs, FrontmatterKind, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let options = ParseOptions {
+///     constructs: Constructs {
+///         frontmatter: true,
+///         ..Constructs::default()
+///     },
+///     ..ParseOptions::default()
+/// };
+///
+/// let result = frontmatter("---\ntitle: Neptune\n---\n# Hi", &options)?.unwrap();
+///
+/// assert_eq!(result.kind, FrontmatterKind::Yaml);
+/// assert_eq!(result.value, "title: Neptune");
+/// # Ok(())
+/// # }
+/// ```
+pub fn frontmatter<'a>(
+    value: &'a str,
+    options: &ParseOptions,
+) -> Result<Option<Frontmatter<'a>>, String> {
+    let (events, _) = parser::parse(value, options)?;
+    Ok(util::frontmatter::frontmatter(&events, value.as_bytes()))
+}
@@ -1,12 +1,24 @@
 //! Turn bytes of markdown into events.
 
+use crate::construct::text::MARKERS as TEXT_MARKERS;
 use crate::event::{Event, Point};
+use crate::resolve::Name as ResolveName;
 use crate::state::{Name as StateName, State};
-use crate::subtokenize::subtokenize;
+use crate::subtokenize::{subtokenize, Subresult};
 use crate::tokenizer::Tokenizer;
-use crate::util::location::Location;
+use crate::util::{
+    location::Location,
+    warning::{duplicate_definitions, unclosed_raw_flow, Warning},
+};
 use crate::ParseOptions;
-use alloc::{string::String, vec, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::String,
+    vec,
+    vec::Vec,
+};
+use core::mem;
 
 /// Info needed, in all content types, when parsing markdown.
 ///
@@ -24,6 +36,79 @@ pub struct ParseState<'a> {
     pub definitions: Vec<String>,
     /// Set of defined GFM footnote definition identifiers.
     pub gfm_footnote_definitions: Vec<String>,
+    /// Set of defined abbreviation labels, by their size.
+    pub abbreviations: BTreeMap<usize, BTreeSet<Vec<u8>>>,
+    /// Bytes that can start something in text, if there are custom text
+    /// constructs.
+    pub text_markers: Vec<u8>,
+    /// Whether a byte can start a custom text construct.
+    pub text_construct_markers: [bool; 256],
+    /// List of warnings.
+    pub warnings: Vec<Warning>,
+}
+
+/// Events from [`tokenize()`][crate::tokenize()], before they are resolved.
+///
+/// Besides the events, this keeps what tokenizing found and which resolvers
+/// it registered, which [`resolve()`][crate::resolve()] needs.
+#[derive(Debug)]
+pub struct Tokenized {
+    /// List of events, which can be inspected or changed.
+    pub events: Vec<Event>,
+    /// List of resolvers that tokenizing registered.
+    resolvers: Vec<ResolveName>,
+    /// What tokenizing found, such as GFM footnote definitions.
+    result: Subresult,
+}
+
+/// Reusable parser.
+///
+/// Parsing many documents, such as on a server, with one parser reuses the
+/// allocation of the events across documents.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_from_events, Options, ParseOptions, Parser};
+/// # fn main() -> Result<(), String> {
+///
+/// let mut parser = Parser::new();
+///
+/// for value in ["# a", "*b*"] {
+///     let events = parser.parse(value, &ParseOptions::default())?;
+///     println!("{}", to_html_from_events(value, events, &Options::default())?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct Parser {
+    /// Events of the last document, whose allocation is reused.
+    events: Vec<Event>,
+}
+
+impl Parser {
+    /// Create a parser.
+    pub fn new() -> Parser {
+        Parser::default()
+    }
+
+    /// Parse markdown, and get its events.
+    ///
+    /// The events are the same as [`Markdown`][crate::Markdown] has.
+    /// They are valid until the next document is parsed.
+    ///
+    /// ## Errors
+    ///
+    /// This errors in the same cases as [`to_mdast()`][crate::to_mdast()],
+    /// so only with MDX.
+    pub fn parse(&mut self, value: &str, options: &ParseOptions) -> Result<&[Event], String> {
+        let parse_state = parse_state(value, options)?;
+        let tokenized = tokenize_document(&parse_state, mem::take(&mut self.events))?;
+        let (events, _) = resolve_document(tokenized, parse_state)?;
+        self.events = events;
+        Ok(&self.events)
+    }
 }
 
 /// Turn a string of markdown into events.
@@ -33,9 +118,47 @@ pub fn parse<'a>(
     value: &'a str,
     options: &'a ParseOptions,
 ) -> Result<(Vec<Event>, ParseState<'a>), String> {
+    let parse_state = parse_state(value, options)?;
+    let tokenized = tokenize_document(&parse_state, vec![])?;
+    resolve_document(tokenized, parse_state)
+}
+
+/// Turn a string of markdown into events, without resolving them.
+///
+/// See [`tokenize()`][crate::tokenize()] for what these events are.
+pub fn tokenize(value: &str, options: &ParseOptions) -> Result<Tokenized, String> {
+    let parse_state = parse_state(value, options)?;
+    tokenize_document(&parse_state, vec![])
+}
+
+/// Resolve events from [`tokenize`][].
+pub fn resolve<'a>(
+    value: &'a str,
+    tokenized: Tokenized,
+    options: &'a ParseOptions,
+) -> Result<(Vec<Event>, ParseState<'a>), String> {
+    let parse_state = parse_state(value, options)?;
+    resolve_document(tokenized, parse_state)
+}
+
+/// Create the state for parsing a string of markdown.
+pub fn parse_state<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+) -> Result<ParseState<'a>, String> {
     let bytes = value.as_bytes();
 
-    let mut parse_state = ParseState {
+    if let Some(max) = options.max_input_bytes {
+        if bytes.len() > max {
+            return Err(format!(
+                "1:1: Unexpected input of `{}` bytes, expected at most `{}` bytes (max-input-bytes)",
+                bytes.len(),
+                max
+            ));
+        }
+    }
+
+    Ok(ParseState {
         options,
         bytes,
         location: if options.mdx_esm_parse.is_some() || options.mdx_expression_parse.is_some() {
@@ -45,34 +168,117 @@ pub fn parse<'a>(
         },
         definitions: vec![],
         gfm_footnote_definitions: vec![],
-    };
-
-    let start = Point {
-        line: 1,
-        column: 1,
-        index: 0,
-        vs: 0,
-    };
-    let mut tokenizer = Tokenizer::new(start, &parse_state);
+        abbreviations: BTreeMap::new(),
+        text_markers: text_markers(options),
+        text_construct_markers: text_construct_markers(options),
+        warnings: vec![],
+    })
+}
 
+/// Tokenize the document, without resolving it.
+///
+/// `events` is cleared and used as the buffer for the events.
+fn tokenize_document(
+    parse_state: &ParseState,
+    mut events: Vec<Event>,
+) -> Result<Tokenized, String> {
+    let mut tokenizer = Tokenizer::new(start(), parse_state);
+    events.clear();
+    tokenizer.events = events;
     let state = tokenizer.push(
         (0, 0),
         (parse_state.bytes.len(), 0),
         State::Next(StateName::DocumentStart),
     );
-    let mut result = tokenizer.flush(state, true)?;
+    let result = tokenizer.flush(state, false)?;
+    Ok(Tokenized {
+        events: tokenizer.events,
+        resolvers: tokenizer.resolvers,
+        result,
+    })
+}
+
+/// Resolve the document, and then tokenize and resolve its content, until
+/// everything is done.
+fn resolve_document(
+    Tokenized {
+        events,
+        resolvers,
+        mut result,
+    }: Tokenized,
+    mut parse_state: ParseState,
+) -> Result<(Vec<Event>, ParseState), String> {
+    let mut tokenizer = Tokenizer::new(start(), &parse_state);
+    tokenizer.events = events;
+    tokenizer.resolvers = resolvers;
+    tokenizer.resolve(&mut result)?;
     let mut events = tokenizer.events;
+    let bytes = parse_state.bytes;
 
     loop {
         let fn_defs = &mut parse_state.gfm_footnote_definitions;
         let defs = &mut parse_state.definitions;
         fn_defs.append(&mut result.gfm_footnote_definitions);
         defs.append(&mut result.definitions);
+        parse_state.warnings.append(&mut result.warnings);
+
+        for label in result.abbreviations.drain(..) {
+            parse_state
+                .abbreviations
+                .entry(label.len())
+                .or_default()
+                .insert(label.into_bytes());
+        }
 
         if result.done {
+            unclosed_raw_flow(&events, &mut parse_state.warnings);
+            duplicate_definitions(&events, bytes, &mut parse_state.warnings);
             return Ok((events, parse_state));
         }
 
         result = subtokenize(&mut events, &parse_state, &None)?;
     }
 }
+
+/// Get the point at the start of the document.
+fn start() -> Point {
+    Point {
+        line: 1,
+        column: 1,
+        index: 0,
+        vs: 0,
+    }
+}
+
+/// Get the bytes that can start something in text, when there are custom text
+/// constructs.
+fn text_markers(options: &ParseOptions) -> Vec<u8> {
+    let mut markers = vec![];
+
+    if !options.text_constructs.is_empty() {
+        markers.extend_from_slice(&TEXT_MARKERS);
+
+        for construct in &options.text_constructs {
+            for marker in construct.markers() {
+                if !markers.contains(marker) {
+                    markers.push(*marker);
+                }
+            }
+        }
+    }
+
+    markers
+}
+
+/// Get which bytes can start custom text constructs.
+fn text_construct_markers(options: &ParseOptions) -> [bool; 256] {
+    let mut markers = [false; 256];
+
+    for construct in &options.text_constructs {
+        for marker in construct.markers() {
+            markers[usize::from(*marker)] = true;
+        }
+    }
+
+    markers
+}
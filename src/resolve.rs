@@ -60,6 +60,11 @@ pub enum Name {
     String,
     /// Resolve whitespace in `text`.
     Text,
+    /// Resolve abbreviations.
+    ///
+    /// Abbreviations are not parsed, but found in the data of text, when it
+    /// matches the label of an abbreviation definition.
+    Abbreviation,
 }
 
 /// Call the corresponding resolver.
@@ -75,6 +80,7 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> Result<Option<Subresult>,
         Name::Data => construct::partial_data::resolve(tokenizer),
         Name::String => construct::string::resolve(tokenizer),
         Name::Text => construct::text::resolve(tokenizer),
+        Name::Abbreviation => construct::abbreviation_definition::resolve(tokenizer),
     };
 
     Ok(result)
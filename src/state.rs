@@ -43,6 +43,19 @@ impl State {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[allow(clippy::enum_variant_names)]
 pub enum Name {
+    AbbreviationDefinitionStart,
+    AbbreviationDefinitionLabelOpen,
+    AbbreviationDefinitionLabelBefore,
+    AbbreviationDefinitionLabelInside,
+    AbbreviationDefinitionLabelAfter,
+    AbbreviationDefinitionMarkerAfter,
+    AbbreviationDefinitionTitleBefore,
+    AbbreviationDefinitionTitleInside,
+    AbbreviationDefinitionTitleTrailing,
+    AbbreviationDefinitionTitleWhitespace,
+    AbbreviationDefinitionTitleEnd,
+    AbbreviationDefinitionAfter,
+
     AttentionStart,
     AttentionInside,
 
@@ -82,6 +95,11 @@ pub enum Name {
     CodeIndentedInside,
     CodeIndentedFurtherBegin,
     CodeIndentedFurtherAfter,
+    CodeIndentedFurtherBlank,
+    CodeIndentedFurtherIndented,
+
+    CommentLineStart,
+    CommentLineInside,
 
     ContentChunkStart,
     ContentChunkInside,
@@ -107,6 +125,16 @@ pub enum Name {
     DefinitionTitleAfter,
     DefinitionTitleAfterOptionalWhitespace,
 
+    DetailsStart,
+    DetailsBefore,
+    DetailsSequenceOpen,
+    DetailsSummaryBefore,
+    DetailsSummaryInside,
+    DetailsContStart,
+    DetailsContBefore,
+    DetailsSequenceClose,
+    DetailsAfterSequenceClose,
+
     DestinationStart,
     DestinationEnclosedBefore,
     DestinationEnclosed,
@@ -119,15 +147,22 @@ pub enum Name {
     DocumentContainerExistingBefore,
     DocumentContainerExistingAfter,
     DocumentContainerNewBefore,
+    DocumentContainerNewBeforeNotDetails,
     DocumentContainerNewBeforeNotBlockQuote,
     DocumentContainerNewBeforeNotList,
     DocumentContainerNewBeforeNotGfmFootnoteDefinition,
     DocumentContainerNewAfter,
     DocumentContainersAfter,
+    DocumentCommentLineAfter,
+    DocumentFlowBefore,
     DocumentFlowInside,
     DocumentFlowEnd,
 
+    EmojiStart,
+    EmojiShortcode,
+
     FlowStart,
+    FlowBeforeAbbreviationDefinition,
     FlowBeforeGfmTable,
     FlowBeforeCodeIndented,
     FlowBeforeRaw,
@@ -448,12 +483,17 @@ pub enum Name {
 
     TextStart,
     TextBefore,
+    TextBeforeBuiltin,
     TextBeforeHtml,
     TextBeforeMdxJsx,
+    TextBeforeCharacterEscape,
     TextBeforeHardBreakEscape,
     TextBeforeLabelStartLink,
     TextBeforeData,
 
+    TextConstructStart,
+    TextConstructInside,
+
     ThematicBreakStart,
     ThematicBreakBefore,
     ThematicBreakSequence,
@@ -472,6 +512,23 @@ pub enum Name {
 /// Call the corresponding state for a state name.
 pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
     let func = match name {
+        Name::AbbreviationDefinitionStart => construct::abbreviation_definition::start,
+        Name::AbbreviationDefinitionLabelOpen => construct::abbreviation_definition::label_open,
+        Name::AbbreviationDefinitionLabelBefore => construct::abbreviation_definition::label_before,
+        Name::AbbreviationDefinitionLabelInside => construct::abbreviation_definition::label_inside,
+        Name::AbbreviationDefinitionLabelAfter => construct::abbreviation_definition::label_after,
+        Name::AbbreviationDefinitionMarkerAfter => construct::abbreviation_definition::marker_after,
+        Name::AbbreviationDefinitionTitleBefore => construct::abbreviation_definition::title_before,
+        Name::AbbreviationDefinitionTitleInside => construct::abbreviation_definition::title_inside,
+        Name::AbbreviationDefinitionTitleTrailing => {
+            construct::abbreviation_definition::title_trailing
+        }
+        Name::AbbreviationDefinitionTitleWhitespace => {
+            construct::abbreviation_definition::title_whitespace
+        }
+        Name::AbbreviationDefinitionTitleEnd => construct::abbreviation_definition::title_end,
+        Name::AbbreviationDefinitionAfter => construct::abbreviation_definition::after,
+
         Name::AttentionStart => construct::attention::start,
         Name::AttentionInside => construct::attention::inside,
 
@@ -511,6 +568,11 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::CodeIndentedInside => construct::code_indented::inside,
         Name::CodeIndentedFurtherBegin => construct::code_indented::further_begin,
         Name::CodeIndentedFurtherAfter => construct::code_indented::further_after,
+        Name::CodeIndentedFurtherBlank => construct::code_indented::further_blank,
+        Name::CodeIndentedFurtherIndented => construct::code_indented::further_indented,
+
+        Name::CommentLineStart => construct::comment_line::start,
+        Name::CommentLineInside => construct::comment_line::inside,
 
         Name::ContentChunkStart => construct::content::chunk_start,
         Name::ContentChunkInside => construct::content::chunk_inside,
@@ -538,6 +600,16 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
             construct::definition::title_after_optional_whitespace
         }
 
+        Name::DetailsStart => construct::details::start,
+        Name::DetailsBefore => construct::details::before,
+        Name::DetailsSequenceOpen => construct::details::sequence_open,
+        Name::DetailsSummaryBefore => construct::details::summary_before,
+        Name::DetailsSummaryInside => construct::details::summary_inside,
+        Name::DetailsContStart => construct::details::cont_start,
+        Name::DetailsContBefore => construct::details::cont_before,
+        Name::DetailsSequenceClose => construct::details::sequence_close,
+        Name::DetailsAfterSequenceClose => construct::details::after_sequence_close,
+
         Name::DestinationStart => construct::partial_destination::start,
         Name::DestinationEnclosedBefore => construct::partial_destination::enclosed_before,
         Name::DestinationEnclosed => construct::partial_destination::enclosed,
@@ -550,6 +622,9 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::DocumentContainerExistingBefore => construct::document::container_existing_before,
         Name::DocumentContainerExistingAfter => construct::document::container_existing_after,
         Name::DocumentContainerNewBefore => construct::document::container_new_before,
+        Name::DocumentContainerNewBeforeNotDetails => {
+            construct::document::container_new_before_not_details
+        }
         Name::DocumentContainerNewBeforeNotBlockQuote => {
             construct::document::container_new_before_not_block_quote
         }
@@ -561,10 +636,16 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         }
         Name::DocumentContainerNewAfter => construct::document::container_new_after,
         Name::DocumentContainersAfter => construct::document::containers_after,
+        Name::DocumentCommentLineAfter => construct::document::comment_line_after,
+        Name::DocumentFlowBefore => construct::document::flow_before,
         Name::DocumentFlowEnd => construct::document::flow_end,
+
+        Name::EmojiStart => construct::emoji::start,
+        Name::EmojiShortcode => construct::emoji::shortcode,
         Name::DocumentFlowInside => construct::document::flow_inside,
 
         Name::FlowStart => construct::flow::start,
+        Name::FlowBeforeAbbreviationDefinition => construct::flow::before_abbreviation_definition,
         Name::FlowBeforeGfmTable => construct::flow::before_gfm_table,
         Name::FlowBeforeCodeIndented => construct::flow::before_code_indented,
         Name::FlowBeforeRaw => construct::flow::before_raw,
@@ -947,12 +1028,17 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
 
         Name::TextStart => construct::text::start,
         Name::TextBefore => construct::text::before,
+        Name::TextBeforeBuiltin => construct::text::before_builtin,
         Name::TextBeforeHtml => construct::text::before_html,
         Name::TextBeforeMdxJsx => construct::text::before_mdx_jsx,
+        Name::TextBeforeCharacterEscape => construct::text::before_character_escape,
         Name::TextBeforeHardBreakEscape => construct::text::before_hard_break_escape,
         Name::TextBeforeLabelStartLink => construct::text::before_label_start_link,
         Name::TextBeforeData => construct::text::before_data,
 
+        Name::TextConstructStart => construct::text_construct::start,
+        Name::TextConstructInside => construct::text_construct::inside,
+
         Name::ThematicBreakStart => construct::thematic_break::start,
         Name::ThematicBreakBefore => construct::thematic_break::before,
         Name::ThematicBreakSequence => construct::thematic_break::sequence,
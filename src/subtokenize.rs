@@ -21,7 +21,7 @@ use crate::event::{Content, Event, Kind, Name, VOID_EVENTS};
 use crate::parser::ParseState;
 use crate::state::{Name as StateName, State};
 use crate::tokenizer::Tokenizer;
-use crate::util::{edit_map::EditMap, skip};
+use crate::util::{edit_map::EditMap, skip, warning::Warning};
 use alloc::{string::String, vec, vec::Vec};
 
 #[derive(Debug)]
@@ -29,6 +29,8 @@ pub struct Subresult {
     pub done: bool,
     pub gfm_footnote_definitions: Vec<String>,
     pub definitions: Vec<String>,
+    pub abbreviations: Vec<String>,
+    pub warnings: Vec<Warning>,
 }
 
 /// Link two [`Event`][]s.
@@ -85,8 +87,12 @@ pub fn subtokenize(
         done: true,
         gfm_footnote_definitions: vec![],
         definitions: vec![],
+        abbreviations: vec![],
+        warnings: vec![],
     };
     let mut acc = (0, 0);
+    // Reused across chunks, to not allocate new buffers each time.
+    let mut tokenizer_opt: Option<Tokenizer> = None;
 
     while index < events.len() {
         let event = &events[index];
@@ -102,7 +108,12 @@ pub fn subtokenize(
                 // Index into `events` pointing to a chunk.
                 let mut link_index = Some(index);
                 // Subtokenizer.
-                let mut tokenizer = Tokenizer::new(event.point.clone(), parse_state);
+                let tokenizer = if let Some(tokenizer) = &mut tokenizer_opt {
+                    tokenizer.reset(event.point.clone(), parse_state);
+                    tokenizer
+                } else {
+                    tokenizer_opt.insert(Tokenizer::new(event.point.clone(), parse_state))
+                };
                 debug_assert!(
                     !matches!(link.content, Content::Flow),
                     "cannot use flow as subcontent yet"
@@ -117,7 +128,9 @@ pub fn subtokenize(
                 // Check if this is the first paragraph, after zero or more
                 // definitions (or a blank line), in a list item.
                 // Used for GFM task list items.
-                if tokenizer.parse_state.options.constructs.gfm_task_list_item
+                if tokenizer
+                    .constructs()
+                    .is_enabled(&Name::GfmTaskListItemCheck)
                     && index > 2
                     && events[index - 1].kind == Kind::Enter
                     && events[index - 1].name == Name::Paragraph
@@ -142,6 +155,19 @@ pub fn subtokenize(
                     }
                 }
 
+                // Check if this is a destination, where backslashes are
+                // literal.
+                if tokenizer.options().literal_backslash_in_destinations
+                    && index > 0
+                    && events[index - 1].kind == Kind::Enter
+                    && matches!(
+                        events[index - 1].name,
+                        Name::DefinitionDestinationString | Name::ResourceDestinationString
+                    )
+                {
+                    tokenizer.tokenize_state.destination_literal_backslash = true;
+                }
+
                 // Loop through links to pass them in order to the subtokenizer.
                 while let Some(index) = link_index {
                     let enter = &events[index];
@@ -168,6 +194,8 @@ pub fn subtokenize(
                     .gfm_footnote_definitions
                     .append(&mut result.gfm_footnote_definitions);
                 value.definitions.append(&mut result.definitions);
+                value.abbreviations.append(&mut result.abbreviations);
+                value.warnings.append(&mut result.warnings);
                 value.done = false;
 
                 acc = divide_events(&mut map, events, index, &mut tokenizer.events, acc);
@@ -2,24 +2,31 @@
 use crate::event::{Event, Kind, Name};
 use crate::mdast::AlignKind;
 use crate::util::{
+    char::is_rtl,
     character_reference::decode as decode_character_reference,
     constant::{SAFE_PROTOCOL_HREF, SAFE_PROTOCOL_SRC},
-    encode::encode,
+    emoji::decode as decode_emoji,
+    encode::{encode, is_attribute_name},
     gfm_tagfilter::gfm_tagfilter,
     infer::{gfm_table_align, list_loose},
+    markdown::exit_index,
     normalize_identifier::normalize_identifier,
+    punycode::to_ascii_host,
     sanitize_uri::{sanitize, sanitize_with_protocols},
     skip,
     slice::{Position, Slice},
+    strip_style_attributes::{strip_style_attributes, State as HtmlState},
+    text_construct::{find as find_text_construct, TextConstruct},
 };
 use crate::{CompileOptions, LineEnding};
 use alloc::{
+    boxed::Box,
     format,
     string::{String, ToString},
     vec,
     vec::Vec,
 };
-use core::str;
+use core::{mem, str};
 
 /// Link, image, or footnote call.
 /// Resource or reference.
@@ -82,6 +89,11 @@ struct CompileContext<'a> {
     bytes: &'a [u8],
     /// Configuration.
     options: &'a CompileOptions,
+    /// Custom text constructs.
+    text_constructs: &'a [Box<dyn TextConstruct>],
+    /// Rest of the line at the last custom text construct, and where it
+    /// starts.
+    text_construct_line: (usize, &'a str),
     // Fields used by handlers to track the things they need to track to
     // compile markdown.
     /// Rank of heading (atx).
@@ -96,6 +108,14 @@ struct CompileContext<'a> {
     raw_text_inside: bool,
     /// Whether we are in image text.
     image_alt_inside: bool,
+    /// Whether we are in a paragraph.
+    paragraph_inside: bool,
+    /// Whether we are in a paragraph compiled as a figure.
+    figure_inside: bool,
+    /// Whether we are in HTML (text).
+    html_text_inside: bool,
+    /// Number of subscripts and superscripts we are in.
+    script_depth: usize,
     /// Marker of character reference.
     character_reference_marker: Option<u8>,
     /// Whether we are expecting the first list item marker.
@@ -106,6 +126,8 @@ struct CompileContext<'a> {
     tight_stack: Vec<bool>,
     /// List of definitions.
     definitions: Vec<Definition>,
+    /// List of abbreviation definitions, as labels and titles.
+    abbreviations: Vec<(String, String)>,
     /// List of definitions.
     gfm_footnote_definitions: Vec<(String, String)>,
     gfm_footnote_definition_calls: Vec<(String, usize)>,
@@ -121,12 +143,23 @@ struct CompileContext<'a> {
     slurp_one_line_ending: bool,
     /// Whether to encode HTML.
     encode_html: bool,
+    /// Where we are in raw HTML, to strip `style` attributes.
+    html_state: HtmlState,
+    /// Number of block-level elements we are in, to indent with.
+    indent: usize,
+    /// Indentation to restore after GFM footnote definitions.
+    gfm_footnote_definition_indent_stack: Vec<usize>,
+    /// Whether to indent the next value.
+    indent_next: bool,
     // Configuration
     /// Line ending to use.
     line_ending_default: LineEnding,
     // Intermediate results.
     /// Stack of buffers.
     buffers: Vec<String>,
+    /// Size of the first buffer before compiling, when compiling into a
+    /// string that already has content.
+    buffer_start: usize,
     /// Current event index.
     index: usize,
 }
@@ -137,11 +170,14 @@ impl<'a> CompileContext<'a> {
         events: &'a [Event],
         bytes: &'a [u8],
         options: &'a CompileOptions,
+        text_constructs: &'a [Box<dyn TextConstruct>],
         line_ending: LineEnding,
     ) -> CompileContext<'a> {
         CompileContext {
             events,
             bytes,
+            text_constructs,
+            text_construct_line: (0, ""),
             heading_atx_rank: None,
             heading_setext_buffer: None,
             raw_flow_seen_data: None,
@@ -151,6 +187,7 @@ impl<'a> CompileContext<'a> {
             list_expect_first_marker: None,
             media_stack: vec![],
             definitions: vec![],
+            abbreviations: vec![],
             gfm_footnote_definitions: vec![],
             gfm_footnote_definition_calls: vec![],
             gfm_footnote_definition_stack: vec![],
@@ -160,9 +197,18 @@ impl<'a> CompileContext<'a> {
             tight_stack: vec![],
             slurp_one_line_ending: false,
             image_alt_inside: false,
+            script_depth: 0,
+            paragraph_inside: false,
+            figure_inside: false,
+            html_text_inside: false,
             encode_html: true,
+            html_state: HtmlState::Data,
+            indent: 0,
+            gfm_footnote_definition_indent_stack: vec![],
+            indent_next: false,
             line_ending_default: line_ending,
             buffers: vec![String::new()],
+            buffer_start: 0,
             index: 0,
             options,
         }
@@ -170,21 +216,297 @@ impl<'a> CompileContext<'a> {
 
     /// Push a buffer.
     fn buffer(&mut self) {
+        self.indent_next = false;
         self.buffers.push(String::new());
     }
 
     /// Pop a buffer, returning its value.
     fn resume(&mut self) -> String {
+        self.indent_next = false;
         self.buffers.pop().expect("Cannot resume w/o buffer")
     }
 
     /// Push a str to the last buffer.
     fn push(&mut self, value: &str) {
+        if self.indent_next && !matches!(value.as_bytes().first(), None | Some(b'\n' | b'\r')) {
+            self.indent_next = false;
+
+            if self.at_line_start() {
+                let indent = "  ".repeat(self.indent);
+                self.push(&indent);
+            }
+        }
+
         let last_buf_opt = self.buffers.last_mut();
         let last_buf = last_buf_opt.expect("at least one buffer should exist");
         last_buf.push_str(value);
     }
 
+    /// Shift the rank of a heading by `heading_offset`, keeping it in `1..=6`.
+    fn heading_rank(&self, rank: usize) -> usize {
+        let offset = self.options.heading_offset;
+        let shift = usize::from(offset.unsigned_abs());
+        let rank = if offset < 0 {
+            rank.saturating_sub(shift)
+        } else {
+            rank.saturating_add(shift)
+        };
+        rank.clamp(1, 6)
+    }
+
+    /// Indent the next value, if `pretty` is on.
+    fn indent_next(&mut self) {
+        self.indent_next = self.options.pretty;
+    }
+
+    /// Push a `data-sourcepos` attribute for the nearest `name` that was
+    /// entered, if source positions are on.
+    fn push_source_position(&mut self, name: &Name) {
+        if self.options.source_positions {
+            let mut enter = self.index;
+
+            while !(self.events[enter].kind == Kind::Enter && self.events[enter].name == *name) {
+                enter -= 1;
+            }
+
+            let start = &self.events[enter].point;
+            let exit = exit_index(self.events, enter);
+            let mut end = &self.events[exit].point;
+            let mut index = exit;
+
+            // The end is inclusive and without trailing whitespace, like in
+            // `cmark`.
+            // Walking back over exits and whitespace, the first enter we find
+            // is of the last thing with content.
+            while index > enter + 1 {
+                index -= 1;
+                let event = &self.events[index];
+
+                if event.kind == Kind::Enter
+                    && !matches!(
+                        event.name,
+                        Name::BlankLineEnding | Name::LineEnding | Name::SpaceOrTab
+                    )
+                {
+                    end = &self.events[exit_index(self.events, index)].point;
+                    break;
+                }
+            }
+
+            let value = format!(
+                " data-sourcepos=\"{}:{}-{}:{}\"",
+                start.line,
+                start.column,
+                end.line,
+                end.column - 1
+            );
+            self.push(&value);
+        }
+    }
+
+    /// Push `data-line-start` and `data-line-count` attributes for the code
+    /// block (fenced or indented) entered at the current index, if line
+    /// numbers are on.
+    ///
+    /// These are about the content, so without the fences of fenced code.
+    fn push_code_line_numbers(&mut self) {
+        if self.options.code_line_numbers {
+            let enter = self.index;
+            let exit = exit_index(self.events, enter);
+            let mut start = self.events[enter].point.line;
+            let mut end = self.events[exit].point.line;
+
+            // Code that is not closed can end in a line ending.
+            if self.events[exit - 1].name == Name::LineEnding {
+                end -= 1;
+            }
+
+            if self.events[enter].name == Name::CodeFenced {
+                let fence_exit = exit_index(self.events, enter + 1);
+                let mut index = fence_exit + 1;
+                start = self.events[fence_exit].point.line + 1;
+
+                // Stop before the closing fence, if there is one.
+                while index < exit {
+                    if self.events[index].kind == Kind::Enter
+                        && self.events[index].name == Name::CodeFencedFence
+                    {
+                        end = self.events[index].point.line - 1;
+                        break;
+                    }
+
+                    index += 1;
+                }
+            }
+
+            let value = format!(
+                " data-line-start=\"{}\" data-line-count=\"{}\"",
+                start,
+                (end + 1).saturating_sub(start)
+            );
+            self.push(&value);
+        }
+    }
+
+    /// Push an attribute for copy buttons on the code block that was
+    /// entered, if configured, with the code as its value if
+    /// `code_block_copy_value` is on.
+    fn push_code_copy(&mut self) {
+        if let Some(attribute) = self
+            .options
+            .code_block_copy_attribute
+            .as_deref()
+            .filter(|d| is_attribute_name(d))
+        {
+            let mut value = String::new();
+
+            if self.options.code_block_copy_value {
+                let enter = self.index;
+                let exit = exit_index(self.events, enter);
+                let mut index = enter + 1;
+                // The line ending after the opening fence is not part of
+                // the code.
+                let mut skip_line_ending = false;
+                // Line ending that is only part of the code if more follows.
+                let mut line_ending = None;
+
+                if self.events[enter].name == Name::CodeFenced {
+                    index = exit_index(self.events, enter + 1) + 1;
+                    skip_line_ending = true;
+                }
+
+                while index < exit {
+                    let event = &self.events[index];
+
+                    if event.kind == Kind::Enter {
+                        // Stop at the closing fence.
+                        if event.name == Name::CodeFencedFence {
+                            break;
+                        }
+                    } else if event.name == Name::LineEnding {
+                        if skip_line_ending {
+                            skip_line_ending = false;
+                        } else {
+                            if let Some((start, end)) = line_ending {
+                                value
+                                    .push_str(Slice::from_indices(self.bytes, start, end).as_str());
+                            }
+
+                            line_ending =
+                                Some(Position::from_exit_event(self.events, index).to_indices());
+                        }
+                    } else if event.name == Name::CodeFlowChunk {
+                        skip_line_ending = false;
+
+                        if let Some((start, end)) = line_ending.take() {
+                            value.push_str(Slice::from_indices(self.bytes, start, end).as_str());
+                        }
+
+                        value.push_str(
+                            // Must serialize to get virtual spaces.
+                            &Slice::from_position(
+                                self.bytes,
+                                &Position::from_exit_event(self.events, index),
+                            )
+                            .serialize(),
+                        );
+                    }
+
+                    index += 1;
+                }
+            }
+
+            let value = format!(" {}=\"{}\"", attribute, encode(&value, true));
+            self.push(&value);
+        }
+    }
+
+    /// Push a `dir` attribute for the nearest `name` that was entered, if
+    /// automatic text direction is on and most of its letters are written
+    /// right-to-left.
+    fn push_text_direction(&mut self, name: &Name) {
+        if self.options.auto_text_direction {
+            let mut index = self.index;
+
+            while !(self.events[index].kind == Kind::Enter && self.events[index].name == *name) {
+                index -= 1;
+            }
+
+            let exit = exit_index(self.events, index);
+            let mut rtl = 0;
+            let mut ltr = 0;
+
+            while index < exit {
+                if self.events[index].kind == Kind::Enter && self.events[index].name == Name::Data {
+                    let value = Slice::from_position(
+                        self.bytes,
+                        &Position::from_exit_event(self.events, index + 1),
+                    );
+
+                    for char in value.as_str().chars() {
+                        if is_rtl(char) {
+                            rtl += 1;
+                        } else if char.is_alphabetic() {
+                            ltr += 1;
+                        }
+                    }
+                }
+
+                index += 1;
+            }
+
+            if rtl > ltr {
+                self.push(" dir=\"rtl\"");
+            }
+        }
+    }
+
+    /// Push attributes: `own` ones of the element first, then ones for
+    /// `name` from the `attributes` option.
+    ///
+    /// Values are encoded.
+    /// Attributes with invalid names are dropped, and the values of later
+    /// `class` and `rel` attributes are added to the first one.
+    fn push_attributes(&mut self, name: &Name, own: Vec<(String, String)>) {
+        let mut attributes: Vec<(String, String)> = vec![];
+        let extra = self
+            .options
+            .attributes
+            .iter()
+            .filter(|d| d.0 == *name)
+            .map(|d| (d.1.clone(), d.2.clone()));
+
+        for (attribute, value) in own.into_iter().chain(extra) {
+            if !is_attribute_name(&attribute) {
+                continue;
+            }
+
+            if attribute.eq_ignore_ascii_case("class") || attribute.eq_ignore_ascii_case("rel") {
+                if let Some(existing) = attributes
+                    .iter_mut()
+                    .find(|d| d.0.eq_ignore_ascii_case(&attribute))
+                {
+                    if !value.is_empty() {
+                        if !existing.1.is_empty() {
+                            existing.1.push(' ');
+                        }
+
+                        existing.1.push_str(&value);
+                    }
+
+                    continue;
+                }
+            }
+
+            attributes.push((attribute, value));
+        }
+
+        for (attribute, value) in &attributes {
+            let value = format!(" {}=\"{}\"", attribute, encode(value, true));
+            self.push(&value);
+        }
+    }
+
     /// Add a line ending.
     fn line_ending(&mut self) {
         let eol = self.line_ending_default.as_str().to_string();
@@ -192,19 +514,51 @@ impl<'a> CompileContext<'a> {
     }
 
     /// Add a line ending if needed (as in, there’s no eol/eof already).
+    ///
+    /// Indents the next value, if `pretty` is on.
     fn line_ending_if_needed(&mut self) {
-        let last_buf_opt = self.buffers.last();
-        let last_buf = last_buf_opt.expect("at least one buffer should exist");
-        let last_byte = last_buf.as_bytes().last();
-
-        if !matches!(last_byte, None | Some(b'\n' | b'\r')) {
+        if !self.at_line_start() {
             self.line_ending();
         }
+
+        self.indent_next();
+    }
+
+    /// Whether the last buffer is empty or ends in a line ending.
+    fn at_line_start(&self) -> bool {
+        let last_buf_opt = self.buffers.last();
+        let last_buf = last_buf_opt.expect("at least one buffer should exist");
+        // Ignore what was in the string before we started.
+        let start = if self.buffers.len() == 1 {
+            self.buffer_start
+        } else {
+            0
+        };
+        let last_byte = last_buf.as_bytes()[start..].last();
+        matches!(last_byte, None | Some(b'\n' | b'\r'))
     }
 }
 
 /// Turn events and bytes into a string of HTML.
-pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> String {
+pub fn compile(
+    events: &[Event],
+    bytes: &[u8],
+    options: &CompileOptions,
+    text_constructs: &[Box<dyn TextConstruct>],
+) -> String {
+    let mut value = String::new();
+    compile_into(events, bytes, options, text_constructs, &mut value);
+    value
+}
+
+/// Turn events and bytes into HTML, appended to `value`.
+pub fn compile_into(
+    events: &[Event],
+    bytes: &[u8],
+    options: &CompileOptions,
+    text_constructs: &[Box<dyn TextConstruct>],
+    value: &mut String,
+) {
     let mut index = 0;
     let mut line_ending_inferred = None;
 
@@ -228,7 +582,10 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
     let line_ending_default =
         line_ending_inferred.unwrap_or_else(|| options.default_line_ending.clone());
 
-    let mut context = CompileContext::new(events, bytes, options, line_ending_default);
+    let mut context =
+        CompileContext::new(events, bytes, options, text_constructs, line_ending_default);
+    context.buffer_start = value.len();
+    context.buffers[0] = mem::take(value);
     let mut definition_indices = vec![];
     let mut index = 0;
     let mut definition_inside = false;
@@ -236,6 +593,8 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
     // Handle all definitions first.
     // We must do two passes because we need to compile the events in
     // definitions which come after references already.
+    // The same goes for abbreviation definitions, which can come after
+    // abbreviations.
     //
     // To speed things up, we collect the places we can jump over for the
     // second pass.
@@ -252,12 +611,12 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
         }
 
         if event.kind == Kind::Enter {
-            if event.name == Name::Definition {
+            if event.name == Name::Definition || event.name == Name::AbbreviationDefinition {
                 handle(&mut context, index); // Also handle start.
                 definition_inside = true;
                 definition_indices.push((index, index));
             }
-        } else if event.name == Name::Definition {
+        } else if event.name == Name::Definition || event.name == Name::AbbreviationDefinition {
             definition_inside = false;
             definition_indices.last_mut().unwrap().1 = index;
         }
@@ -291,11 +650,7 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
     }
 
     debug_assert_eq!(context.buffers.len(), 1, "expected 1 final buffer");
-    context
-        .buffers
-        .get(0)
-        .expect("expected 1 final buffer")
-        .into()
+    *value = context.buffers.pop().expect("expected 1 final buffer");
 }
 
 /// Handle the event at `index`.
@@ -312,6 +667,7 @@ fn handle(context: &mut CompileContext, index: usize) {
 /// Handle [`Enter`][Kind::Enter].
 fn enter(context: &mut CompileContext) {
     match context.events[context.index].name {
+        Name::Abbreviation => on_enter_abbreviation(context),
         Name::CodeFencedFenceInfo
         | Name::CodeFencedFenceMeta
         | Name::MathFlowFenceMeta
@@ -335,6 +691,8 @@ fn enter(context: &mut CompileContext) {
         Name::CodeText | Name::MathText => on_enter_raw_text(context),
         Name::Definition => on_enter_definition(context),
         Name::DefinitionDestinationString => on_enter_definition_destination_string(context),
+        Name::Details => on_enter_details(context),
+        Name::DetailsSummary => on_enter_details_summary(context),
         Name::Emphasis => on_enter_emphasis(context),
         Name::Frontmatter => on_enter_frontmatter(context),
         Name::GfmFootnoteDefinition => on_enter_gfm_footnote_definition(context),
@@ -356,6 +714,8 @@ fn enter(context: &mut CompileContext) {
         Name::Resource => on_enter_resource(context),
         Name::ResourceDestinationString => on_enter_resource_destination_string(context),
         Name::Strong => on_enter_strong(context),
+        Name::Subscript => on_enter_subscript(context),
+        Name::Superscript => on_enter_superscript(context),
         _ => {}
     }
 }
@@ -374,6 +734,11 @@ fn exit(context: &mut CompileContext) {
         Name::CharacterEscapeValue | Name::CodeTextData | Name::Data | Name::MathTextData => {
             on_exit_data(context);
         }
+        Name::Abbreviation => on_exit_abbreviation(context),
+        Name::AbbreviationDefinitionLabelString => {
+            on_exit_abbreviation_definition_label_string(context);
+        }
+        Name::AbbreviationDefinitionTitle => on_exit_abbreviation_definition_title(context),
         Name::AutolinkEmail => on_exit_autolink_email(context),
         Name::AutolinkProtocol => on_exit_autolink_protocol(context),
         Name::BlankLineEnding => on_exit_blank_line_ending(context),
@@ -395,6 +760,9 @@ fn exit(context: &mut CompileContext) {
         Name::DefinitionDestinationString => on_exit_definition_destination_string(context),
         Name::DefinitionLabelString => on_exit_definition_label_string(context),
         Name::DefinitionTitleString => on_exit_definition_title_string(context),
+        Name::Details => on_exit_details(context),
+        Name::DetailsSummary => on_exit_details_summary(context),
+        Name::EmojiShortcode => on_exit_emoji_shortcode(context),
         Name::Emphasis => on_exit_emphasis(context),
         Name::Frontmatter => on_exit_frontmatter(context),
         Name::GfmAutolinkLiteralEmail => on_exit_gfm_autolink_literal_email(context),
@@ -436,6 +804,9 @@ fn exit(context: &mut CompileContext) {
         Name::ResourceDestinationString => on_exit_resource_destination_string(context),
         Name::ResourceTitleString => on_exit_resource_title_string(context),
         Name::Strong => on_exit_strong(context),
+        Name::Subscript => on_exit_subscript(context),
+        Name::Superscript => on_exit_superscript(context),
+        Name::TextConstruct => on_exit_text_construct(context),
         Name::ThematicBreak => on_exit_thematic_break(context),
         _ => {}
     }
@@ -448,26 +819,81 @@ fn on_enter_buffer(context: &mut CompileContext) {
     context.buffer();
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Abbreviation`][Name::Abbreviation].
+fn on_enter_abbreviation(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        // An abbreviation contains one data event.
+        let label = Slice::from_indices(
+            context.bytes,
+            context.events[context.index].point.index,
+            context.events[context.index + 3].point.index,
+        );
+        let title = context
+            .abbreviations
+            .iter()
+            .find(|d| d.0 == label.as_str())
+            .map(|d| d.1.clone());
+
+        context.push("<abbr");
+
+        if let Some(title) = title {
+            context.push(" title=\"");
+            context.push(&encode(&title, true));
+            context.push("\"");
+        }
+
+        context.push_attributes(&Name::Abbreviation, vec![]);
+        context.push(">");
+    }
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`BlockQuote`][Name::BlockQuote].
 fn on_enter_block_quote(context: &mut CompileContext) {
     context.tight_stack.push(false);
     context.line_ending_if_needed();
-    context.push("<blockquote>");
+    context.push("<blockquote");
+    context.push_attributes(
+        &Name::BlockQuote,
+        class_attribute(context.options.block_quote_class.as_ref()),
+    );
+    context.push_source_position(&Name::BlockQuote);
+    context.push(">");
+    context.indent += 1;
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`CodeIndented`][Name::CodeIndented].
 fn on_enter_code_indented(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(false);
     context.line_ending_if_needed();
-    context.push("<pre><code>");
+    context.push("<pre");
+    context.push_attributes(
+        &Name::CodeIndented,
+        class_attribute(context.options.code_block_class.as_ref()),
+    );
+    context.push_source_position(&Name::CodeIndented);
+    context.push_code_line_numbers();
+    context.push_code_copy();
+    context.push("><code>");
 }
 
 /// Handle [`Enter`][Kind::Enter]:{[`CodeFenced`][Name::CodeFenced],[`MathFlow`][Name::MathFlow]}.
 fn on_enter_raw_flow(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(false);
     context.line_ending_if_needed();
+    context.push("<pre");
+
+    if context.events[context.index].name == Name::CodeFenced {
+        context.push_attributes(
+            &Name::CodeFenced,
+            class_attribute(context.options.code_block_class.as_ref()),
+        );
+        context.push_source_position(&Name::CodeFenced);
+        context.push_code_line_numbers();
+        context.push_code_copy();
+    }
+
     // Note that no `>` is used, which is added later (due to info)
-    context.push("<pre><code");
+    context.push("><code");
     context.raw_flow_fences_count = Some(0);
 
     if context.events[context.index].name == Name::MathFlow {
@@ -507,10 +933,29 @@ fn on_enter_definition_destination_string(context: &mut CompileContext) {
     context.encode_html = false;
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Details`][Name::Details].
+fn on_enter_details(context: &mut CompileContext) {
+    context.tight_stack.push(false);
+    context.line_ending_if_needed();
+    context.push("<details");
+    context.push_source_position(&Name::Details);
+    context.push_attributes(&Name::Details, vec![]);
+    context.push(">");
+    context.indent += 1;
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DetailsSummary`][Name::DetailsSummary].
+fn on_enter_details_summary(context: &mut CompileContext) {
+    context.line_ending_if_needed();
+    context.push("<summary>");
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Emphasis`][Name::Emphasis].
 fn on_enter_emphasis(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<em>");
+        context.push("<em");
+        context.push_attributes(&Name::Emphasis, vec![]);
+        context.push(">");
     }
 }
 
@@ -522,6 +967,11 @@ fn on_enter_frontmatter(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`GfmFootnoteDefinition`][Name::GfmFootnoteDefinition].
 fn on_enter_gfm_footnote_definition(context: &mut CompileContext) {
     context.tight_stack.push(false);
+    // Indent as content of an item in the list in the footnote section.
+    context
+        .gfm_footnote_definition_indent_stack
+        .push(context.indent);
+    context.indent = 3;
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmFootnoteCall`][Name::GfmFootnoteCall].
@@ -539,21 +989,57 @@ fn on_enter_gfm_footnote_call(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`GfmStrikethrough`][Name::GfmStrikethrough].
 fn on_enter_gfm_strikethrough(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<del>");
+        context.push("<");
+        context.push(context.options.strikethrough_element.tag_name());
+        context.push(">");
     }
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmTable`][Name::GfmTable].
 fn on_enter_gfm_table(context: &mut CompileContext) {
     let align = gfm_table_align(context.events, context.index);
-    context.gfm_table_align = Some(align);
     context.line_ending_if_needed();
-    context.push("<table>");
+    context.push("<table");
+    context.push_attributes(
+        &Name::GfmTable,
+        class_attribute(context.options.gfm_table_class.as_ref()),
+    );
+    context.push_source_position(&Name::GfmTable);
+    context.push(">");
+    context.indent += 1;
+
+    if context.options.gfm_table_colgroup {
+        context.line_ending_if_needed();
+        context.push("<colgroup>");
+        context.indent += 1;
+
+        for value in &align {
+            context.line_ending_if_needed();
+            context.push("<col");
+
+            match value {
+                AlignKind::Left => context.push(" style=\"text-align: left\""),
+                AlignKind::Right => context.push(" style=\"text-align: right\""),
+                AlignKind::Center => context.push(" style=\"text-align: center\""),
+                AlignKind::None => {}
+            }
+
+            context.push(" />");
+        }
+
+        context.indent -= 1;
+        context.line_ending_if_needed();
+        context.push("</colgroup>");
+    }
+
+    context.gfm_table_align = Some(align);
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmTableBody`][Name::GfmTableBody].
 fn on_enter_gfm_table_body(context: &mut CompileContext) {
+    context.indent_next();
     context.push("<tbody>");
+    context.indent += 1;
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmTableCell`][Name::GfmTableCell].
@@ -589,6 +1075,7 @@ fn on_enter_gfm_table_cell(context: &mut CompileContext) {
 fn on_enter_gfm_table_head(context: &mut CompileContext) {
     context.line_ending_if_needed();
     context.push("<thead>");
+    context.indent += 1;
     context.gfm_table_in_head = true;
 }
 
@@ -596,6 +1083,7 @@ fn on_enter_gfm_table_head(context: &mut CompileContext) {
 fn on_enter_gfm_table_row(context: &mut CompileContext) {
     context.line_ending_if_needed();
     context.push("<tr>");
+    context.indent += 1;
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck].
@@ -611,6 +1099,8 @@ fn on_enter_gfm_task_list_item_check(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`HtmlFlow`][Name::HtmlFlow].
 fn on_enter_html_flow(context: &mut CompileContext) {
     context.line_ending_if_needed();
+    // Do not indent raw HTML.
+    context.indent_next = false;
     if context.options.allow_dangerous_html {
         context.encode_html = false;
     }
@@ -618,6 +1108,7 @@ fn on_enter_html_flow(context: &mut CompileContext) {
 
 /// Handle [`Enter`][Kind::Enter]:[`HtmlText`][Name::HtmlText].
 fn on_enter_html_text(context: &mut CompileContext) {
+    context.html_text_inside = true;
     if context.options.allow_dangerous_html {
         context.encode_html = false;
     }
@@ -655,11 +1146,15 @@ fn on_enter_list(context: &mut CompileContext) {
     context.line_ending_if_needed();
 
     // Note: no `>`.
-    context.push(if context.events[context.index].name == Name::ListOrdered {
+    let name = context.events[context.index].name.clone();
+    context.push(if name == Name::ListOrdered {
         "<ol"
     } else {
         "<ul"
     });
+    context.push_source_position(&name);
+    context.push_attributes(&name, vec![]);
+    context.indent += 1;
     context.list_expect_first_marker = Some(true);
 }
 
@@ -671,17 +1166,35 @@ fn on_enter_list_item_marker(context: &mut CompileContext) {
 
     context.line_ending_if_needed();
 
-    context.push("<li>");
+    context.push("<li");
+    context.push_source_position(&Name::ListItem);
+    context.push(">");
+    context.indent += 1;
     context.list_expect_first_marker = Some(false);
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`Paragraph`][Name::Paragraph].
 fn on_enter_paragraph(context: &mut CompileContext) {
+    context.paragraph_inside = true;
     let tight = context.tight_stack.last().unwrap_or(&false);
 
     if !tight {
         context.line_ending_if_needed();
-        context.push("<p>");
+        context.figure_inside = context.options.images_as_figures
+            && paragraph_only_image(context.events, context.index);
+
+        if context.figure_inside {
+            context.push("<figure");
+            context.push_source_position(&Name::Paragraph);
+            context.push_text_direction(&Name::Paragraph);
+        } else {
+            context.push("<p");
+            context.push_source_position(&Name::Paragraph);
+            context.push_text_direction(&Name::Paragraph);
+            context.push_attributes(&Name::Paragraph, vec![]);
+        }
+
+        context.push(">");
     }
 }
 
@@ -702,15 +1215,68 @@ fn on_enter_resource_destination_string(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`Strong`][Name::Strong].
 fn on_enter_strong(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<strong>");
+        context.push("<strong");
+        context.push_attributes(&Name::Strong, vec![]);
+        context.push(">");
     }
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Subscript`][Name::Subscript].
+fn on_enter_subscript(context: &mut CompileContext) {
+    context.script_depth += 1;
+
+    if !context.image_alt_inside {
+        context.push("<sub>");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Superscript`][Name::Superscript].
+fn on_enter_superscript(context: &mut CompileContext) {
+    context.script_depth += 1;
+
+    if !context.image_alt_inside {
+        context.push("<sup>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Abbreviation`][Name::Abbreviation].
+fn on_exit_abbreviation(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("</abbr>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`AbbreviationDefinitionLabelString`][Name::AbbreviationDefinitionLabelString].
+fn on_exit_abbreviation_definition_label_string(context: &mut CompileContext) {
+    let label = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+    )
+    .as_str()
+    .into();
+    context.abbreviations.push((label, String::new()));
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`AbbreviationDefinitionTitle`][Name::AbbreviationDefinitionTitle].
+fn on_exit_abbreviation_definition_title(context: &mut CompileContext) {
+    let title = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+    )
+    .as_str()
+    .into();
+    context.abbreviations.last_mut().unwrap().1 = title;
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`AutolinkEmail`][Name::AutolinkEmail].
 fn on_exit_autolink_email(context: &mut CompileContext) {
     generate_autolink(
         context,
-        Some("mailto:"),
+        if context.options.autolink_email_without_mailto {
+            None
+        } else {
+            Some("mailto:")
+        },
         Slice::from_position(
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
@@ -743,6 +1309,9 @@ fn on_exit_break(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:[`BlankLineEnding`][Name::BlankLineEnding].
 fn on_exit_blank_line_ending(context: &mut CompileContext) {
+    // A line ending that was to be slurped is this blank one.
+    context.slurp_one_line_ending = false;
+
     if context.index == context.events.len() - 1 {
         context.line_ending_if_needed();
     }
@@ -751,6 +1320,7 @@ fn on_exit_blank_line_ending(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:[`BlockQuote`][Name::BlockQuote].
 fn on_exit_block_quote(context: &mut CompileContext) {
     context.tight_stack.pop();
+    context.indent -= 1;
     context.line_ending_if_needed();
     context.slurp_one_line_ending = false;
     context.push("</blockquote>");
@@ -854,6 +1424,8 @@ fn on_exit_raw_flow(context: &mut CompileContext) {
         context.line_ending_if_needed();
     }
 
+    // Do not indent in `pre`.
+    context.indent_next = false;
     context.push("</code></pre>");
 
     if let Some(count) = context.raw_flow_fences_count.take() {
@@ -933,14 +1505,19 @@ fn on_exit_drop_slurp(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeTextData`][Name::CodeTextData],[`Data`][Name::Data],[`CharacterEscapeValue`][Name::CharacterEscapeValue]}.
 fn on_exit_data(context: &mut CompileContext) {
-    context.push(&encode(
-        Slice::from_position(
-            context.bytes,
-            &Position::from_exit_event(context.events, context.index),
-        )
-        .as_str(),
-        context.encode_html,
-    ));
+    let slice = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+    );
+    let value = slice.as_str();
+
+    // Spaces in subscript and superscript are escaped with a backslash,
+    // which is not part of the result.
+    if context.script_depth > 0 && value.contains("\\ ") {
+        context.push(&encode(&value.replace("\\ ", " "), context.encode_html));
+    } else {
+        context.push(&encode(value, context.encode_html));
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`Definition`][Name::Definition].
@@ -979,6 +1556,30 @@ fn on_exit_definition_title_string(context: &mut CompileContext) {
     context.media_stack.last_mut().unwrap().title = Some(buf);
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`Details`][Name::Details].
+fn on_exit_details(context: &mut CompileContext) {
+    context.tight_stack.pop();
+    context.indent -= 1;
+    context.line_ending_if_needed();
+    context.slurp_one_line_ending = false;
+    context.push("</details>");
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DetailsSummary`][Name::DetailsSummary].
+fn on_exit_details_summary(context: &mut CompileContext) {
+    context.push("</summary>");
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`EmojiShortcode`][Name::EmojiShortcode].
+fn on_exit_emoji_shortcode(context: &mut CompileContext) {
+    let slice = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+    );
+    let value = decode_emoji(slice.as_str()).expect("expected to parse only known shortcodes");
+    context.push(value);
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`Emphasis`][Name::Emphasis].
 fn on_exit_emphasis(context: &mut CompileContext) {
     if !context.image_alt_inside {
@@ -1142,6 +1743,7 @@ fn on_exit_gfm_footnote_definition(context: &mut CompileContext) {
     let value = context.resume();
     let indices = context.gfm_footnote_definition_stack.pop().unwrap();
     context.tight_stack.pop();
+    context.indent = context.gfm_footnote_definition_indent_stack.pop().unwrap();
     context.gfm_footnote_definitions.push((
         normalize_identifier(Slice::from_indices(context.bytes, indices.0, indices.1).as_str()),
         value,
@@ -1151,19 +1753,23 @@ fn on_exit_gfm_footnote_definition(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:[`GfmStrikethrough`][Name::GfmStrikethrough].
 fn on_exit_gfm_strikethrough(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("</del>");
+        context.push("</");
+        context.push(context.options.strikethrough_element.tag_name());
+        context.push(">");
     }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmTable`][Name::GfmTable].
 fn on_exit_gfm_table(context: &mut CompileContext) {
     context.gfm_table_align = None;
+    context.indent -= 1;
     context.line_ending_if_needed();
     context.push("</table>");
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmTableBody`][Name::GfmTableBody].
 fn on_exit_gfm_table_body(context: &mut CompileContext) {
+    context.indent -= 1;
     context.line_ending_if_needed();
     context.push("</tbody>");
 }
@@ -1189,6 +1795,7 @@ fn on_exit_gfm_table_cell(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:[`GfmTableHead`][Name::GfmTableHead].
 fn on_exit_gfm_table_head(context: &mut CompileContext) {
     context.gfm_table_in_head = false;
+    context.indent -= 1;
     context.line_ending_if_needed();
     context.push("</thead>");
 }
@@ -1207,6 +1814,7 @@ fn on_exit_gfm_table_row(context: &mut CompileContext) {
     }
 
     context.gfm_table_column = 0;
+    context.indent -= 1;
     context.line_ending_if_needed();
     context.push("</tr>");
 }
@@ -1241,15 +1849,19 @@ fn on_exit_heading_atx(context: &mut CompileContext) {
 fn on_exit_heading_atx_sequence(context: &mut CompileContext) {
     // First fence we see.
     if context.heading_atx_rank.is_none() {
-        let rank = Slice::from_position(
-            context.bytes,
-            &Position::from_exit_event(context.events, context.index),
-        )
-        .len();
+        let rank = context.heading_rank(
+            Slice::from_position(
+                context.bytes,
+                &Position::from_exit_event(context.events, context.index),
+            )
+            .len(),
+        );
         context.line_ending_if_needed();
         context.heading_atx_rank = Some(rank);
         context.push("<h");
         context.push(&rank.to_string());
+        context.push_source_position(&Name::HeadingAtx);
+        context.push_attributes(&Name::HeadingAtx, vec![]);
         context.push(">");
     }
 }
@@ -1275,21 +1887,27 @@ fn on_exit_heading_setext_underline_sequence(context: &mut CompileContext) {
         .expect("`heading_atx_rank` must be set in headings");
     let position = Position::from_exit_event(context.events, context.index);
     let head = context.bytes[position.start.index];
-    let rank = if head == b'-' { "2" } else { "1" };
+    let rank = context
+        .heading_rank(if head == b'-' { 2 } else { 1 })
+        .to_string();
 
     context.line_ending_if_needed();
     context.push("<h");
-    context.push(rank);
+    context.push(&rank);
+    context.push_source_position(&Name::HeadingSetext);
+    context.push_attributes(&Name::HeadingSetext, vec![]);
     context.push(">");
     context.push(&text);
     context.push("</h");
-    context.push(rank);
+    context.push(&rank);
     context.push(">");
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`HtmlFlow`][Name::HtmlFlow],[`HtmlText`][Name::HtmlText]}.
 fn on_exit_html(context: &mut CompileContext) {
+    context.html_text_inside = false;
     context.encode_html = true;
+    context.html_state = HtmlState::Data;
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`HtmlFlowData`][Name::HtmlFlowData],[`HtmlTextData`][Name::HtmlTextData]}.
@@ -1298,7 +1916,13 @@ fn on_exit_html_data(context: &mut CompileContext) {
         context.bytes,
         &Position::from_exit_event(context.events, context.index),
     );
-    let value = slice.as_str();
+    let mut value = slice.as_str();
+    let stripped;
+
+    if context.options.strip_style_attributes && context.options.allow_dangerous_html {
+        stripped = strip_style_attributes(value, &mut context.html_state);
+        value = &stripped;
+    }
 
     let encoded = if context.options.gfm_tagfilter && context.options.allow_dangerous_html {
         encode(&gfm_tagfilter(value), context.encode_html)
@@ -1328,11 +1952,28 @@ fn on_exit_line_ending(context: &mut CompileContext) {
     } else if context.slurp_one_line_ending
         // Ignore line endings after definitions.
         || (context.index > 1
-            && (context.events[context.index - 2].name == Name::Definition
-                || context.events[context.index - 2].name == Name::GfmFootnoteDefinition))
+            && matches!(
+                context.events[context.index - 2].name,
+                Name::AbbreviationDefinition | Name::Definition | Name::GfmFootnoteDefinition
+            ))
+        // Ignore line endings before a final comment line without one.
+        || before_final_comment_line(context.events, context.bytes, context.index)
     {
         context.slurp_one_line_ending = false;
     } else {
+        // Turn soft breaks into hard breaks, if configured.
+        if context.options.soft_break_as_hard_break
+            && context.paragraph_inside
+            && !context.html_text_inside
+            && !context.image_alt_inside
+            && !matches!(
+                context.events[context.index - 2].name,
+                Name::HardBreakEscape | Name::HardBreakTrailing
+            )
+        {
+            context.push("<br />");
+        }
+
         context.push(&encode(
             Slice::from_position(
                 context.bytes,
@@ -1347,7 +1988,9 @@ fn on_exit_line_ending(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:{[`ListOrdered`][Name::ListOrdered],[`ListUnordered`][Name::ListUnordered]}.
 fn on_exit_list(context: &mut CompileContext) {
     context.tight_stack.pop();
+    context.indent -= 1;
     context.line_ending();
+    context.indent_next();
     context.push(if context.events[context.index].name == Name::ListOrdered {
         "</ol>"
     } else {
@@ -1367,6 +2010,7 @@ fn on_exit_list_item(context: &mut CompileContext) {
             Name::LineEnding,
             Name::SpaceOrTab,
             // Also ignore things that don’t contribute to the document.
+            Name::AbbreviationDefinition,
             Name::Definition,
             Name::GfmFootnoteDefinition,
         ],
@@ -1376,6 +2020,7 @@ fn on_exit_list_item(context: &mut CompileContext) {
     let empty_item = previous.name == Name::ListItemPrefix;
 
     context.slurp_one_line_ending = false;
+    context.indent -= 1;
 
     if !tight_paragraph && !empty_item {
         context.line_ending_if_needed();
@@ -1442,6 +2087,9 @@ fn on_exit_media(context: &mut CompileContext) {
         None
     };
 
+    let mut external = false;
+    let mut attributes = vec![];
+
     if !is_in_image {
         if media.image {
             context.push("<img src=\"");
@@ -1455,8 +2103,38 @@ fn on_exit_media(context: &mut CompileContext) {
             media.destination.as_ref()
         };
 
+        if !media.image {
+            if let Some(link_attributes) = context.options.link_attributes {
+                attributes = link_attributes(destination.map_or("", |d| d.as_str()));
+            }
+        }
+
         if let Some(destination) = destination {
-            let url = if context.options.allow_dangerous_protocol {
+            external = !media.image
+                && context.options.external_links_target_blank
+                && is_external(destination, context.options.external_link_base.as_deref());
+
+            let resolved = if media.image {
+                context
+                    .options
+                    .image_src_resolver
+                    .and_then(|image_src_resolver| image_src_resolver(destination))
+            } else {
+                None
+            };
+
+            let ascii;
+            let destination = if context.options.punycode_hosts {
+                ascii = to_ascii_host(destination);
+                &ascii
+            } else {
+                destination
+            };
+
+            // Sources from the resolver are trusted, so any protocol is fine.
+            let url = if let Some(resolved) = resolved {
+                sanitize(&resolved)
+            } else if context.options.allow_dangerous_protocol {
                 sanitize(destination)
             } else {
                 sanitize_with_protocols(
@@ -1471,7 +2149,8 @@ fn on_exit_media(context: &mut CompileContext) {
             context.push(&url);
         }
 
-        if media.image {
+        // An empty `alt` marks decorative images, but it can be left out.
+        if media.image && !(label.is_empty() && context.options.image_omit_empty_alt) {
             context.push("\" alt=\"");
         };
     }
@@ -1489,17 +2168,62 @@ fn on_exit_media(context: &mut CompileContext) {
             media.title
         };
 
-        if let Some(title) = title {
+        if let Some(title) = &title {
             context.push(" title=\"");
-            context.push(&title);
+            context.push(title);
             context.push("\"");
         };
 
+        if media.image {
+            if context.options.image_lazy_loading {
+                context.push(" loading=\"lazy\"");
+            }
+
+            if context.options.image_async_decoding {
+                context.push(" decoding=\"async\"");
+            }
+        }
+
+        let mut own = vec![];
+
+        if external {
+            own.push(("target".into(), "_blank".into()));
+
+            let rel = context
+                .options
+                .external_link_rel
+                .clone()
+                .unwrap_or_else(|| "noopener".into());
+
+            if !rel.is_empty() {
+                own.push(("rel".into(), rel));
+            }
+        }
+
+        own.append(&mut attributes);
+        context.push_attributes(
+            if media.image {
+                &Name::Image
+            } else {
+                &Name::Link
+            },
+            own,
+        );
+
         if media.image {
             context.push(" /");
         }
 
         context.push(">");
+
+        // The image of a figure is the only thing in it.
+        if context.figure_inside {
+            if let Some(title) = &title {
+                context.push("<figcaption>");
+                context.push(title);
+                context.push("</figcaption>");
+            }
+        }
     }
 
     if !media.image {
@@ -1513,10 +2237,14 @@ fn on_exit_media(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:[`Paragraph`][Name::Paragraph].
 fn on_exit_paragraph(context: &mut CompileContext) {
+    context.paragraph_inside = false;
     let tight = context.tight_stack.last().unwrap_or(&false);
 
     if *tight {
         context.slurp_one_line_ending = true;
+    } else if context.figure_inside {
+        context.figure_inside = false;
+        context.push("</figure>");
     } else {
         context.push("</p>");
     }
@@ -1551,10 +2279,78 @@ fn on_exit_strong(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`Subscript`][Name::Subscript].
+fn on_exit_subscript(context: &mut CompileContext) {
+    context.script_depth -= 1;
+
+    if !context.image_alt_inside {
+        context.push("</sub>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Superscript`][Name::Superscript].
+fn on_exit_superscript(context: &mut CompileContext) {
+    context.script_depth -= 1;
+
+    if !context.image_alt_inside {
+        context.push("</sup>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`TextConstruct`][Name::TextConstruct].
+fn on_exit_text_construct(context: &mut CompileContext) {
+    let slice = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+    );
+    let value = slice.as_str();
+
+    let start = context.events[context.index - 1].point.index;
+    let found = if context.image_alt_inside {
+        None
+    } else {
+        find_text_construct(
+            context.text_constructs,
+            context.bytes,
+            &mut context.text_construct_line,
+            start,
+        )
+    };
+
+    // Without the construct that matched, which happens when compiling
+    // events with other options, the match is text.
+    if let Some((construct_index, _)) = found {
+        let html = context.text_constructs[construct_index].to_html(value);
+        context.push(&html);
+    } else {
+        context.push(&encode(value, context.encode_html));
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`ThematicBreak`][Name::ThematicBreak].
 fn on_exit_thematic_break(context: &mut CompileContext) {
+    let tag_name = context
+        .options
+        .thematic_break_tag_name
+        .as_deref()
+        .map(|value| encode(value, true));
     context.line_ending_if_needed();
-    context.push("<hr />");
+    context.push("<");
+    context.push(tag_name.as_deref().unwrap_or("hr"));
+    context.push_attributes(
+        &Name::ThematicBreak,
+        class_attribute(context.options.thematic_break_class.as_ref()),
+    );
+    context.push_source_position(&Name::ThematicBreak);
+
+    match tag_name {
+        Some(tag_name) if !tag_name.eq_ignore_ascii_case("hr") => {
+            context.push("></");
+            context.push(&tag_name);
+            context.push(">");
+        }
+        _ => context.push(" />"),
+    }
 }
 
 /// Generate a footnote section.
@@ -1585,8 +2381,11 @@ fn generate_footnote_section(context: &mut CompileContext) {
         context.push("h2");
     }
     context.push(">");
+    context.indent += 1;
     context.line_ending();
+    context.indent_next();
     context.push("<ol>");
+    context.indent += 1;
 
     let mut index = 0;
     while index < context.gfm_footnote_definition_calls.len() {
@@ -1594,9 +2393,13 @@ fn generate_footnote_section(context: &mut CompileContext) {
         index += 1;
     }
 
+    context.indent -= 1;
     context.line_ending();
+    context.indent_next();
     context.push("</ol>");
+    context.indent -= 1;
     context.line_ending();
+    context.indent_next();
     context.push("</section>");
     context.line_ending();
 }
@@ -1622,6 +2425,7 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
     );
 
     context.line_ending();
+    context.indent_next();
     context.push("<li id=\"");
     if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
         context.push(&encode(value, context.encode_html));
@@ -1631,6 +2435,7 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
     context.push("fn-");
     context.push(&safe_id);
     context.push("\">");
+    context.indent += 1;
     context.line_ending();
 
     // Create one or more backreferences.
@@ -1699,6 +2504,7 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
         context.line_ending_if_needed();
         context.push(&backreferences);
     }
+    context.indent -= 1;
     context.line_ending_if_needed();
     context.push("</li>");
 }
@@ -1729,6 +2535,12 @@ fn generate_autolink(
             value.into()
         };
 
+        let url = if context.options.punycode_hosts {
+            to_ascii_host(&url)
+        } else {
+            url
+        };
+
         let url = if context.options.allow_dangerous_protocol {
             sanitize(&url)
         } else {
@@ -1745,3 +2557,107 @@ fn generate_autolink(
         context.push("</a>");
     }
 }
+
+/// Turn an optional class into a list of attributes.
+fn class_attribute(class: Option<&String>) -> Vec<(String, String)> {
+    class
+        .iter()
+        .map(|class| ("class".into(), (*class).clone()))
+        .collect()
+}
+
+/// Check whether a destination is external.
+///
+/// It is external if it is a web URL (it has an `http` or `https` protocol,
+/// or starts with `//`), and is not on `base`.
+fn is_external(destination: &str, base: Option<&str>) -> bool {
+    match (web_url(destination), base.and_then(web_url)) {
+        (Some(_), None) => true,
+        (Some((scheme, host, path)), Some((base_scheme, base_host, base_path))) => {
+            let base_path = base_path.trim_end_matches('/');
+            let same_scheme = match (scheme, base_scheme) {
+                (Some(scheme), Some(base_scheme)) => scheme.eq_ignore_ascii_case(base_scheme),
+                _ => true,
+            };
+
+            !(same_scheme
+                && host.eq_ignore_ascii_case(base_host)
+                && path.starts_with(base_path)
+                && matches!(
+                    path[base_path.len()..].chars().next(),
+                    None | Some('/' | '?' | '#')
+                ))
+        }
+        _ => false,
+    }
+}
+
+/// Split a web URL into its scheme, host, and the rest.
+///
+/// The scheme is `None` for protocol-relative URLs (`//example.com`).
+/// Returns `None` if `url` is not a web URL.
+fn web_url(url: &str) -> Option<(Option<&str>, &str, &str)> {
+    let (scheme, rest) = if let Some(rest) = url.strip_prefix("//") {
+        (None, rest)
+    } else {
+        let colon = url.find(':')?;
+        let scheme = &url[..colon];
+
+        if !(scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")) {
+            return None;
+        }
+
+        (Some(scheme), url[colon + 1..].strip_prefix("//")?)
+    };
+
+    let end = rest
+        .find(|c| matches!(c, '/' | '?' | '#'))
+        .unwrap_or(rest.len());
+
+    Some((scheme, &rest[..end], &rest[end..]))
+}
+
+/// Check whether the paragraph entered at `index` contains only an image,
+/// ignoring whitespace around it.
+fn paragraph_only_image(events: &[Event], index: usize) -> bool {
+    let exit = exit_index(events, index);
+    let mut image = index + 1;
+
+    if events[image].name == Name::SpaceOrTab {
+        image += 2;
+    }
+
+    if !(events[image].kind == Kind::Enter && events[image].name == Name::Image) {
+        return false;
+    }
+
+    let mut after = exit_index(events, image) + 1;
+
+    if events[after].name == Name::SpaceOrTab {
+        after += 2;
+    }
+
+    after == exit
+}
+
+/// Check whether the line ending exited at `index` is followed by a final
+/// comment line that does not end in a line ending, ignoring exits of
+/// containers.
+fn before_final_comment_line(events: &[Event], bytes: &[u8], mut index: usize) -> bool {
+    let mut end = None;
+    index += 1;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.name == Name::CommentLine {
+            end = Some(event.point.index);
+        } else if event.kind == Kind::Enter {
+            return false;
+        }
+
+        index += 1;
+    }
+
+    matches!(end, Some(end) if !matches!(bytes[end - 1], b'\n' | b'\r'))
+}
@@ -0,0 +1,348 @@
+//! Turn events into a string of markdown.
+//!
+//! Markdown is not rebuilt from a syntax tree: the source is kept as it is,
+//! except for the constructs that are normalized.
+use crate::configuration::{HeadingStyle, ParseOptions, ThematicBreakStyle, ToMarkdownOptions};
+use crate::event::{Event, Kind, Name};
+use crate::parser::parse;
+use crate::util::{
+    constant::THEMATIC_BREAK_MARKER_COUNT_MIN,
+    line_ending::LineEnding,
+    markdown::exit_index,
+    slice::{Position, Slice},
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::str;
+
+/// Turn events and bytes into a string of markdown.
+pub fn compile(
+    events: &[Event],
+    bytes: &[u8],
+    parse_options: &ParseOptions,
+    options: &ToMarkdownOptions,
+) -> String {
+    let mut index = 0;
+    let mut line_ending = LineEnding::default();
+
+    // First, we figure out what the used line ending style is.
+    // Stop when we find a line ending.
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit
+            && (event.name == Name::BlankLineEnding || event.name == Name::LineEnding)
+        {
+            let slice = Slice::from_position(bytes, &Position::from_exit_event(events, index));
+            line_ending = slice.as_str().parse().unwrap();
+            break;
+        }
+
+        index += 1;
+    }
+
+    let mut result = String::with_capacity(bytes.len());
+    let mut start = 0;
+    index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            let value = match (&event.name, options.normalize_headings) {
+                (Name::HeadingSetext, HeadingStyle::Atx) => setext_to_atx(events, bytes, index),
+                (Name::HeadingAtx, HeadingStyle::Setext) => {
+                    atx_to_setext(events, bytes, index, parse_options, &line_ending)
+                }
+                (Name::ThematicBreak, _) => {
+                    thematic_break(events, bytes, index, parse_options, options)
+                }
+                _ => None,
+            };
+
+            if let Some(value) = value {
+                let exit = exit_index(events, index);
+                result.push_str(Slice::from_indices(bytes, start, event.point.index).as_str());
+                result.push_str(&value);
+                start = events[exit].point.index;
+                index = exit;
+            }
+        }
+
+        index += 1;
+    }
+
+    result.push_str(Slice::from_indices(bytes, start, bytes.len()).as_str());
+    result
+}
+
+/// Turn the heading (setext) at `index` into a heading (atx).
+///
+/// Returns `None` if that is not possible.
+fn setext_to_atx(events: &[Event], bytes: &[u8], mut index: usize) -> Option<String> {
+    let mut value = String::new();
+    let mut start = events[index + 1].point.index;
+    let mut line_start = true;
+    // Skip the heading and its text.
+    index += 2;
+
+    // Join lines with spaces, without the container prefixes and whitespace
+    // at their starts.
+    while !(events[index].kind == Kind::Exit && events[index].name == Name::HeadingSetextText) {
+        let event = &events[index];
+
+        if matches!(event.name, Name::HardBreakEscape | Name::HardBreakTrailing) {
+            return None;
+        }
+
+        if event.kind == Kind::Enter {
+            if event.name == Name::LineEnding {
+                let line = str::from_utf8(&bytes[start..event.point.index]).unwrap();
+                value.push_str(line.trim_end());
+                value.push(' ');
+                line_start = true;
+            } else if line_start {
+                if matches!(event.name, Name::BlockQuotePrefix | Name::SpaceOrTab) {
+                    index = exit_index(events, index);
+                } else {
+                    start = event.point.index;
+                    line_start = false;
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    value.push_str(Slice::from_indices(bytes, start, events[index].point.index).as_str());
+
+    // Skip to the underline.
+    while events[index].name != Name::HeadingSetextUnderlineSequence {
+        index += 1;
+    }
+
+    let rank = if bytes[events[index].point.index] == b'-' {
+        2
+    } else {
+        1
+    };
+    // Escape the first `#` of a final run of them, if it would otherwise be
+    // seen as a closing sequence.
+    let run = value.trim_end_matches('#').len();
+
+    if run < value.len() && (run == 0 || value[..run].ends_with(' ')) {
+        value.insert(run, '\\');
+    }
+
+    Some(format!("{} {}", "#".repeat(rank), value))
+}
+
+/// Check whether the flow construct at `index` starts or ends the document,
+/// or is next to a blank line, ignoring containers.
+///
+/// Checks what comes after when `after`, and before otherwise.
+fn next_to_blank(events: &[Event], mut index: usize, after: bool) -> bool {
+    loop {
+        if after {
+            index += 1;
+
+            if index == events.len() {
+                return true;
+            }
+        } else if index == 0 {
+            return true;
+        } else {
+            index -= 1;
+        }
+
+        let event = &events[index];
+
+        if !matches!(
+            event.name,
+            Name::BlockQuote
+                | Name::BlockQuoteMarker
+                | Name::BlockQuotePrefix
+                | Name::LineEnding
+                | Name::ListItem
+                | Name::ListItemMarker
+                | Name::ListItemPrefix
+                | Name::ListItemValue
+                | Name::ListOrdered
+                | Name::ListUnordered
+                | Name::SpaceOrTab
+        ) {
+            return event.name == Name::BlankLineEnding;
+        }
+    }
+}
+
+/// Turn the thematic break at `index` into the configured style.
+///
+/// Returns `None` if that is not possible.
+fn thematic_break(
+    events: &[Event],
+    bytes: &[u8],
+    index: usize,
+    parse_options: &ParseOptions,
+    options: &ToMarkdownOptions,
+) -> Option<String> {
+    let marker = options.normalize_thematic_breaks.marker()?;
+    let line = events[index].point.line;
+    let mut before = index;
+
+    // List item markers on the same line would form one thematic break with
+    // markers of the same kind.
+    while before > 0 && events[before - 1].point.line == line {
+        before -= 1;
+        let event = &events[before];
+
+        if event.kind == Kind::Enter
+            && event.name == Name::ListItemMarker
+            && char::from(bytes[event.point.index]) == marker
+        {
+            return None;
+        }
+    }
+
+    if options.normalize_thematic_breaks == ThematicBreakStyle::Dash {
+        // Frontmatter fences look like thematic breaks (dash).
+        if parse_options.constructs.frontmatter && events[index].point.index == 0 {
+            return None;
+        }
+
+        // Thematic breaks (dash) after paragraphs are headings (setext)
+        // underlines.
+        let mut before = index;
+
+        while before > 0 {
+            before -= 1;
+            let event = &events[before];
+
+            if !matches!(
+                event.name,
+                Name::LineEnding
+                    | Name::BlockQuotePrefix
+                    | Name::BlockQuoteMarker
+                    | Name::SpaceOrTab
+            ) {
+                if event.kind == Kind::Exit && event.name == Name::Paragraph {
+                    return None;
+                }
+
+                break;
+            }
+        }
+    }
+
+    Some(
+        marker.to_string().repeat(
+            options
+                .thematic_break_size
+                .max(THEMATIC_BREAK_MARKER_COUNT_MIN),
+        ),
+    )
+}
+
+/// Turn the heading (atx) at `index` into a heading (setext).
+///
+/// Returns `None` if that is not possible.
+fn atx_to_setext(
+    events: &[Event],
+    bytes: &[u8],
+    index: usize,
+    parse_options: &ParseOptions,
+    line_ending: &LineEnding,
+) -> Option<String> {
+    let exit = exit_index(events, index);
+    let mut rank = 0;
+    let mut text = None;
+    let mut inner = index + 1;
+
+    while inner < exit {
+        let event = &events[inner];
+
+        if event.kind == Kind::Enter
+            && matches!(event.name, Name::HeadingAtxSequence | Name::HeadingAtxText)
+        {
+            let end = events[exit_index(events, inner)].point.index;
+
+            if event.name == Name::HeadingAtxText {
+                text = Some(
+                    str::from_utf8(&bytes[event.point.index..end])
+                        .unwrap()
+                        .trim_end(),
+                );
+            } else if rank == 0 {
+                rank = end - event.point.index;
+            }
+        }
+
+        inner += 1;
+    }
+
+    let text = text?;
+
+    if rank > 2 {
+        return None;
+    }
+
+    let underline = (if rank == 1 { "=" } else { "-" }).repeat(text.chars().count());
+
+    // Make sure the text forms a heading (setext) on its own, and does not
+    // start something else, such as a list.
+    let (check, _) = parse(&format!("{}\n{}", text, underline), parse_options).ok()?;
+
+    if !(check[0].kind == Kind::Enter
+        && check[0].name == Name::HeadingSetext
+        && check[check.len() - 1].name == Name::HeadingSetext)
+    {
+        return None;
+    }
+
+    // Continue containers: keep block quote markers and tabs, replace list
+    // item markers with spaces.
+    let start = events[index].point.index;
+    let mut line_start = start;
+
+    while line_start > 0 && !matches!(bytes[line_start - 1], b'\n' | b'\r') {
+        line_start -= 1;
+    }
+
+    // The text would continue a paragraph before it, and in containers, a
+    // line after the underline can be lazy, so only turn headings that are
+    // away from such things into headings (setext).
+    if !next_to_blank(events, index, false)
+        || (line_start != start && !next_to_blank(events, exit, true))
+    {
+        return None;
+    }
+
+    // A thematic break right after the underline would not be seen as one.
+    let mut after = exit + 1;
+
+    while after < events.len() && events[after].name == Name::LineEnding {
+        after += 1;
+    }
+
+    if after < events.len() && events[after].name == Name::ThematicBreak {
+        return None;
+    }
+
+    let prefix = bytes[line_start..start]
+        .iter()
+        .map(|&byte| match byte {
+            b'>' | b'\t' => char::from(byte),
+            _ => ' ',
+        })
+        .collect::<String>();
+
+    Some(format!(
+        "{}{}{}{}",
+        text,
+        line_ending.as_str(),
+        prefix,
+        underline
+    ))
+}
@@ -14,12 +14,14 @@ use crate::util::{
     character_reference::{
         decode as decode_character_reference, parse as parse_character_reference,
     },
+    emoji::decode as decode_emoji,
     infer::{gfm_table_align, list_item_loose, list_loose},
     mdx_collect::{collect, Result as CollectResult},
     normalize_identifier::normalize_identifier,
     slice::{Position as SlicePosition, Slice},
 };
 use alloc::{
+    collections::BTreeMap,
     format,
     string::{String, ToString},
     vec,
@@ -87,6 +89,8 @@ struct CompileContext<'a> {
     events: &'a [Event],
     /// List of bytes.
     bytes: &'a [u8],
+    /// Titles of abbreviation definitions, by label.
+    abbreviations: BTreeMap<String, String>,
     // Fields used by handlers to track the things they need to track to
     // compile markdown.
     character_reference_marker: u8,
@@ -126,6 +130,7 @@ impl<'a> CompileContext<'a> {
         CompileContext {
             events,
             bytes,
+            abbreviations: abbreviations(events, bytes),
             character_reference_marker: 0,
             gfm_table_inside: false,
             hard_break_after: false,
@@ -242,6 +247,40 @@ pub fn compile(events: &[Event], bytes: &[u8]) -> Result<Node, String> {
     Ok(tree)
 }
 
+/// Get the titles of abbreviation definitions, by label.
+///
+/// When a label is defined several times, the first definition is used.
+fn abbreviations(events: &[Event], bytes: &[u8]) -> BTreeMap<String, String> {
+    let mut abbreviations = BTreeMap::new();
+    let mut label = None;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            let value = || {
+                String::from(
+                    Slice::from_position(bytes, &SlicePosition::from_exit_event(events, index))
+                        .as_str(),
+                )
+            };
+
+            if event.name == Name::AbbreviationDefinitionLabelString {
+                label = Some(value());
+            } else if event.name == Name::AbbreviationDefinitionTitle {
+                if let Some(label) = label.take() {
+                    abbreviations.entry(label).or_insert_with(value);
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    abbreviations
+}
+
 /// Handle the event at `index`.
 fn handle(context: &mut CompileContext, index: usize) -> Result<(), String> {
     context.index = index;
@@ -258,6 +297,7 @@ fn handle(context: &mut CompileContext, index: usize) -> Result<(), String> {
 /// Handle [`Enter`][Kind::Enter].
 fn enter(context: &mut CompileContext) -> Result<(), String> {
     match context.events[context.index].name {
+        Name::Abbreviation => on_enter_abbreviation(context),
         Name::AutolinkEmail
         | Name::AutolinkProtocol
         | Name::CharacterEscapeValue
@@ -265,12 +305,14 @@ fn enter(context: &mut CompileContext) -> Result<(), String> {
         | Name::CodeFlowChunk
         | Name::CodeTextData
         | Name::Data
+        | Name::Emoji
         | Name::FrontmatterChunk
         | Name::HtmlFlowData
         | Name::HtmlTextData
         | Name::MathFlowChunk
         | Name::MathTextData
-        | Name::MdxJsxTagAttributeValueLiteralValue => on_enter_data(context),
+        | Name::MdxJsxTagAttributeValueLiteralValue
+        | Name::TextConstruct => on_enter_data(context),
         Name::CodeFencedFenceInfo
         | Name::CodeFencedFenceMeta
         | Name::DefinitionDestinationString
@@ -322,6 +364,8 @@ fn enter(context: &mut CompileContext) -> Result<(), String> {
             on_enter_mdx_jsx_tag_attribute_value_expression(context);
         }
         Name::MdxJsxTagSelfClosingMarker => on_enter_mdx_jsx_tag_self_closing_marker(context)?,
+        Name::Details => on_enter_details(context),
+        Name::DetailsSummary => on_enter_details_summary(context),
         Name::Paragraph => on_enter_paragraph(context),
         Name::Reference => on_enter_reference(context),
         Name::Resource => on_enter_resource(context),
@@ -336,10 +380,14 @@ fn enter(context: &mut CompileContext) -> Result<(), String> {
 /// Handle [`Exit`][Kind::Exit].
 fn exit(context: &mut CompileContext) -> Result<(), String> {
     match context.events[context.index].name {
-        Name::Autolink
+        Name::Abbreviation
+        | Name::Autolink
         | Name::BlockQuote
         | Name::CharacterReference
         | Name::Definition
+        | Name::Details
+        | Name::DetailsSummary
+        | Name::Emoji
         | Name::Emphasis
         | Name::GfmFootnoteDefinition
         | Name::GfmStrikethrough
@@ -362,7 +410,8 @@ fn exit(context: &mut CompileContext) -> Result<(), String> {
         | Name::HtmlTextData
         | Name::MathFlowChunk
         | Name::MathTextData
-        | Name::MdxJsxTagAttributeValueLiteralValue => {
+        | Name::MdxJsxTagAttributeValueLiteralValue
+        | Name::TextConstruct => {
             on_exit_data(context)?;
         }
         Name::MdxJsxTagAttributeExpression | Name::MdxJsxTagAttributeValueExpression => {
@@ -389,6 +438,7 @@ fn exit(context: &mut CompileContext) -> Result<(), String> {
             on_exit_definition_id(context);
         }
         Name::DefinitionTitleString => on_exit_definition_title_string(context),
+        Name::EmojiShortcode => on_exit_emoji_shortcode(context),
         Name::Frontmatter => on_exit_frontmatter(context)?,
         Name::GfmAutolinkLiteralEmail
         | Name::GfmAutolinkLiteralMailto
@@ -455,6 +505,36 @@ fn on_enter_data(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Abbreviation`][Name::Abbreviation].
+///
+/// There is no node for abbreviations in mdast, so they are turned into the
+/// JSX element that MDX would give for `<abbr>`.
+fn on_enter_abbreviation(context: &mut CompileContext) {
+    // An abbreviation contains one data event.
+    let label = Slice::from_indices(
+        context.bytes,
+        context.events[context.index].point.index,
+        context.events[context.index + 3].point.index,
+    );
+    let attributes = context
+        .abbreviations
+        .get(label.as_str())
+        .map(|title| {
+            vec![AttributeContent::Property(MdxJsxAttribute {
+                name: "title".into(),
+                value: Some(AttributeValue::Literal(title.clone())),
+            })]
+        })
+        .unwrap_or_default();
+
+    context.tail_push(Node::MdxJsxTextElement(MdxJsxTextElement {
+        name: Some("abbr".into()),
+        attributes,
+        children: vec![],
+        position: None,
+    }));
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Autolink`][Name::Autolink].
 fn on_enter_autolink(context: &mut CompileContext) {
     context.tail_push(Node::Link(Link {
@@ -569,6 +649,29 @@ fn on_enter_definition(context: &mut CompileContext) {
     }));
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Details`][Name::Details].
+///
+/// There is no node for details in mdast, so they are turned into the JSX
+/// element that MDX would give for `<details>`.
+fn on_enter_details(context: &mut CompileContext) {
+    context.tail_push(Node::MdxJsxFlowElement(MdxJsxFlowElement {
+        name: Some("details".into()),
+        attributes: vec![],
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DetailsSummary`][Name::DetailsSummary].
+fn on_enter_details_summary(context: &mut CompileContext) {
+    context.tail_push(Node::MdxJsxFlowElement(MdxJsxFlowElement {
+        name: Some("summary".into()),
+        attributes: vec![],
+        children: vec![],
+        position: None,
+    }));
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Emphasis`][Name::Emphasis].
 fn on_enter_emphasis(context: &mut CompileContext) {
     context.tail_push(Node::Emphasis(Emphasis {
@@ -1081,8 +1184,15 @@ fn on_exit_data(context: &mut CompileContext) -> Result<(), String> {
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
     );
+    let value = value.as_str();
     if let Node::Text(text) = context.tail_mut() {
-        text.value.push_str(value.as_str());
+        // Like `CommonMark` requires for HTML, replace NUL with U+FFFD
+        // REPLACEMENT CHARACTER (`�`).
+        if value.contains('\0') {
+            text.value.push_str(&value.replace('\0', "\u{FFFD}"));
+        } else {
+            text.value.push_str(value);
+        }
     } else {
         unreachable!("expected text on stack");
     }
@@ -1132,6 +1242,21 @@ fn on_exit_definition_title_string(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`EmojiShortcode`][Name::EmojiShortcode].
+fn on_exit_emoji_shortcode(context: &mut CompileContext) {
+    let slice = Slice::from_position(
+        context.bytes,
+        &SlicePosition::from_exit_event(context.events, context.index),
+    );
+    let value = decode_emoji(slice.as_str()).expect("expected to parse only known shortcodes");
+
+    if let Node::Text(node) = context.tail_mut() {
+        node.value.push_str(value);
+    } else {
+        unreachable!("expected text on stack");
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:*, by dropping the current buffer.
 fn on_exit_drop(context: &mut CompileContext) {
     context.resume();
@@ -8,17 +8,19 @@
 //!
 //! [`attempt`]: Tokenizer::attempt
 
+use crate::configuration::{Constructs, ParseOptions};
 use crate::event::{Content, Event, Kind, Link, Name, Point, VOID_EVENTS};
 use crate::parser::ParseState;
 use crate::resolve::{call as call_resolve, Name as ResolveName};
 use crate::state::{call, State};
-use crate::subtokenize::Subresult;
+use crate::subtokenize::{link, Subresult};
 
 #[cfg(feature = "log")]
 use crate::util::char::format_byte_opt;
 
-use crate::util::{constant::TAB_SIZE, edit_map::EditMap};
-use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use crate::util::{constant::TAB_SIZE, edit_map::EditMap, warning::Warning};
+use alloc::{boxed::Box, collections::BTreeSet, string::String, vec, vec::Vec};
+use core::mem;
 
 /// Containers.
 ///
@@ -35,6 +37,8 @@ pub enum Container {
     ListItem,
     /// [GFM: Footnote definition][crate::construct::gfm_footnote_definition].
     GfmFootnoteDefinition,
+    /// [Details][crate::construct::details].
+    Details,
 }
 
 /// Info used to tokenize a container.
@@ -200,6 +204,9 @@ pub struct TokenizeState<'a> {
     /// a list item.
     /// Used for GFM task list items.
     pub document_at_first_paragraph_of_list_item: bool,
+    /// Whether this is the destination of a link, image, or definition, and
+    /// backslashes in it are literal.
+    pub destination_literal_backslash: bool,
 
     // Couple of very frequent settings for parsing whitespace.
     pub space_or_tab_eol_content: Option<Content>,
@@ -230,6 +237,14 @@ pub struct TokenizeState<'a> {
     pub definitions: Vec<String>,
     /// List of defined GFM footnote definition identifiers.
     pub gfm_footnote_definitions: Vec<String>,
+    /// List of defined abbreviation labels.
+    pub abbreviations: Vec<String>,
+    /// List of warnings.
+    pub warnings: Vec<Warning>,
+    /// Start and end indices of undefined references that are warned about.
+    ///
+    /// Attempts can try the same reference again, which must not warn twice.
+    pub warned_references: BTreeSet<(usize, usize)>,
 
     // Last error message provided at an EOF of an expression.
     pub mdx_last_parse_error: Option<String>,
@@ -241,7 +256,10 @@ pub struct TokenizeState<'a> {
     /// Secondary marker.
     pub marker_b: u8,
     /// Several markers.
-    pub markers: &'static [u8],
+    pub markers: &'a [u8],
+    /// Rest of the line at the last custom text construct marker, and where
+    /// it starts.
+    pub text_construct_line: (usize, &'a str),
     /// Whether something was seen.
     pub seen: bool,
     /// Size.
@@ -285,6 +303,8 @@ pub struct Tokenizer<'a> {
     consumed: bool,
     /// Stack of how to handle attempts.
     attempts: Vec<Attempt>,
+    /// End of what is currently fed, as index and virtual spaces.
+    to: (usize, usize),
     /// Current byte.
     pub current: Option<u8>,
     /// Previous byte.
@@ -334,6 +354,7 @@ impl<'a> Tokenizer<'a> {
             line_start: point.clone(),
             consumed: true,
             attempts: vec![],
+            to: (0, 0),
             point,
             stack: vec![],
             events: vec![],
@@ -348,8 +369,12 @@ impl<'a> Tokenizer<'a> {
                 document_child_state: None,
                 document_child: None,
                 document_at_first_paragraph_of_list_item: false,
+                destination_literal_backslash: false,
                 definitions: vec![],
                 gfm_footnote_definitions: vec![],
+                abbreviations: vec![],
+                warnings: vec![],
+                warned_references: BTreeSet::new(),
                 mdx_last_parse_error: None,
                 end: 0,
                 label_starts: vec![],
@@ -357,6 +382,7 @@ impl<'a> Tokenizer<'a> {
                 marker: 0,
                 marker_b: 0,
                 markers: &[],
+                text_construct_line: (0, ""),
                 labels: vec![],
                 seen: false,
                 size: 0,
@@ -388,6 +414,54 @@ impl<'a> Tokenizer<'a> {
         }
     }
 
+    /// Reset the tokenizer, to tokenize something else.
+    ///
+    /// Everything is reset as if the tokenizer was new, but the buffers for
+    /// events, the stack, attempts, and line jumps keep their allocations.
+    ///
+    /// This is internal: the tokenizer is not exposed.
+    /// It is used in [`subtokenize`][crate::subtokenize::subtokenize], to
+    /// tokenize each chunk of content in a document with one tokenizer.
+    pub fn reset(&mut self, point: Point, parse_state: &'a ParseState) {
+        let mut events = mem::take(&mut self.events);
+        let mut stack = mem::take(&mut self.stack);
+        let mut attempts = mem::take(&mut self.attempts);
+        let mut column_start = mem::take(&mut self.column_start);
+        events.clear();
+        stack.clear();
+        attempts.clear();
+        column_start.clear();
+        *self = Tokenizer::new(point, parse_state);
+        self.events = events;
+        self.stack = stack;
+        self.attempts = attempts;
+        self.column_start = column_start;
+    }
+
+    /// Get the parse options.
+    ///
+    /// Read-only: constructs can use this to check how they are configured,
+    /// including whether sibling constructs are turned on.
+    pub fn options(&self) -> &'a ParseOptions {
+        self.parse_state.options
+    }
+
+    /// Get which constructs are turned on.
+    pub fn constructs(&self) -> &'a Constructs {
+        &self.parse_state.options.constructs
+    }
+
+    /// Get the current point.
+    ///
+    /// Read-only: constructs can use this for positional info in messages,
+    /// or to decide things based on where they are, such as the column.
+    ///
+    /// This is internal, like the tokenizer: custom constructs are given the
+    /// rest of the line instead (see [`TextConstruct`][crate::TextConstruct]).
+    pub fn point(&self) -> &Point {
+        &self.point
+    }
+
     /// Register a resolver.
     pub fn register_resolver(&mut self, name: ResolveName) {
         if !self.resolvers.contains(&name) {
@@ -407,13 +481,21 @@ impl<'a> Tokenizer<'a> {
     /// This defines to which future index we move after a line ending.
     pub fn define_skip(&mut self, mut point: Point) {
         move_point_back(self, &mut point);
+        self.define_skip_line(point.line, &point);
+    }
 
+    /// Define a jump from the start of `line` to `point`.
+    ///
+    /// When `point` is on a later line, the lines in between are skipped
+    /// entirely, which is used for lines that are not fed to this tokenizer
+    /// (such as comment lines).
+    pub fn define_skip_line(&mut self, line: usize, point: &Point) {
         let info = (point.index, point.vs);
 
         #[cfg(feature = "log")]
-        log::debug!("position: define skip: {:?} -> ({:?})", point.line, info);
+        log::debug!("position: define skip: {:?} -> ({:?})", line, info);
 
-        let at = point.line - self.first_line;
+        let at = line - self.first_line;
 
         if at >= self.column_start.len() {
             self.column_start.push(info);
@@ -456,6 +538,43 @@ impl<'a> Tokenizer<'a> {
         self.consumed = true;
     }
 
+    /// Get the bytes after the current point, up to the end of what is
+    /// currently fed.
+    ///
+    /// This is empty when in the virtual spaces of a tab.
+    pub fn bytes_ahead(&self) -> &'a [u8] {
+        if self.point.vs == 0 && self.point.index < self.to.0 {
+            &self.parse_state.bytes[self.point.index..self.to.0]
+        } else {
+            &[]
+        }
+    }
+
+    /// Consume `size` bytes of [`bytes_ahead`][Tokenizer::bytes_ahead] at
+    /// once, after the current byte was consumed.
+    ///
+    /// This is like feeding and consuming them one by one, without going
+    /// through the state machine, so it is much faster for runs of bytes
+    /// that a state would consume anyway.
+    /// The bytes cannot be line endings or tabs, as they are not moved over
+    /// byte by byte.
+    pub fn consume_ahead(&mut self, size: usize) {
+        debug_assert!(self.consumed, "expected current byte to be consumed");
+
+        if size > 0 {
+            let bytes = &self.bytes_ahead()[..size];
+            debug_assert!(
+                !bytes
+                    .iter()
+                    .any(|byte| matches!(byte, b'\t' | b'\n' | b'\r')),
+                "expected no line endings or tabs"
+            );
+            self.previous = Some(bytes[size - 1]);
+            self.point.index += size;
+            self.point.column += size;
+        }
+    }
+
     /// Move to the next (virtual) byte.
     fn move_one(&mut self) {
         match byte_action(self.parse_state.bytes, &self.point) {
@@ -480,10 +599,15 @@ impl<'a> Tokenizer<'a> {
                         self.column_start.push((self.point.index, self.point.vs));
                     }
 
-                    self.line_start = self.point.clone();
+                    let line_start = self.point.clone();
 
                     self.account_for_potential_skip();
 
+                    // The skip can pass over lines that are not part of
+                    // this chunk (such as comment lines), which must not
+                    // end up in the line ending.
+                    self.line_start = line_start;
+
                     #[cfg(feature = "log")]
                     log::debug!("position: after eol: `{:?}`", self.point);
                 } else {
@@ -511,6 +635,30 @@ impl<'a> Tokenizer<'a> {
         enter_impl(self, name, Some(link));
     }
 
+    /// Enter a chunk of `content`, linked to the previous chunk if
+    /// [`connect`][TokenizeState::connect] is on.
+    ///
+    /// Turns `connect` on otherwise, so that the next chunk is linked to this
+    /// one.
+    pub fn enter_with_content(&mut self, name: Name, content: Content) {
+        enter_impl(
+            self,
+            name,
+            Some(Link {
+                previous: None,
+                next: None,
+                content,
+            }),
+        );
+
+        if self.tokenize_state.connect {
+            let index = self.events.len() - 1;
+            link(&mut self.events, index);
+        } else {
+            self.tokenize_state.connect = true;
+        }
+    }
+
     /// Mark the end of a semantic label.
     pub fn exit(&mut self, name: Name) {
         let current = self.stack.pop().expect("cannot close w/o open tokens");
@@ -635,26 +783,39 @@ impl<'a> Tokenizer<'a> {
             done: false,
             gfm_footnote_definitions: self.tokenize_state.gfm_footnote_definitions.split_off(0),
             definitions: self.tokenize_state.definitions.split_off(0),
+            abbreviations: self.tokenize_state.abbreviations.split_off(0),
+            warnings: self.tokenize_state.warnings.split_off(0),
         };
 
         if resolve {
-            let resolvers = self.resolvers.split_off(0);
-            let mut index = 0;
-            let defs = &mut value.definitions;
-            let fn_defs = &mut value.gfm_footnote_definitions;
-            while index < resolvers.len() {
-                if let Some(mut result) = call_resolve(self, resolvers[index])? {
-                    fn_defs.append(&mut result.gfm_footnote_definitions);
-                    defs.append(&mut result.definitions);
-                }
-                index += 1;
-            }
-
-            self.map.consume(&mut self.events);
+            self.resolve(&mut value)?;
         }
 
         Ok(value)
     }
+
+    /// Run the registered resolvers on the events, adding what they find to
+    /// `value`.
+    pub fn resolve(&mut self, value: &mut Subresult) -> Result<(), String> {
+        let resolvers = self.resolvers.split_off(0);
+        let mut index = 0;
+        let defs = &mut value.definitions;
+        let fn_defs = &mut value.gfm_footnote_definitions;
+        let abbrs = &mut value.abbreviations;
+        let warnings = &mut value.warnings;
+        while index < resolvers.len() {
+            if let Some(mut result) = call_resolve(self, resolvers[index])? {
+                fn_defs.append(&mut result.gfm_footnote_definitions);
+                defs.append(&mut result.definitions);
+                abbrs.append(&mut result.abbreviations);
+                warnings.append(&mut result.warnings);
+            }
+            index += 1;
+        }
+
+        self.map.consume(&mut self.events);
+        Ok(())
+    }
 }
 
 /// Move back past ignored bytes.
@@ -701,6 +862,7 @@ fn push_impl(
     );
 
     tokenizer.move_to(from);
+    tokenizer.to = to;
 
     loop {
         match state {
@@ -821,3 +983,126 @@ fn byte_action(bytes: &[u8], point: &Point) -> ByteAction {
         unreachable!("out of bounds")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_state;
+    use crate::state::Name as StateName;
+    use alloc::format;
+
+    #[test]
+    fn point() {
+        let options = ParseOptions::default();
+        let value = "a\tb\nc&amp;";
+        let parse_state = parse_state(value, &options).unwrap();
+        let mut tokenizer = Tokenizer::new(
+            Point {
+                line: 1,
+                column: 1,
+                index: 0,
+                vs: 0,
+            },
+            &parse_state,
+        );
+
+        let state = tokenizer.push((0, 0), (1, 0), State::Next(StateName::StringStart));
+
+        assert_eq!(
+            (
+                tokenizer.point().line,
+                tokenizer.point().column,
+                tokenizer.point().index
+            ),
+            (1, 2, 1),
+            "should be after a byte"
+        );
+
+        let state = tokenizer.push((1, 0), (3, 0), state);
+
+        assert_eq!(
+            (
+                tokenizer.point().line,
+                tokenizer.point().column,
+                tokenizer.point().index
+            ),
+            (1, 6, 3),
+            "should be after a tab, which moves to the next tab stop"
+        );
+
+        let state = tokenizer.push((3, 0), (4, 0), state);
+
+        assert_eq!(
+            (
+                tokenizer.point().line,
+                tokenizer.point().column,
+                tokenizer.point().index
+            ),
+            (2, 1, 4),
+            "should be at the start of the next line after a line ending"
+        );
+
+        let state = tokenizer.push((4, 0), (value.len(), 0), state);
+
+        assert_eq!(
+            (
+                tokenizer.point().line,
+                tokenizer.point().column,
+                tokenizer.point().index
+            ),
+            (2, 7, 10),
+            "should be after a construct (character reference)"
+        );
+
+        tokenizer.flush(state, false).unwrap();
+    }
+
+    #[test]
+    fn reset() {
+        let options = ParseOptions::default();
+        let start = Point {
+            line: 1,
+            column: 1,
+            index: 0,
+            vs: 0,
+        };
+        let values = ["[a]: b\n\n> c *d*\n\n- e", "f\n===\n\n    g"];
+        let states = values.map(|value| parse_state(value, &options).unwrap());
+        let tokenize = |tokenizer: &mut Tokenizer, length: usize| {
+            let state = tokenizer.push((0, 0), (length, 0), State::Next(StateName::DocumentStart));
+            tokenizer.flush(state, true).unwrap();
+            format!("{:?}", tokenizer.events)
+        };
+
+        let mut reused = Tokenizer::new(start.clone(), &states[0]);
+        tokenize(&mut reused, values[0].len());
+        let capacity = reused.events.capacity();
+        reused.reset(start.clone(), &states[1]);
+
+        assert!(
+            reused.events.is_empty() && reused.stack.is_empty(),
+            "should clear events and the stack"
+        );
+        assert_eq!(
+            reused.events.capacity(),
+            capacity,
+            "should keep the allocation of events"
+        );
+        assert_eq!(
+            tokenize(&mut reused, values[1].len()),
+            tokenize(
+                &mut Tokenizer::new(start.clone(), &states[1]),
+                values[1].len()
+            ),
+            "should tokenize like a new tokenizer after a reset"
+        );
+
+        reused.reset(start.clone(), &states[0]);
+
+        assert_eq!(
+            tokenize(&mut reused, values[0].len()),
+            tokenize(&mut Tokenizer::new(start, &states[0]), values[0].len()),
+            "should tokenize like a new tokenizer after another reset"
+        );
+    }
+}
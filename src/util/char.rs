@@ -41,6 +41,12 @@ pub enum Kind {
 /// In most cases, markdown operates on ASCII bytes.
 /// In a few cases, it is unicode aware, so we need to find an actual char.
 pub fn before_index(bytes: &[u8], index: usize) -> Option<char> {
+    // ASCII bytes are never part of multibyte characters, so they can be
+    // turned into a char directly.
+    if index > 0 && bytes[index - 1].is_ascii() {
+        return Some(bytes[index - 1] as char);
+    }
+
     let start = if index < 4 { 0 } else { index - 4 };
     String::from_utf8_lossy(&bytes[start..index]).chars().last()
 }
@@ -50,6 +56,12 @@ pub fn before_index(bytes: &[u8], index: usize) -> Option<char> {
 /// In most cases, markdown operates on ASCII bytes.
 /// In a few cases, it is unicode aware, so we need to find an actual char.
 pub fn after_index(bytes: &[u8], index: usize) -> Option<char> {
+    // ASCII bytes are never part of multibyte characters, so they can be
+    // turned into a char directly.
+    if index < bytes.len() && bytes[index].is_ascii() {
+        return Some(bytes[index] as char);
+    }
+
     let end = if index + 4 > bytes.len() {
         bytes.len()
     } else {
@@ -88,6 +100,39 @@ pub fn kind_after_index(bytes: &[u8], index: usize) -> Kind {
 ///
 /// *   [`micromark-util-classify-character` in `micromark`](https://github.com/micromark/micromark/blob/main/packages/micromark-util-classify-character/dev/index.js)
 pub fn classify(char: char) -> Kind {
+    if char.is_ascii() {
+        classify_ascii(char as u8)
+    } else {
+        classify_unicode(char)
+    }
+}
+
+/// Classify an ASCII byte, without looking through the unicode tables.
+///
+/// This gives the same results as [`classify_unicode`] for ASCII, which is
+/// what most markdown consists of.
+fn classify_ascii(byte: u8) -> Kind {
+    match byte {
+        // Unicode whitespace in ASCII.
+        b'\t'..=b'\r' | b' ' => Kind::Whitespace,
+        // Unicode punctuation (not symbols, such as `$` or `+`) in ASCII.
+        b'!'..=b'#'
+        | b'%'..=b'*'
+        | b','..=b'/'
+        | b':'
+        | b';'
+        | b'?'
+        | b'@'
+        | b'['..=b']'
+        | b'_'
+        | b'{'
+        | b'}' => Kind::Punctuation,
+        _ => Kind::Other,
+    }
+}
+
+/// Classify a `char` with the unicode tables.
+fn classify_unicode(char: char) -> Kind {
     // Unicode whitespace.
     if char.is_whitespace() {
         Kind::Whitespace
@@ -102,6 +147,57 @@ pub fn classify(char: char) -> Kind {
     }
 }
 
+/// Check whether a `char` is a letter written right-to-left.
+///
+/// This looks for letters in the blocks of scripts that are written
+/// right-to-left, such as Arabic and Hebrew, which roughly matches the strong
+/// right-to-left bidirectional classes (`R` and `AL`).
+/// Digits, punctuation, and the combining marks in the main blocks are not
+/// letters: they take the direction of what is around them.
+///
+/// ## References
+///
+/// *   [*§ 3 Bidirectional character types* in `UAX #9`](https://www.unicode.org/reports/tr9/#Bidirectional_Character_Types)
+pub fn is_rtl(char: char) -> bool {
+    char.is_alphabetic()
+        && matches!(
+            char,
+            // Hebrew, Arabic, Syriac, Thaana, NKo, Samaritan, Mandaic, and
+            // their supplements and extensions.
+            '\u{0590}'..='\u{08FF}'
+            // Hebrew and Arabic presentation forms, without the byte order
+            // mark (U+FEFF) at the end of that last block.
+            | '\u{FB1D}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFC}'
+            // Historic scripts, Adlam, and Arabic mathematical symbols.
+            | '\u{10800}'..='\u{10FFF}'
+            | '\u{1E800}'..='\u{1EFFF}'
+        )
+        // Combining marks that are alphabetic, such as Hebrew points and
+        // Arabic vowel signs.
+        && !matches!(
+            char,
+            '\u{0591}'..='\u{05C7}'
+                | '\u{0610}'..='\u{061A}'
+                | '\u{064B}'..='\u{065F}'
+                | '\u{0670}'
+                | '\u{06D6}'..='\u{06E4}'
+                | '\u{06E7}'..='\u{06ED}'
+                | '\u{0711}'
+                | '\u{0730}'..='\u{074A}'
+                | '\u{07A6}'..='\u{07B0}'
+                | '\u{07EB}'..='\u{07F3}'
+                | '\u{0816}'..='\u{0819}'
+                | '\u{081B}'..='\u{0823}'
+                | '\u{0825}'..='\u{0827}'
+                | '\u{0829}'..='\u{082D}'
+                | '\u{0859}'..='\u{085B}'
+                | '\u{0898}'..='\u{089F}'
+                | '\u{08CA}'..='\u{08FF}'
+                | '\u{FB1E}'
+        )
+}
+
 /// Like [`classify`], but supports eof as whitespace.
 pub fn classify_opt(char_opt: Option<char>) -> Kind {
     char_opt.map_or(Kind::Whitespace, classify)
@@ -174,6 +270,81 @@ mod tests {
         );
 
         assert_eq!(classify('a'), Kind::Other, "should classify other");
+
+        assert_eq!(
+            classify('\u{A0}'),
+            Kind::Whitespace,
+            "should classify unicode whitespace"
+        );
+
+        assert_eq!(
+            classify('。'),
+            Kind::Punctuation,
+            "should classify unicode punctuation"
+        );
+
+        assert_eq!(classify('é'), Kind::Other, "should classify unicode other");
+
+        assert_eq!(
+            classify('\u{AD}'),
+            Kind::Other,
+            "should classify format characters (soft hyphen) as other"
+        );
+
+        assert_eq!(
+            classify('\u{200B}'),
+            Kind::Other,
+            "should classify format characters (zero-width space) as other"
+        );
+
+        assert_eq!(
+            classify('\u{200F}'),
+            Kind::Other,
+            "should classify format characters (bidirectional controls) as other"
+        );
+    }
+
+    #[test]
+    fn test_classify_ascii() {
+        for byte in 0..=0x7F_u8 {
+            assert_eq!(
+                classify_ascii(byte),
+                classify_unicode(byte as char),
+                "should classify ASCII the same as unicode ({})",
+                format_byte(byte)
+            );
+        }
+    }
+
+    #[test]
+    fn test_index() {
+        let bytes = "a *é* 😄.".as_bytes();
+
+        assert_eq!(before_index(bytes, 0), None, "should support bof");
+        assert_eq!(before_index(bytes, 1), Some('a'), "should support ASCII");
+        assert_eq!(
+            before_index(bytes, 5),
+            Some('é'),
+            "should support a 2-byte char before"
+        );
+        assert_eq!(
+            before_index(bytes, 11),
+            Some('😄'),
+            "should support a 4-byte char before"
+        );
+
+        assert_eq!(after_index(bytes, 0), Some('a'), "should support ASCII");
+        assert_eq!(
+            after_index(bytes, 3),
+            Some('é'),
+            "should support a 2-byte char after"
+        );
+        assert_eq!(
+            after_index(bytes, 7),
+            Some('😄'),
+            "should support a 4-byte char after"
+        );
+        assert_eq!(after_index(bytes, bytes.len()), None, "should support eof");
     }
 
     #[test]
@@ -207,6 +378,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_rtl() {
+        assert!(is_rtl('ש'), "should support Hebrew");
+        assert!(is_rtl('ب'), "should support Arabic");
+        assert!(is_rtl('ﷺ'), "should support Arabic presentation forms");
+        assert!(!is_rtl('a'), "should not support Latin");
+        assert!(!is_rtl('字'), "should not support CJK");
+        assert!(!is_rtl(' '), "should not support whitespace");
+        assert!(!is_rtl('\u{FEFF}'), "should not support a byte order mark");
+        assert!(!is_rtl('١'), "should not support Arabic-Indic digits");
+        assert!(!is_rtl('؟'), "should not support Arabic punctuation");
+        assert!(!is_rtl('\u{064E}'), "should not support Arabic marks");
+        assert!(!is_rtl('\u{05B8}'), "should not support Hebrew points");
+    }
+
     #[test]
     fn test_format() {
         assert_eq!(
@@ -0,0 +1,324 @@
+//! Diff events.
+
+use crate::event::Event;
+use alloc::{vec, vec::Vec};
+
+/// Change from old events to new events.
+///
+/// Created by [`diff_events`][].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EventChange {
+    /// Event at this index in the old events is removed.
+    Delete(usize),
+    /// Event at this index in the new events is added.
+    Insert(usize),
+}
+
+/// Get a minimal edit script to turn `old` events into `new` events.
+///
+/// Events are the same if they have the same kind and name, and are at the
+/// same distance from the event before them.
+/// Where they are in the document does not matter, so that adding something
+/// early in a document does not change all events after it.
+///
+/// Changes are in order, and events that are not changed are not included,
+/// so applying them means going through both lists of events at once:
+/// skipping deleted old events, taking inserted new events, and keeping
+/// other old events.
+///
+/// > 👉 **Note**: changes in content that do not change its size, such as
+/// > `a` to `b`, are not found.
+/// > Compare the source between events if you need that.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{diff_events, EventChange, Markdown, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let old = Markdown::new("a *b* c", &ParseOptions::default())?;
+/// let new = Markdown::new("a *bc* c", &ParseOptions::default())?;
+///
+/// // Only the exit of the data in the emphasis is different.
+/// assert_eq!(
+///     diff_events(old.events(), new.events()),
+///     vec![EventChange::Delete(8), EventChange::Insert(8)]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn diff_events(old: &[Event], new: &[Event]) -> Vec<EventChange> {
+    let mut script = Script::default();
+    myers(old, new, (0, old.len()), (0, new.len()), &mut script);
+    script.keep();
+    script.changes
+}
+
+/// Edit script, being built.
+#[derive(Debug, Default)]
+struct Script {
+    /// Changes, in order.
+    changes: Vec<EventChange>,
+    /// Deletes since the last kept event.
+    deletes: Vec<usize>,
+    /// Inserts since the last kept event.
+    inserts: Vec<usize>,
+}
+
+impl Script {
+    /// Delete an old event.
+    fn delete(&mut self, index: usize) {
+        self.deletes.push(index);
+    }
+
+    /// Insert a new event.
+    fn insert(&mut self, index: usize) {
+        self.inserts.push(index);
+    }
+
+    /// Keep events, which ends a run of changes.
+    ///
+    /// Halves of the script are found separately, so to have the same order
+    /// everywhere, deletes in a run go before inserts.
+    fn keep(&mut self) {
+        self.changes
+            .extend(self.deletes.drain(..).map(EventChange::Delete));
+        self.changes
+            .extend(self.inserts.drain(..).map(EventChange::Insert));
+    }
+}
+
+/// Find the shortest edit script between the `old` and `new` events in
+/// `old_range` and `new_range`, with Myers’ algorithm.
+///
+/// To use memory linear to the number of events, instead of keeping what is
+/// reached after each edit to walk back, this searches from both ends for
+/// the middle of the script, and then recurses into both halves.
+///
+/// See [*An O(ND) Difference Algorithm and Its Variations*](http://www.xmailserver.org/diff2.pdf)
+/// (§ 4b) and [`diff_bisect` in `diff-match-patch`](https://github.com/google/diff-match-patch).
+fn myers(
+    old: &[Event],
+    new: &[Event],
+    old_range: (usize, usize),
+    new_range: (usize, usize),
+    script: &mut Script,
+) {
+    let (mut old_start, mut old_end) = old_range;
+    let (mut new_start, mut new_end) = new_range;
+    // Skip what is the same at the start and end, which is most things when
+    // editing.
+    let mut kept = false;
+
+    while old_start < old_end && new_start < new_end && same(old, old_start, new, new_start) {
+        old_start += 1;
+        new_start += 1;
+        kept = true;
+    }
+
+    if kept {
+        script.keep();
+    }
+
+    let mut kept_end = false;
+
+    while old_end > old_start && new_end > new_start && same(old, old_end - 1, new, new_end - 1) {
+        old_end -= 1;
+        new_end -= 1;
+        kept_end = true;
+    }
+
+    let split = if old_start == old_end || new_start == new_end {
+        None
+    } else {
+        middle(old, new, (old_start, old_end), (new_start, new_end))
+    };
+
+    if let Some((old_middle, new_middle)) = split {
+        myers(
+            old,
+            new,
+            (old_start, old_middle),
+            (new_start, new_middle),
+            script,
+        );
+        myers(
+            old,
+            new,
+            (old_middle, old_end),
+            (new_middle, new_end),
+            script,
+        );
+    } else {
+        (old_start..old_end).for_each(|index| script.delete(index));
+        (new_start..new_end).for_each(|index| script.insert(index));
+    }
+
+    if kept_end {
+        script.keep();
+    }
+}
+
+/// Find where the paths from the start and from the end of the events in
+/// `old_range` and `new_range` meet.
+///
+/// Diagonals `k = x - y` can be negative, so this works with `isize`, and
+/// keeps the furthest `x` reached on each diagonal offset by the maximum
+/// number of edits, so that they can be used as indices.
+/// Returns `None` if there is no useful split.
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn middle(
+    old: &[Event],
+    new: &[Event],
+    old_range: (usize, usize),
+    new_range: (usize, usize),
+) -> Option<(usize, usize)> {
+    let (old_start, old_end) = old_range;
+    let (new_start, new_end) = new_range;
+    let old_size = (old_end - old_start) as isize;
+    let new_size = (new_end - new_start) as isize;
+    let max = (old_size + new_size + 1) / 2;
+    let size = (2 * max + 2) as usize;
+    let delta = old_size - new_size;
+    // If the difference in sizes is odd, the forward path reaches the
+    // middle first.
+    let odd = delta % 2 != 0;
+    // Furthest `x` reached on each diagonal, from the start and from the end.
+    let mut forward = vec![-1; size];
+    let mut backward = vec![-1; size];
+    // Diagonals to skip at the start and end, as they went out of bounds.
+    let mut forward_start = 0;
+    let mut forward_end = 0;
+    let mut backward_start = 0;
+    let mut backward_end = 0;
+
+    forward[(max + 1) as usize] = 0;
+    backward[(max + 1) as usize] = 0;
+
+    for edits in 0..max {
+        let mut k = -edits + forward_start;
+
+        while k <= edits - forward_end {
+            let index = (max + k) as usize;
+            let mut x = if k == -edits || (k != edits && forward[index - 1] < forward[index + 1]) {
+                // Down: insert.
+                forward[index + 1]
+            } else {
+                // Right: delete.
+                forward[index - 1] + 1
+            };
+            let mut y = x - k;
+
+            while x < old_size
+                && y < new_size
+                && same(old, old_start + x as usize, new, new_start + y as usize)
+            {
+                x += 1;
+                y += 1;
+            }
+
+            forward[index] = x;
+
+            if x > old_size {
+                forward_end += 2;
+            } else if y > new_size {
+                forward_start += 2;
+            } else if odd {
+                let other = max + delta - k;
+
+                if other >= 0
+                    && other < size as isize
+                    && backward[other as usize] != -1
+                    && x >= old_size - backward[other as usize]
+                {
+                    return split(old_range, new_range, x, y);
+                }
+            }
+
+            k += 2;
+        }
+
+        let mut k = -edits + backward_start;
+
+        while k <= edits - backward_end {
+            let index = (max + k) as usize;
+            let mut x = if k == -edits || (k != edits && backward[index - 1] < backward[index + 1])
+            {
+                backward[index + 1]
+            } else {
+                backward[index - 1] + 1
+            };
+            let mut y = x - k;
+
+            while x < old_size
+                && y < new_size
+                && same(old, old_end - 1 - x as usize, new, new_end - 1 - y as usize)
+            {
+                x += 1;
+                y += 1;
+            }
+
+            backward[index] = x;
+
+            if x > old_size {
+                backward_end += 2;
+            } else if y > new_size {
+                backward_start += 2;
+            } else if !odd {
+                let other = max + delta - k;
+
+                if other >= 0 && other < size as isize && forward[other as usize] != -1 {
+                    let forward_x = forward[other as usize];
+                    let forward_y = forward_x - (other - max);
+
+                    if forward_x >= old_size - x {
+                        return split(old_range, new_range, forward_x, forward_y);
+                    }
+                }
+            }
+
+            k += 2;
+        }
+    }
+
+    None
+}
+
+/// Turn a point where paths meet into indices, if it splits the events.
+#[allow(clippy::cast_sign_loss)]
+fn split(
+    old_range: (usize, usize),
+    new_range: (usize, usize),
+    x: isize,
+    y: isize,
+) -> Option<(usize, usize)> {
+    let old_middle = old_range.0 + x as usize;
+    let new_middle = new_range.0 + y as usize;
+
+    if (old_middle, new_middle) == (old_range.0, new_range.0)
+        || (old_middle, new_middle) == (old_range.1, new_range.1)
+    {
+        None
+    } else {
+        Some((old_middle, new_middle))
+    }
+}
+
+/// Check whether the events at `old_index` and `new_index` are the same.
+fn same(old: &[Event], old_index: usize, new: &[Event], new_index: usize) -> bool {
+    let a = &old[old_index];
+    let b = &new[new_index];
+    a.kind == b.kind
+        && a.name == b.name
+        && a.point.vs == b.point.vs
+        && distance(old, old_index) == distance(new, new_index)
+}
+
+/// Get the distance of the event at `index` from the event before it.
+fn distance(events: &[Event], index: usize) -> usize {
+    if index == 0 {
+        events[index].point.index
+    } else {
+        events[index].point.index - events[index - 1].point.index
+    }
+}
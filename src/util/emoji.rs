@@ -0,0 +1,29 @@
+//! Helpers for emoji.
+
+use crate::util::constant::EMOJI_SHORTCODES;
+
+/// Decode an emoji shortcode.
+///
+/// Turn the name coming from an emoji (such as `smile` in `:smile:`) into
+/// the emoji it represents.
+/// Returns `None` if the shortcode is not known.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::emoji::decode;
+///
+/// assert_eq!(decode("smile"), Some("😄"));
+/// assert_eq!(decode("+1"), Some("👍"));
+/// assert_eq!(decode("nope"), None);
+/// ```
+///
+/// ## References
+///
+/// *   [`github/gemoji`](https://github.com/github/gemoji)
+pub fn decode(shortcode: &str) -> Option<&'static str> {
+    EMOJI_SHORTCODES
+        .binary_search_by(|d| d.0.cmp(shortcode))
+        .ok()
+        .map(|index| EMOJI_SHORTCODES[index].1)
+}
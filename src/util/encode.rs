@@ -53,3 +53,31 @@ pub fn encode(value: &str, encode_html: bool) -> String {
 
     result
 }
+
+/// Check whether `name` is a valid HTML attribute name.
+///
+/// Names must not be empty, and must not contain controls, spaces, `"`,
+/// `'`, `/`, `=`, `>`, or noncharacters.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::encode::is_attribute_name;
+///
+/// assert!(is_attribute_name("data-a"));
+/// assert!(!is_attribute_name("a onclick"));
+/// ```
+///
+/// ## References
+///
+/// *   [*§ 13.1.2.3 Attributes* in `HTML`](https://html.spec.whatwg.org/multipage/syntax.html#attributes-2)
+pub fn is_attribute_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.chars().all(|char| {
+            let code = char as u32;
+            !char.is_control()
+                && !matches!(char, ' ' | '"' | '\'' | '/' | '=' | '>')
+                && !(0xFDD0..=0xFDEF).contains(&code)
+                && code & 0xFFFE != 0xFFFE
+        })
+}
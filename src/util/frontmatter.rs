@@ -0,0 +1,111 @@
+//! Get frontmatter from events.
+
+use crate::event::{Event, Kind as EventKind, Name};
+use core::str;
+
+/// Kind of frontmatter.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::FrontmatterKind;
+/// # fn main() {
+///
+/// // Frontmatter fenced with `---`:
+/// let yaml = FrontmatterKind::Yaml;
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// YAML, fenced with dashes (`-`).
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// ---
+    /// title: Neptune
+    /// ---
+    /// ```
+    Yaml,
+    /// TOML, fenced with pluses (`+`).
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// +++
+    /// title = "Neptune"
+    /// +++
+    /// ```
+    Toml,
+}
+
+/// Frontmatter found in a document.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Frontmatter<'a> {
+    /// Kind of frontmatter, based on the fence marker.
+    pub kind: Kind,
+    /// Content between the fences.
+    ///
+    /// Does not include the fences, or the line endings right after the
+    /// opening fence and right before the closing fence.
+    /// Empty lines at the start and end are trimmed too, so `---\n\na\n\n---`
+    /// has the value `a`; lines with only whitespace, and empty lines between
+    /// other lines, are kept.
+    pub value: &'a str,
+}
+
+/// Get the frontmatter from events, if there is any.
+///
+/// Frontmatter can only occur at the start of a document, so this stops at
+/// the first event that isn’t part of frontmatter.
+pub fn frontmatter<'a>(events: &[Event], bytes: &'a [u8]) -> Option<Frontmatter<'a>> {
+    let mut index = 0;
+    let mut start = None;
+    let mut end = None;
+    let mut kind = None;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        match event.name {
+            Name::ByteOrderMark => {}
+            Name::Frontmatter => {
+                if event.kind == EventKind::Exit {
+                    break;
+                }
+
+                kind = Some(if bytes[event.point.index] == b'+' {
+                    Kind::Toml
+                } else {
+                    Kind::Yaml
+                });
+            }
+            Name::FrontmatterChunk => {
+                if event.kind == EventKind::Enter {
+                    if start.is_none() {
+                        start = Some(event.point.index);
+                    }
+                } else {
+                    end = Some(event.point.index);
+                }
+            }
+            _ => {
+                if kind.is_none() {
+                    break;
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    kind.map(|kind| {
+        let value = if let (Some(start), Some(end)) = (start, end) {
+            str::from_utf8(&bytes[start..end]).unwrap()
+        } else {
+            ""
+        };
+
+        Frontmatter { kind, value }
+    })
+}
@@ -4,8 +4,114 @@
 
 use crate::event::{Event, Kind, Name};
 use crate::mdast::AlignKind;
+use crate::util::skip;
 use alloc::{vec, vec::Vec};
 
+/// Kind of list item marker.
+///
+/// Two list items can only be in the same list if they use the same marker.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ListItemMarker {
+    /// Asterisk (`*`), in an unordered list.
+    ///
+    /// ```markdown
+    /// > | * a
+    ///     ^
+    /// ```
+    Asterisk,
+    /// Dash (`-`), in an unordered list.
+    ///
+    /// ```markdown
+    /// > | - a
+    ///     ^
+    /// ```
+    Dash,
+    /// Plus sign (`+`), in an unordered list.
+    ///
+    /// ```markdown
+    /// > | + a
+    ///     ^
+    /// ```
+    Plus,
+    /// Dot (`.`) after a value, in an ordered list.
+    ///
+    /// ```markdown
+    /// > | 1. a
+    ///      ^
+    /// ```
+    Dot,
+    /// Closing paren (`)`) after a value, in an ordered list.
+    ///
+    /// ```markdown
+    /// > | 1) a
+    ///      ^
+    /// ```
+    Paren,
+}
+
+impl ListItemMarker {
+    /// Turn a byte into a kind.
+    ///
+    /// Returns `None` if `byte` is not `*`, `-`, `+`, `.`, or `)`.
+    pub fn from_byte(byte: u8) -> Option<ListItemMarker> {
+        match byte {
+            b'*' => Some(ListItemMarker::Asterisk),
+            b'-' => Some(ListItemMarker::Dash),
+            b'+' => Some(ListItemMarker::Plus),
+            b'.' => Some(ListItemMarker::Dot),
+            b')' => Some(ListItemMarker::Paren),
+            _ => None,
+        }
+    }
+
+    /// Whether this marker is used in ordered lists.
+    pub fn ordered(self) -> bool {
+        matches!(self, ListItemMarker::Dot | ListItemMarker::Paren)
+    }
+}
+
+/// Figure out the marker of a list item.
+///
+/// `index` is the index of an [`Enter`][Kind::Enter]:[`ListItem`][Name::ListItem]
+/// event in `events`, and `bytes` are the bytes of the document the events
+/// are for.
+/// Returns `None` if there is no such event at `index`, or if its marker is
+/// not in `bytes`.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{list_item_marker, EventKind, EventName, ListItemMarker, Markdown, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let markdown = Markdown::new("1. a\n2) b", &ParseOptions::default())?;
+/// let events = markdown.events();
+/// let markers = events
+///     .iter()
+///     .enumerate()
+///     .filter(|(_, d)| d.kind == EventKind::Enter && d.name == EventName::ListItem)
+///     .map(|(index, _)| list_item_marker(events, markdown.value().as_bytes(), index))
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     markers,
+///     vec![Some(ListItemMarker::Dot), Some(ListItemMarker::Paren)]
+/// );
+/// assert_eq!(list_item_marker(events, markdown.value().as_bytes(), 0), None);
+/// # Ok(())
+/// # }
+/// ```
+pub fn list_item_marker(events: &[Event], bytes: &[u8], index: usize) -> Option<ListItemMarker> {
+    let event = events.get(index)?;
+
+    if event.kind != Kind::Enter || event.name != Name::ListItem {
+        return None;
+    }
+
+    let marker = skip::to(events, index, &[Name::ListItemMarker]);
+    ListItemMarker::from_byte(*bytes.get(events.get(marker)?.point.index)?)
+}
+
 /// Figure out if a list is spread or not.
 ///
 /// When `include_items: true` is passed, infers whether the list as a whole
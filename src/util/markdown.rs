@@ -0,0 +1,579 @@
+//! Parsed markdown, with helpers to find things in it.
+
+use crate::event::{Event, Kind, Name};
+use crate::parser::parse;
+use crate::util::normalize_identifier::normalize_identifier;
+use crate::util::plain::to_plain;
+use crate::ParseOptions;
+use alloc::{collections::BTreeMap, format, string::String, vec, vec::Vec};
+use core::{slice, str};
+
+/// Parsed markdown: a document and its events.
+///
+/// This is a small layer over the events that are otherwise compiled to HTML
+/// or mdast, for when you want to find certain things, such as to check links.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{EventName, Markdown, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let markdown = Markdown::new("[a](b) and [c](d)", &ParseOptions::default())?;
+///
+/// let destinations = markdown
+///     .links()
+///     .filter_map(|link| link.find(&EventName::ResourceDestinationString))
+///     .map(|destination| destination.as_str())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(destinations, vec!["b", "d"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Markdown<'a> {
+    /// Document.
+    value: &'a str,
+    /// Events.
+    events: Vec<Event>,
+}
+
+impl<'a> Markdown<'a> {
+    /// Parse markdown.
+    ///
+    /// ## Errors
+    ///
+    /// This errors in the same cases as [`to_mdast()`][crate::to_mdast()],
+    /// so only with MDX.
+    pub fn new(value: &'a str, options: &ParseOptions) -> Result<Markdown<'a>, String> {
+        let (events, _) = parse(value, options)?;
+        Ok(Markdown { value, events })
+    }
+
+    /// Get the document.
+    pub fn value(&self) -> &'a str {
+        self.value
+    }
+
+    /// Get all events.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Iterate over all events.
+    pub fn iter(&self) -> slice::Iter<'_, Event> {
+        self.events.iter()
+    }
+
+    /// Get all spans with one of `names`.
+    pub fn spans<'b>(&'b self, names: &'b [Name]) -> Spans<'a, 'b> {
+        Spans {
+            value: self.value,
+            events: &self.events,
+            names,
+            index: 0,
+        }
+    }
+
+    /// Get all headings (atx and setext).
+    pub fn headings(&self) -> Spans<'a, '_> {
+        self.spans(&[Name::HeadingAtx, Name::HeadingSetext])
+    }
+
+    /// Get all links.
+    ///
+    /// > 👉 **Note**: this includes links with resources (`[a](b)`) and
+    /// > references (`[a][b]`), but not autolinks.
+    pub fn links(&self) -> Spans<'a, '_> {
+        self.spans(&[Name::Link])
+    }
+
+    /// Get all code blocks (fenced and indented).
+    pub fn code_blocks(&self) -> Spans<'a, '_> {
+        self.spans(&[Name::CodeFenced, Name::CodeIndented])
+    }
+
+    /// Get the definitions that references match.
+    ///
+    /// Each reference (full, collapsed, or shortcut; link or image) is paired
+    /// with the index of its enter event and the index of the enter event of
+    /// the [`Definition`][Name::Definition] it matches.
+    /// Labels are matched as in HTML: normalized, and the first definition
+    /// wins when there are several with the same label.
+    /// This is useful for things such as “go to definition” in editors.
+    ///
+    /// > 👉 **Note**: references that do not match a definition, such as
+    /// > `[a][b]` without `[b]: c`, are not references in markdown: they are
+    /// > text, so they are not included.
+    pub fn reference_definitions(&self) -> Vec<(usize, usize)> {
+        let mut definitions: BTreeMap<String, usize> = BTreeMap::new();
+        let mut references = vec![];
+        let mut index = 0;
+
+        while index < self.events.len() {
+            let event = &self.events[index];
+
+            if event.kind == Kind::Enter {
+                if event.name == Name::Definition {
+                    if let Some(id) = child_id(
+                        self.value,
+                        &self.events,
+                        index,
+                        &Name::DefinitionLabel,
+                        &Name::DefinitionLabelString,
+                    ) {
+                        definitions.entry(id).or_insert(index);
+                    }
+                } else if matches!(event.name, Name::Link | Name::Image)
+                    && child_index(&self.events, index, &Name::Resource).is_none()
+                {
+                    let id = child_id(
+                        self.value,
+                        &self.events,
+                        index,
+                        &Name::Reference,
+                        &Name::ReferenceString,
+                    )
+                    .or_else(|| {
+                        child_id(
+                            self.value,
+                            &self.events,
+                            index,
+                            &Name::Label,
+                            &Name::LabelText,
+                        )
+                    });
+
+                    references.push((index, id));
+                }
+            }
+
+            index += 1;
+        }
+
+        references
+            .into_iter()
+            .filter_map(|(index, id)| Some((index, *definitions.get(&id?)?)))
+            .collect()
+    }
+}
+
+impl IntoIterator for Markdown<'_> {
+    type Item = Event;
+    type IntoIter = vec::IntoIter<Event>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.into_iter()
+    }
+}
+
+impl<'b> IntoIterator for &'b Markdown<'_> {
+    type Item = &'b Event;
+    type IntoIter = slice::Iter<'b, Event>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.iter()
+    }
+}
+
+/// Index of events by name.
+///
+/// Built once from events, to get all enter events of a certain name without
+/// going through all events each time.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{EventIndex, EventName, Markdown, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let markdown = Markdown::new("# a\n\n## b", &ParseOptions::default())?;
+/// let index = EventIndex::new(markdown.events());
+///
+/// assert_eq!(index.get(&EventName::HeadingAtx), &[0, 14]);
+/// assert_eq!(index.get(&EventName::Link), &[]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct EventIndex {
+    /// Indices of enter events, by name (its discriminant).
+    enters: Vec<Vec<usize>>,
+}
+
+impl EventIndex {
+    /// Index `events`.
+    pub fn new(events: &[Event]) -> EventIndex {
+        let mut enters: Vec<Vec<usize>> = vec![];
+        let mut index = 0;
+
+        while index < events.len() {
+            let event = &events[index];
+
+            if event.kind == Kind::Enter {
+                let name = event.name.clone() as usize;
+
+                if name >= enters.len() {
+                    enters.resize(name + 1, vec![]);
+                }
+
+                enters[name].push(index);
+            }
+
+            index += 1;
+        }
+
+        EventIndex { enters }
+    }
+
+    /// Get the indices of all enter events with `name`, in order.
+    pub fn get(&self, name: &Name) -> &[usize] {
+        self.enters
+            .get(name.clone() as usize)
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Check whether a document is a single paragraph.
+///
+/// That is, whether the flow of the document, ignoring blank lines, is one
+/// [`Paragraph`][Name::Paragraph], without block quotes, lists, headings,
+/// definitions, or other block structure around or next to it.
+/// This can be used to render such documents inline, for example without
+/// `<p>`.
+/// An empty document is not inline only.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{is_inline_only, Markdown, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let inline = Markdown::new("a *b*\n", &ParseOptions::default())?;
+/// let block = Markdown::new("# a\n\nb", &ParseOptions::default())?;
+///
+/// assert!(is_inline_only(inline.events()));
+/// assert!(!is_inline_only(block.events()));
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_inline_only(events: &[Event]) -> bool {
+    let mut paragraph = false;
+    let mut depth = 0;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            if depth == 0 {
+                match event.name {
+                    Name::BlankLineEnding | Name::LineEnding | Name::SpaceOrTab => {}
+                    Name::Paragraph if !paragraph => paragraph = true,
+                    _ => return false,
+                }
+            }
+
+            depth += 1;
+        } else {
+            depth -= 1;
+        }
+
+        index += 1;
+    }
+
+    paragraph
+}
+
+/// Iterator over spans with certain names.
+///
+/// Created by [`Markdown::spans`][] and friends.
+#[derive(Debug)]
+pub struct Spans<'a, 'b> {
+    /// Document.
+    value: &'a str,
+    /// Events to search in.
+    events: &'b [Event],
+    /// Names to look for.
+    names: &'b [Name],
+    /// Current index into `events`.
+    index: usize,
+}
+
+impl<'a, 'b> Iterator for Spans<'a, 'b> {
+    type Item = Span<'a, 'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.events.len() {
+            let enter = self.index;
+            self.index += 1;
+
+            if self.events[enter].kind == Kind::Enter
+                && self.names.contains(&self.events[enter].name)
+            {
+                let exit = exit_index(self.events, enter);
+                // Continue after this span, so nested spans are not yielded.
+                self.index = exit + 1;
+                return Some(Span {
+                    value: self.value,
+                    events: &self.events[enter..=exit],
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Something found in a document: its events, from enter to exit.
+#[derive(Clone, Debug)]
+pub struct Span<'a, 'b> {
+    /// Document.
+    value: &'a str,
+    /// Events, from enter to exit.
+    events: &'b [Event],
+}
+
+impl<'a, 'b> Span<'a, 'b> {
+    /// Get the name.
+    pub fn name(&self) -> &'b Name {
+        &self.events[0].name
+    }
+
+    /// Get the events, from enter to exit.
+    pub fn events(&self) -> &'b [Event] {
+        self.events
+    }
+
+    /// Get the source.
+    ///
+    /// > 👉 **Note**: this includes everything between the start and end,
+    /// > such as the markers of block quotes for code in a block quote.
+    pub fn as_str(&self) -> &'a str {
+        let start = self.events[0].point.index;
+        let end = self.events[self.events.len() - 1].point.index;
+        &self.value[start..end]
+    }
+
+    /// Get the plain text.
+    ///
+    /// Markup is dropped, and character escapes, character references, and
+    /// emoji are decoded, so `**a** [b](c)` turns into `a b`.
+    /// This is useful for things such as search indices and slugs of
+    /// headings.
+    pub fn to_plain(&self) -> String {
+        to_plain(self.events, self.value.as_bytes(), 0)
+    }
+
+    /// Get the destination of a definition or resource.
+    ///
+    /// The destination is the same whether it is enclosed in angle brackets
+    /// (`<b c>`) or not (`b`): the brackets are dropped, and character
+    /// escapes and character references are decoded.
+    /// It is empty when there is no destination, such as in `[a]()`.
+    ///
+    /// Returns `None` if this span is not a definition or resource.
+    pub fn destination(&self) -> Option<String> {
+        let name = match self.name() {
+            Name::Definition => Name::DefinitionDestinationString,
+            Name::Resource => Name::ResourceDestinationString,
+            _ => return None,
+        };
+
+        Some(
+            self.find(&name)
+                .map_or_else(String::new, |string| string.to_plain()),
+        )
+    }
+
+    /// Get the size of the whitespace between the marker and the content of
+    /// a list item, in columns.
+    ///
+    /// This is `1` for `- a` and `3` for `-   a`.
+    /// When there are more than `4` columns of whitespace, such as in
+    /// `-     a`, only the first is part of the marker, and the rest is part
+    /// of the content (code (indented)), so this is `1`.
+    /// It is `0` when the first line is blank.
+    /// The content column of a list item is the width of its marker plus this.
+    ///
+    /// Returns `None` if this span is not a list item.
+    pub fn list_item_spacing(&self) -> Option<usize> {
+        if *self.name() != Name::ListItem {
+            return None;
+        }
+
+        let prefix = self.find(&Name::ListItemPrefix)?;
+        Some(prefix.find(&Name::SpaceOrTab).map_or(0, |space_or_tab| {
+            let events = space_or_tab.events();
+            events[events.len() - 1].point.column - events[0].point.column
+        }))
+    }
+
+    /// Get the rank of a heading.
+    ///
+    /// This is the number of markers for headings (atx), so `1` for `# a`
+    /// and `6` for `###### a`.
+    /// For headings (setext), it comes from the kind of underline: `1` for
+    /// `=` and `2` for `-`.
+    ///
+    /// Returns `None` if this span is not a heading.
+    pub fn heading_rank(&self) -> Option<usize> {
+        match self.name() {
+            Name::HeadingAtx => {
+                let sequence = self.find(&Name::HeadingAtxSequence)?;
+                let events = sequence.events();
+                Some(events[events.len() - 1].point.index - events[0].point.index)
+            }
+            Name::HeadingSetext => {
+                let underline = self.find(&Name::HeadingSetextUnderlineSequence)?;
+                Some(if underline.as_str().starts_with('-') {
+                    2
+                } else {
+                    1
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Find the first span with `name` in this span.
+    pub fn find(&self, name: &Name) -> Option<Span<'a, 'b>> {
+        let mut index = 1;
+
+        while index < self.events.len() {
+            let event = &self.events[index];
+
+            if event.kind == Kind::Enter && event.name == *name {
+                let exit = exit_index(self.events, index);
+                return Some(Span {
+                    value: self.value,
+                    events: &self.events[index..=exit],
+                });
+            }
+
+            index += 1;
+        }
+
+        None
+    }
+}
+
+/// Get the normalized identifier in the `name` grandchild of the `parent`
+/// child of the span entered at `index`.
+fn child_id(
+    value: &str,
+    events: &[Event],
+    index: usize,
+    parent: &Name,
+    name: &Name,
+) -> Option<String> {
+    let parent_index = child_index(events, index, parent)?;
+    let enter = child_index(events, parent_index, name)?;
+    let exit = exit_index(events, enter);
+    Some(normalize_identifier(
+        &value[events[enter].point.index..events[exit].point.index],
+    ))
+}
+
+/// Get the index of the enter event of the first `name` child of the span
+/// entered at `index`.
+///
+/// Only direct children are checked, so that the label of an image in a
+/// link is not mistaken for the label of the link.
+fn child_index(events: &[Event], index: usize, name: &Name) -> Option<usize> {
+    let mut depth = 0;
+    let mut index = index + 1;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            if depth == 0 && event.name == *name {
+                return Some(index);
+            }
+
+            depth += 1;
+        } else if depth == 0 {
+            break;
+        } else {
+            depth -= 1;
+        }
+
+        index += 1;
+    }
+
+    None
+}
+
+/// Get the index of the exit event that matches the enter event at `index`.
+pub fn exit_index(events: &[Event], mut index: usize) -> usize {
+    let name = &events[index].name;
+    let mut balance = 0;
+
+    loop {
+        let event = &events[index];
+
+        if event.name == *name {
+            if event.kind == Kind::Enter {
+                balance += 1;
+            } else {
+                balance -= 1;
+
+                if balance == 0 {
+                    return index;
+                }
+            }
+        }
+
+        index += 1;
+    }
+}
+
+/// Check that events can be compiled: that they are balanced, where each
+/// enter has a matching exit, and that they point into a document of `len`
+/// bytes.
+///
+/// This does not check that the events form valid markdown.
+pub fn check_events(events: &[Event], len: usize) -> Result<(), String> {
+    let mut stack = vec![];
+
+    for event in events {
+        let point = &event.point;
+
+        if point.index > len {
+            return Err(format!(
+                "{}:{}: Unexpected event at index `{}`, expected at most `{}` (events-out-of-bounds)",
+                point.line, point.column, point.index, len
+            ));
+        }
+
+        if event.kind == Kind::Enter {
+            stack.push(event);
+        } else {
+            let enter = stack.pop();
+
+            if enter.map(|d| &d.name) != Some(&event.name) {
+                return Err(format!(
+                    "{}:{}: Unexpected exit of `{:?}`, expected {} (unbalanced-events)",
+                    point.line,
+                    point.column,
+                    event.name,
+                    enter.map_or_else(
+                        || "no exit".into(),
+                        |d| format!("an exit of `{:?}`", d.name)
+                    )
+                ));
+            }
+        }
+    }
+
+    if let Some(enter) = stack.pop() {
+        return Err(format!(
+            "{}:{}: Expected an exit of `{:?}` (unbalanced-events)",
+            enter.point.line, enter.point.column, enter.name
+        ));
+    }
+
+    Ok(())
+}
@@ -3,17 +3,26 @@
 pub mod char;
 pub mod character_reference;
 pub mod constant;
+pub mod diff;
 pub mod edit_map;
+pub mod emoji;
 pub mod encode;
+pub mod frontmatter;
 pub mod gfm_tagfilter;
 pub mod identifier;
 pub mod infer;
 pub mod line_ending;
 pub mod location;
+pub mod markdown;
 pub mod mdx;
 pub mod mdx_collect;
 pub mod normalize_identifier;
+pub mod plain;
+pub mod punycode;
 pub mod sanitize_uri;
 pub mod skip;
 pub mod slice;
+pub mod strip_style_attributes;
+pub mod text_construct;
 pub mod unicode;
+pub mod warning;
@@ -0,0 +1,145 @@
+//! Turn spans of events into plain text.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::{
+    character_reference::decode as decode_character_reference,
+    emoji::decode as decode_emoji,
+    markdown::exit_index,
+    slice::{Position, Slice},
+};
+use alloc::string::String;
+
+/// Names of spans that are not text, and are skipped with everything in them.
+const SKIP: [Name; 20] = [
+    Name::CodeFencedFence,
+    Name::Definition,
+    Name::Frontmatter,
+    Name::GfmFootnoteCall,
+    Name::GfmFootnoteDefinitionPrefix,
+    Name::GfmTaskListItemCheck,
+    Name::HtmlFlow,
+    Name::HtmlText,
+    Name::MathFlowFence,
+    Name::MdxEsm,
+    Name::MdxFlowExpression,
+    Name::MdxJsxFlowTag,
+    Name::MdxJsxTextTag,
+    Name::MdxTextExpression,
+    Name::Reference,
+    Name::Resource,
+    // Markers, which are not in spans that are skipped otherwise.
+    Name::CharacterEscapeMarker,
+    Name::CharacterReferenceMarkerSemi,
+    Name::EmojiMarker,
+    Name::BlankLineEnding,
+];
+
+/// Get the plain text of the span entered at `enter_index`.
+///
+/// Text is kept, markup is dropped, and character escapes, character
+/// references, and emoji are decoded.
+/// For example, the plain text of `**a** `b` [c](d)` is `a b c`.
+///
+/// Line endings between text in the span are kept.
+/// The alt of images is kept, the destination and title of links and images,
+/// the label of references, definitions, and HTML are dropped.
+///
+/// This is useful for things such as search indices and slugs of headings.
+pub fn to_plain(events: &[Event], bytes: &[u8], enter_index: usize) -> String {
+    debug_assert_eq!(events[enter_index].kind, Kind::Enter, "expected enter");
+    let exit = exit_index(events, enter_index);
+    let mut result = String::new();
+    let mut character_reference_marker = b'&';
+    // Exit of a line ending that is only kept if more text follows.
+    let mut line_ending: Option<usize> = None;
+    let mut index = enter_index;
+
+    while index < exit {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            if SKIP.contains(&event.name) {
+                index = exit_index(events, index);
+            } else {
+                match event.name {
+                    Name::AutolinkEmail
+                    | Name::AutolinkProtocol
+                    | Name::CharacterEscapeValue
+                    | Name::CodeFlowChunk
+                    | Name::CodeTextData
+                    | Name::Data
+                    | Name::GfmAutolinkLiteralEmail
+                    | Name::GfmAutolinkLiteralMailto
+                    | Name::GfmAutolinkLiteralProtocol
+                    | Name::GfmAutolinkLiteralWww
+                    | Name::GfmAutolinkLiteralXmpp
+                    | Name::MathFlowChunk
+                    | Name::MathTextData => {
+                        index = exit_index(events, index);
+                        push_line_ending(events, bytes, &mut result, &mut line_ending);
+                        result.push_str(
+                            Slice::from_position(bytes, &Position::from_exit_event(events, index))
+                                .as_str(),
+                        );
+                    }
+                    Name::LineEnding => {
+                        index = exit_index(events, index);
+
+                        if !result.is_empty() {
+                            line_ending = Some(index);
+                        }
+                    }
+                    Name::CharacterReferenceMarkerHexadecimal => {
+                        character_reference_marker = b'x';
+                    }
+                    Name::CharacterReferenceMarkerNumeric => {
+                        character_reference_marker = b'#';
+                    }
+                    Name::CharacterReferenceValue => {
+                        index = exit_index(events, index);
+                        let slice =
+                            Slice::from_position(bytes, &Position::from_exit_event(events, index));
+                        let value = decode_character_reference(
+                            slice.as_str(),
+                            character_reference_marker,
+                            true,
+                        )
+                        .expect("expected to parse only valid named references");
+                        push_line_ending(events, bytes, &mut result, &mut line_ending);
+                        result.push_str(&value);
+                        character_reference_marker = b'&';
+                    }
+                    Name::EmojiShortcode => {
+                        index = exit_index(events, index);
+                        let slice =
+                            Slice::from_position(bytes, &Position::from_exit_event(events, index));
+                        push_line_ending(events, bytes, &mut result, &mut line_ending);
+                        result.push_str(
+                            decode_emoji(slice.as_str())
+                                .expect("expected to parse only known shortcodes"),
+                        );
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    result
+}
+
+/// Push a pending line ending, as more text follows.
+fn push_line_ending(
+    events: &[Event],
+    bytes: &[u8],
+    result: &mut String,
+    line_ending: &mut Option<usize>,
+) {
+    if let Some(exit) = line_ending.take() {
+        result.push_str(
+            Slice::from_position(bytes, &Position::from_exit_event(events, exit)).as_str(),
+        );
+    }
+}
@@ -49,6 +49,48 @@ impl<'a> Position<'a> {
     }
 }
 
+/// Get the string between two points.
+///
+/// Points of events are always at UTF-8 character boundaries, so this can be
+/// used to get the source of any event or events.
+///
+/// > 👉 **Note**: cannot represent virtual spaces.
+///
+/// ## Panics
+///
+/// This function panics if the points are not in `value`, or not at
+/// character boundaries.
+/// When points of events from `markdown-rs` for that value are used, this
+/// function never panics.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{str_between, EventKind, EventName, Markdown, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let markdown = Markdown::new("a *café* b", &ParseOptions::default())?;
+/// let events = markdown.events();
+/// let enter = events
+///     .iter()
+///     .position(|d| d.kind == EventKind::Enter && d.name == EventName::Emphasis)
+///     .unwrap();
+/// let exit = events
+///     .iter()
+///     .position(|d| d.kind == EventKind::Exit && d.name == EventName::Emphasis)
+///     .unwrap();
+///
+/// assert_eq!(
+///     str_between(markdown.value(), &events[enter].point, &events[exit].point),
+///     "*café*"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn str_between<'a>(value: &'a str, start: &Point, end: &Point) -> &'a str {
+    &value[start.index..end.index]
+}
+
 /// Bytes belonging to a range.
 ///
 /// Includes info on virtual spaces before and after the bytes.
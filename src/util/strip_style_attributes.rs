@@ -0,0 +1,214 @@
+//! Remove `style` attributes from HTML.
+
+use alloc::{string::String, vec::Vec};
+
+/// Where we are in HTML.
+///
+/// HTML comes in chunks (lines), and tags can span several of them, so this
+/// is kept between chunks.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum State {
+    /// Not in a tag.
+    Data,
+    /// In a tag name.
+    TagName,
+    /// In a tag, between attributes.
+    Tag,
+    /// In an attribute name; `true` if the attribute is dropped.
+    AttributeName(bool),
+    /// After an attribute name.
+    AfterAttributeName(bool),
+    /// After `=`, before an attribute value.
+    BeforeAttributeValue(bool),
+    /// In a quoted attribute value, with the quote.
+    AttributeValueQuoted(u8, bool),
+    /// In an unquoted attribute value.
+    AttributeValueUnquoted(bool),
+}
+
+/// Remove `style` attributes, and the whitespace before them, from start
+/// tags in HTML.
+///
+/// Other attributes are left as they are.
+/// This is a naïve attempt to prevent CSS-based attacks, which does not
+/// know about comments, `<script>`, and the like, or about `style`
+/// elements.
+/// You should use a proper HTML sanitizing algorithm instead.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::strip_style_attributes::{strip_style_attributes, State};
+///
+/// let mut state = State::Data;
+/// assert_eq!(
+///     strip_style_attributes("<div style=\"x\" class='y'>", &mut state),
+///     "<div class='y'>"
+/// );
+/// ```
+pub fn strip_style_attributes(value: &str, state: &mut State) -> String {
+    let bytes = value.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    // Whitespace in a tag, which is dropped if a `style` attribute follows.
+    let mut whitespace_start = None;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+
+        match *state {
+            State::Data => {
+                if byte == b'<' && index + 1 < bytes.len() && bytes[index + 1].is_ascii_alphabetic()
+                {
+                    *state = State::TagName;
+                }
+
+                result.push(byte);
+            }
+            State::TagName => {
+                if is_name_end(byte) {
+                    *state = State::Tag;
+                    continue;
+                }
+
+                result.push(byte);
+            }
+            State::Tag => {
+                if is_whitespace(byte) {
+                    if whitespace_start.is_none() {
+                        whitespace_start = Some(index);
+                    }
+                } else {
+                    let drop = is_style(&bytes[index..]);
+
+                    if let Some(start) = whitespace_start.take() {
+                        if !drop {
+                            result.extend_from_slice(&bytes[start..index]);
+                        }
+                    }
+
+                    if drop {
+                        index += 5;
+                        *state = State::AfterAttributeName(true);
+                        continue;
+                    }
+
+                    if byte == b'>' {
+                        *state = State::Data;
+                    } else if byte != b'/' {
+                        *state = State::AttributeName(false);
+                    }
+
+                    result.push(byte);
+                }
+            }
+            State::AttributeName(drop) => {
+                if is_name_end(byte) {
+                    *state = State::AfterAttributeName(drop);
+                    continue;
+                }
+
+                if byte == b'=' {
+                    *state = State::BeforeAttributeValue(drop);
+                }
+
+                if !drop {
+                    result.push(byte);
+                }
+            }
+            State::AfterAttributeName(drop) => {
+                if is_whitespace(byte) {
+                    if whitespace_start.is_none() {
+                        whitespace_start = Some(index);
+                    }
+                } else if byte == b'=' {
+                    if let Some(start) = whitespace_start.take() {
+                        if !drop {
+                            result.extend_from_slice(&bytes[start..index]);
+                        }
+                    }
+
+                    *state = State::BeforeAttributeValue(drop);
+
+                    if !drop {
+                        result.push(byte);
+                    }
+                } else {
+                    // Another attribute, or the end of the tag.
+                    *state = State::Tag;
+                    continue;
+                }
+            }
+            State::BeforeAttributeValue(drop) => {
+                if byte == b'>' {
+                    *state = State::Tag;
+                    continue;
+                }
+
+                if byte == b'"' || byte == b'\'' {
+                    *state = State::AttributeValueQuoted(byte, drop);
+                } else if !is_whitespace(byte) {
+                    *state = State::AttributeValueUnquoted(drop);
+                    continue;
+                }
+
+                if !drop {
+                    result.push(byte);
+                }
+            }
+            State::AttributeValueQuoted(quote, drop) => {
+                if byte == quote {
+                    *state = State::Tag;
+                }
+
+                if !drop {
+                    result.push(byte);
+                }
+            }
+            State::AttributeValueUnquoted(drop) => {
+                if is_whitespace(byte) || byte == b'>' {
+                    *state = State::Tag;
+                    continue;
+                }
+
+                if !drop {
+                    result.push(byte);
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    // Whitespace at the end of a chunk is kept.
+    if let Some(start) = whitespace_start {
+        result.extend_from_slice(&bytes[start..]);
+    }
+
+    // A line ending, which is whitespace, follows chunks.
+    *state = match *state {
+        State::TagName | State::AttributeValueUnquoted(_) => State::Tag,
+        State::AttributeName(drop) => State::AfterAttributeName(drop),
+        state => state,
+    };
+
+    // Only ASCII bytes, or whole attribute values, are dropped.
+    String::from_utf8(result).unwrap()
+}
+
+/// Check whether `bytes` start with a `style` attribute name.
+fn is_style(bytes: &[u8]) -> bool {
+    bytes.len() >= 5
+        && bytes[..5].eq_ignore_ascii_case(b"style")
+        && (bytes.len() == 5 || is_name_end(bytes[5]) || bytes[5] == b'=')
+}
+
+/// Check whether `byte` ends a tag or attribute name.
+fn is_name_end(byte: u8) -> bool {
+    is_whitespace(byte) || byte == b'/' || byte == b'>'
+}
+
+/// Check whether `byte` is HTML whitespace.
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b'\t' | b'\n' | 12 /* `\f` */ | b'\r' | b' ')
+}
@@ -0,0 +1,145 @@
+//! Custom constructs in text.
+
+use alloc::{boxed::Box, fmt, string::String};
+use core::str;
+
+/// Custom construct in the [text][crate::construct::text] content type.
+///
+/// Text constructs are tried before the built-in constructs at each of their
+/// [`markers`][TextConstruct::markers].
+/// When [`parse`][TextConstruct::parse] does not match, or when the match
+/// cannot be used (such as when it goes past the end of a table cell), the
+/// built-in constructs are tried instead.
+///
+/// Matches cannot span multiple lines and their content is not parsed further.
+/// In mdast, they are turned into text.
+///
+/// Unlike the built-in constructs, text constructs are not state machines:
+/// the tokenizer is internal, so instead `parse` is given the rest of the line
+/// and its match is attempted like any other construct, which means it is
+/// backtracked when it cannot be used.
+/// As `parse` is called at each marker, it should look at as little of the
+/// line as it needs to.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_with_options, Options, ParseOptions, TextConstruct};
+/// # fn main() -> Result<(), String> {
+///
+/// struct Mark;
+///
+/// impl TextConstruct for Mark {
+///     fn markers(&self) -> &[u8] {
+///         b"="
+///     }
+///
+///     fn parse(&self, value: &str) -> Option<usize> {
+///         // Look for the closing `==` in at most 64 bytes, so that each
+///         // marker takes constant time, however long the line is.
+///         let rest = value.strip_prefix("==")?.as_bytes();
+///         let rest = &rest[..rest.len().min(64)];
+///         let size = rest.windows(2).position(|pair| pair == b"==")?;
+///
+///         if size > 0 {
+///             Some(size + 4)
+///         } else {
+///             None
+///         }
+///     }
+///
+///     fn to_html(&self, value: &str) -> String {
+///         format!("<mark>{}</mark>", &value[2..value.len() - 2])
+///     }
+/// }
+///
+/// let options = Options {
+///     parse: ParseOptions {
+///         text_constructs: vec![Box::new(Mark)],
+///         ..ParseOptions::default()
+///     },
+///     ..Options::default()
+/// };
+///
+/// assert_eq!(
+///     to_html_with_options("a ==b== c", &options)?,
+///     "<p>a <mark>b</mark> c</p>"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub trait TextConstruct {
+    /// Bytes that can start this construct.
+    fn markers(&self) -> &[u8];
+
+    /// Check whether the construct occurs at the start of `value`.
+    ///
+    /// `value` is the rest of the line, starting at one of the
+    /// [`markers`][TextConstruct::markers].
+    /// This is called at each marker, so avoid scanning all of `value` when
+    /// there cannot be a match.
+    /// Return the number of bytes taken, or `None` if there is no match.
+    fn parse(&self, value: &str) -> Option<usize>;
+
+    /// Turn a match into HTML.
+    ///
+    /// `value` is exactly what [`parse`][TextConstruct::parse] matched.
+    /// When events are compiled without the construct that matched (such as
+    /// with [`to_html_from_events()`][crate::to_html_from_events] and other
+    /// options), `value` is not turned into HTML but encoded as text.
+    ///
+    /// > 👉 **Important**: the result is not encoded or sanitized, which can
+    /// > open you up to XSS attacks for user-provided markdown.
+    fn to_html(&self, value: &str) -> String;
+}
+
+impl fmt::Debug for dyn TextConstruct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[TextConstruct]")
+    }
+}
+
+/// Find the first text construct that matches at `index`.
+///
+/// `line` is the rest of the line that was last looked at, and where it
+/// starts, which is reused when `index` is in it, so that a line with many
+/// markers is not scanned again for each one.
+///
+/// Returns the index of that construct and the number of bytes it takes.
+pub fn find<'a>(
+    constructs: &[Box<dyn TextConstruct>],
+    bytes: &'a [u8],
+    line: &mut (usize, &'a str),
+    index: usize,
+) -> Option<(usize, usize)> {
+    let byte = *bytes.get(index)?;
+
+    if index < line.0 || index >= line.0 + line.1.len() {
+        let mut end = index;
+
+        while end < bytes.len() && !matches!(bytes[end], b'\n' | b'\r') {
+            end += 1;
+        }
+
+        *line = (index, str::from_utf8(&bytes[index..end]).ok()?);
+    }
+
+    let value = line.1.get(index - line.0..)?;
+    let mut construct_index = 0;
+
+    while construct_index < constructs.len() {
+        let construct = &constructs[construct_index];
+
+        if construct.markers().contains(&byte) {
+            if let Some(size) = construct.parse(value) {
+                if size > 0 && value.is_char_boundary(size) {
+                    return Some((construct_index, size));
+                }
+            }
+        }
+
+        construct_index += 1;
+    }
+
+    None
+}
@@ -0,0 +1,188 @@
+//! Warnings found when parsing markdown.
+
+use crate::event::{Event, Kind, Name, Point};
+use crate::unist::Position;
+use crate::util::{
+    normalize_identifier::normalize_identifier,
+    slice::{Position as SlicePosition, Slice},
+};
+use alloc::{collections::BTreeSet, format, string::String, vec, vec::Vec};
+
+/// Something in markdown that is likely a mistake.
+///
+/// Markdown has no syntax errors, so these never stop compiling, but they
+/// point to things that probably don’t render how the author intended.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_with_report, Options};
+/// # fn main() -> Result<(), String> {
+///
+/// let (html, warnings) = to_html_with_report("```js\nconsole.log(1)", &Options::default())?;
+///
+/// assert_eq!(html, "<pre><code class=\"language-js\">console.log(1)\n</code></pre>\n");
+/// assert_eq!(warnings.len(), 1);
+/// assert_eq!(warnings[0].message, "Unclosed code (fenced), expected a closing fence");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Warning {
+    /// Human readable description of the problem.
+    pub message: String,
+    /// Place of the problem.
+    pub position: Position,
+}
+
+/// Create a warning for a reference to something that isn’t defined.
+pub fn undefined_reference(label: &str, start: &Event, end: &Event) -> Warning {
+    Warning {
+        message: format!(
+            "Undefined reference `{}`, expected a definition with that label",
+            label
+        ),
+        position: Position {
+            start: start.point.to_unist(),
+            end: end.point.to_unist(),
+        },
+    }
+}
+
+/// Create a warning for an attention sequence (emphasis, strong) that isn’t
+/// matched, and thus shown as text.
+pub fn unmatched_attention(
+    marker: u8,
+    open: bool,
+    close: bool,
+    start: &Point,
+    end: &Point,
+) -> Warning {
+    Warning {
+        message: format!(
+            "Unmatched `{}`, expected {} sequence",
+            String::from(char::from(marker)).repeat(end.index - start.index),
+            match (open, close) {
+                (true, false) => "a closing",
+                (false, true) => "an opening",
+                _ => "an opening or closing",
+            }
+        ),
+        position: Position {
+            start: start.to_unist(),
+            end: end.to_unist(),
+        },
+    }
+}
+
+/// Find raw (flow) (code (fenced), math (flow)) without a closing fence.
+pub fn unclosed_raw_flow(events: &[Event], warnings: &mut Vec<Warning>) {
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter && matches!(event.name, Name::CodeFenced | Name::MathFlow) {
+            let fence = if event.name == Name::CodeFenced {
+                Name::CodeFencedFence
+            } else {
+                Name::MathFlowFence
+            };
+            let enter = index;
+            let mut fences = 0;
+            index += 1;
+
+            while !(events[index].kind == Kind::Exit && events[index].name == event.name) {
+                if events[index].kind == Kind::Enter && events[index].name == fence {
+                    fences += 1;
+                }
+
+                index += 1;
+            }
+
+            if fences < 2 {
+                warnings.push(Warning {
+                    message: format!(
+                        "Unclosed {}, expected a closing fence",
+                        if event.name == Name::CodeFenced {
+                            "code (fenced)"
+                        } else {
+                            "math (flow)"
+                        }
+                    ),
+                    position: Position {
+                        start: events[enter].point.to_unist(),
+                        end: events[index].point.to_unist(),
+                    },
+                });
+            }
+        }
+
+        index += 1;
+    }
+}
+
+/// Find definitions and GFM footnote definitions with the same label as an
+/// earlier one.
+///
+/// Like `CommonMark` requires, only the first definition with a label is
+/// used, later ones are ignored.
+/// GFM footnote definitions work the same.
+pub fn duplicate_definitions(events: &[Event], bytes: &[u8], warnings: &mut Vec<Warning>) {
+    let mut definitions = BTreeSet::new();
+    let mut gfm_footnote_definitions = BTreeSet::new();
+    // Open definitions: where they start, and their label if it is a
+    // duplicate.
+    // GFM footnote definitions can contain other ones, so this is a stack.
+    let mut stack: Vec<(usize, Option<String>)> = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        match event.name {
+            Name::Definition | Name::GfmFootnoteDefinition => {
+                if event.kind == Kind::Enter {
+                    stack.push((index, None));
+                } else if let Some((enter, Some(label))) = stack.pop() {
+                    let kind = if event.name == Name::Definition {
+                        "definition"
+                    } else {
+                        "footnote definition"
+                    };
+                    warnings.push(Warning {
+                        message: format!(
+                            "Duplicate {} `{}`, expected a unique label (the first {} with it is used)",
+                            kind, label, kind
+                        ),
+                        position: Position {
+                            start: events[enter].point.to_unist(),
+                            end: event.point.to_unist(),
+                        },
+                    });
+                }
+            }
+            Name::DefinitionLabelString | Name::GfmFootnoteDefinitionLabelString
+                if event.kind == Kind::Exit =>
+            {
+                let identifiers = if event.name == Name::DefinitionLabelString {
+                    &mut definitions
+                } else {
+                    &mut gfm_footnote_definitions
+                };
+                let label =
+                    Slice::from_position(bytes, &SlicePosition::from_exit_event(events, index))
+                        .serialize();
+
+                if !identifiers.insert(normalize_identifier(&label)) {
+                    if let Some(open) = stack.last_mut() {
+                        open.1 = Some(label);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+}
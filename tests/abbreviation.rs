@@ -0,0 +1,200 @@
+use markdown::{
+    mdast::{
+        AttributeContent, AttributeValue, MdxJsxAttribute, MdxJsxTextElement, Node, Paragraph,
+        Root, Text,
+    },
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn abbreviation() -> Result<(), String> {
+    let abbreviation = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                abbreviation: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("*[HTML]: HyperText Markup Language\n\nHTML"),
+        "<p>*[HTML]: HyperText Markup Language</p>\n<p>HTML</p>",
+        "should not support abbreviations by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[HTML]: HyperText Markup Language\n\nThe HTML spec.",
+            &abbreviation
+        )?,
+        "<p>The <abbr title=\"HyperText Markup Language\">HTML</abbr> spec.</p>",
+        "should support abbreviations"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[HTML]: HyperText Markup Language\n\nHTMLish and unHTML.",
+            &abbreviation
+        )?,
+        "<p>HTMLish and unHTML.</p>",
+        "should not support abbreviations in words"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n\nHTML5 html HTML_ (HTML) HTML. é HTML|HTML", &abbreviation)?,
+        "<p>HTML5 html <abbr title=\"a\">HTML</abbr>_ (<abbr title=\"a\">HTML</abbr>) <abbr title=\"a\">HTML</abbr>. é <abbr title=\"a\">HTML</abbr>|<abbr title=\"a\">HTML</abbr></p>",
+        "should support abbreviations next to punctuation and whitespace, case-sensitively"
+    );
+
+    assert_eq!(
+        to_html_with_options("éHTML HTMLé\n\n*[HTML]: a", &abbreviation)?,
+        "<p>éHTML HTMLé</p>\n",
+        "should not support abbreviations next to non-ASCII letters"
+    );
+
+    assert_eq!(
+        to_html_with_options("a HTML b\n\n*[HTML]: c", &abbreviation)?,
+        "<p>a <abbr title=\"c\">HTML</abbr> b</p>\n",
+        "should support abbreviations defined after they are used"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n*[HTML5]: b\n\nHTML5 HTML", &abbreviation)?,
+        "<p><abbr title=\"b\">HTML5</abbr> <abbr title=\"a\">HTML</abbr></p>",
+        "should prefer longer abbreviations"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[W3C HTML]: a\n\nThe W3C HTML spec.", &abbreviation)?,
+        "<p>The <abbr title=\"a\">W3C HTML</abbr> spec.</p>",
+        "should support abbreviations w/ whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n*[HTML]: b\n\nHTML", &abbreviation)?,
+        "<p><abbr title=\"a\">HTML</abbr></p>",
+        "should prefer the first definition of an abbreviation"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[HTML]: a\n\n`HTML` <b title=\"HTML\"> <https://HTML> [HTML](HTML \"HTML\") [b][HTML]\n\n    HTML\n\n[HTML]: c",
+            &abbreviation
+        )?,
+        "<p><code>HTML</code> &lt;b title=&quot;HTML&quot;&gt; <a href=\"https://HTML\">https://HTML</a> <a href=\"HTML\" title=\"HTML\"><abbr title=\"a\">HTML</abbr></a> <a href=\"c\">b</a></p>\n<pre><code>HTML\n</code></pre>\n",
+        "should not support abbreviations in code, HTML, destinations, titles, and references"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n\n# HTML\n\n*HTML* and ![HTML](b)", &abbreviation)?,
+        "<h1><abbr title=\"a\">HTML</abbr></h1>\n<p><em><abbr title=\"a\">HTML</abbr></em> and <img src=\"b\" alt=\"HTML\" /></p>",
+        "should support abbreviations in headings, attention, and images"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a \"b\" & <c>\n\nHTML", &abbreviation)?,
+        "<p><abbr title=\"a &quot;b&quot; &amp; &lt;c&gt;\">HTML</abbr></p>",
+        "should encode the title"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]:\t a  b \t\n\nHTML", &abbreviation)?,
+        "<p><abbr title=\"a  b\">HTML</abbr></p>",
+        "should support whitespace around the title"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]:a\n\nHTML", &abbreviation)?,
+        "<p><abbr title=\"a\">HTML</abbr></p>",
+        "should support a title w/o whitespace before it"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]:\n\nHTML", &abbreviation)?,
+        "<p>*[HTML]:</p>\n<p>HTML</p>",
+        "should not support a missing title"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[]: a", &abbreviation)?,
+        "<p>*[]: a</p>",
+        "should not support an empty label"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[a[b]: c\n\n*[a\nb]: c", &abbreviation)?,
+        "<p>*[a[b]: c</p>\n<p>*[a\nb]: c</p>",
+        "should not support brackets or line endings in labels"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML] a\n\n*HTML: a", &abbreviation)?,
+        "<p>*[HTML] a</p>\n<p>*HTML: a</p>",
+        "should not support definitions w/o `:` or `[`"
+    );
+
+    assert_eq!(
+        to_html_with_options(" *[HTML]: a\n\nHTML", &abbreviation)?,
+        "<p>*[HTML]: a</p>\n<p>HTML</p>",
+        "should not support indented definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("a HTML\n*[HTML]: b\nHTML c", &abbreviation)?,
+        "<p>a <abbr title=\"b\">HTML</abbr></p>\n<p><abbr title=\"b\">HTML</abbr> c</p>",
+        "should support definitions interrupting paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("> *[HTML]: a\n\n- HTML", &abbreviation)?,
+        "<blockquote>\n</blockquote>\n<ul>\n<li><abbr title=\"a\">HTML</abbr></li>\n</ul>",
+        "should support definitions in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("***\n* a", &abbreviation)?,
+        "<hr />\n<ul>\n<li>a</li>\n</ul>",
+        "should not affect thematic breaks or list items"
+    );
+
+    assert_eq!(
+        to_mdast("a HTML b\n\n*[HTML]: c", &abbreviation.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::MdxJsxTextElement(MdxJsxTextElement {
+                        name: Some("abbr".into()),
+                        attributes: vec![AttributeContent::Property(MdxJsxAttribute {
+                            name: "title".into(),
+                            value: Some(AttributeValue::Literal("c".into())),
+                        })],
+                        children: vec![Node::Text(Text {
+                            value: "HTML".into(),
+                            position: Some(Position::new(1, 3, 2, 1, 7, 6))
+                        })],
+                        position: Some(Position::new(1, 3, 2, 1, 7, 6))
+                    }),
+                    Node::Text(Text {
+                        value: " b".into(),
+                        position: Some(Position::new(1, 7, 6, 1, 9, 8))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 9, 8))
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 11, 20))
+        }),
+        "should support abbreviations as `abbr` elements (mdast)"
+    );
+
+    Ok(())
+}
@@ -837,6 +837,127 @@ fn attention() -> Result<(), String> {
         "should support turning off attention"
     );
 
+    assert_eq!(
+        to_html(&"a *b* c_d_ **e**$*f*$ (*g*) *h*+\t*i*\n\n".repeat(64)),
+        "<p>a <em>b</em> c_d_ <strong>e</strong>$<em>f</em>$ (<em>g</em>) <em>h</em>+\t<em>i</em></p>\n"
+            .repeat(64),
+        "should classify ASCII around attention (many lines)"
+    );
+
+    assert_eq!(
+        to_html("\u{a0}*a*\u{a0} 。*b*。 é*c*é $*d*$"),
+        "<p>\u{a0}<em>a</em>\u{a0} 。<em>b</em>。 é<em>c</em>é $<em>d</em>$</p>",
+        "should classify non-ASCII around attention the same as ASCII"
+    );
+
+    assert_eq!(
+        to_html("一*二*三"),
+        "<p>一<em>二</em>三</p>",
+        "should support intraword emphasis w/ `*` in CJK (1)"
+    );
+
+    assert_eq!(
+        to_html("こんにちは**世界**です"),
+        "<p>こんにちは<strong>世界</strong>です</p>",
+        "should support intraword emphasis w/ `*` in CJK (2)"
+    );
+
+    assert_eq!(
+        to_html("한*국*어"),
+        "<p>한<em>국</em>어</p>",
+        "should support intraword emphasis w/ `*` in CJK (3)"
+    );
+
+    assert_eq!(
+        to_html("一_二_三"),
+        "<p>一_二_三</p>",
+        "should not support intraword emphasis w/ `_` in CJK (1)"
+    );
+
+    assert_eq!(
+        to_html("一__二__三"),
+        "<p>一__二__三</p>",
+        "should not support intraword emphasis w/ `_` in CJK (2)"
+    );
+
+    assert_eq!(
+        to_html("「*二*」"),
+        "<p>「<em>二</em>」</p>",
+        "should support emphasis between CJK punctuation"
+    );
+
+    assert_eq!(
+        to_html("一*「二」*三"),
+        "<p>一*「二」*三</p>",
+        "should not support emphasis w/ CJK punctuation inside and CJK letters outside"
+    );
+
+    assert_eq!(
+        to_html("a_b_c"),
+        "<p>a_b_c</p>",
+        "should not support intraword emphasis w/ `_` between letters"
+    );
+
+    assert_eq!(
+        to_html("1_2_3"),
+        "<p>1_2_3</p>",
+        "should not support intraword emphasis w/ `_` between digits"
+    );
+
+    assert_eq!(
+        to_html("é_b_é"),
+        "<p>é_b_é</p>",
+        "should not support intraword emphasis w/ `_` between non-ASCII letters"
+    );
+
+    assert_eq!(
+        to_html("a__b__c"),
+        "<p>a__b__c</p>",
+        "should not support intraword strong w/ `_`"
+    );
+
+    assert_eq!(
+        to_html("a_b_ c"),
+        "<p>a_b_ c</p>",
+        "should not support emphasis w/ `_` opening intraword"
+    );
+
+    assert_eq!(
+        to_html("a _b_c"),
+        "<p>a _b_c</p>",
+        "should not support emphasis w/ `_` closing intraword"
+    );
+
+    assert_eq!(
+        to_html("_foo_"),
+        "<p><em>foo</em></p>",
+        "should support emphasis w/ `_` at word boundaries"
+    );
+
+    assert_eq!(
+        to_html("a _b_ c"),
+        "<p>a <em>b</em> c</p>",
+        "should support emphasis w/ `_` between whitespace"
+    );
+
+    assert_eq!(
+        to_html("(_a_)"),
+        "<p>(<em>a</em>)</p>",
+        "should support emphasis w/ `_` between punctuation"
+    );
+
+    assert_eq!(
+        to_html("a *b* c"),
+        "<p>a <em>b</em> c</p>",
+        "should support emphasis w/ `*` at word boundaries"
+    );
+
+    assert_eq!(
+        to_html("a*b*c"),
+        "<p>a<em>b</em>c</p>",
+        "should support intraword emphasis w/ `*`, unlike `_`"
+    );
+
     assert_eq!(
         to_mdast("a *alpha* b **bravo** c.", &Default::default())?,
         Node::Root(Root {
@@ -269,6 +269,97 @@ fn autolink() -> Result<(), String> {
         "should support turning off autolinks"
     );
 
+    let literals_only = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                autolink: false,
+                ..Constructs::gfm()
+            },
+            ..ParseOptions::gfm()
+        },
+        ..Options::gfm()
+    };
+
+    assert_eq!(
+        to_html_with_options("https://a.com", &literals_only)?,
+        "<p><a href=\"https://a.com\">https://a.com</a></p>",
+        "should support GFM autolink literals when autolinks are off"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://a.com>", &literals_only)?,
+        "<p>&lt;<a href=\"https://a.com%3E\">https://a.com&gt;</a></p>",
+        "should not support autolinks when off, but still literals (GFM includes `>` in paths)"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://a.com> b", &literals_only)?,
+        "<p>&lt;<a href=\"https://a.com%3E\">https://a.com&gt;</a> b</p>",
+        "should not support autolinks when off, followed by more text"
+    );
+
+    assert_eq!(
+        to_html_with_options("<a@b.co>", &literals_only)?,
+        "<p>&lt;<a href=\"mailto:a@b.co\">a@b.co</a>&gt;</p>",
+        "should not support email autolinks when off, but still literals"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<https://münchen.de/ä>",
+            &Options {
+                compile: CompileOptions {
+                    punycode_hosts: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://xn--mnchen-3ya.de/%C3%A4\">https://münchen.de/ä</a></p>",
+        "should support `punycode_hosts` in autolinks"
+    );
+
+    assert_eq!(
+        to_html("<a@b.com>"),
+        "<p><a href=\"mailto:a@b.com\">a@b.com</a></p>",
+        "should add `mailto:` to email autolinks by default, but not to their text"
+    );
+
+    let without_mailto = Options {
+        compile: CompileOptions {
+            autolink_email_without_mailto: true,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("<a@b.com>", &without_mailto)?,
+        "<p><a href=\"a@b.com\">a@b.com</a></p>",
+        "should support `autolink_email_without_mailto`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://a.com>", &without_mailto)?,
+        "<p><a href=\"https://a.com\">https://a.com</a></p>",
+        "should not affect URL autolinks w/ `autolink_email_without_mailto`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a@b.com",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    autolink_email_without_mailto: true,
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<p><a href=\"mailto:a@b.com\">a@b.com</a></p>",
+        "should not affect GFM email literals w/ `autolink_email_without_mailto`"
+    );
+
     assert_eq!(
         to_mdast(
             "a <https://alpha.com> b <bravo@charlie.com> c.",
@@ -2,7 +2,7 @@ use markdown::{
     mdast::{BlockQuote, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -218,6 +218,36 @@ fn block_quote() -> Result<(), String> {
         "should support turning off block quotes"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "> a",
+            &Options {
+                compile: CompileOptions {
+                    block_quote_class: Some("note".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<blockquote class=\"note\">\n<p>a</p>\n</blockquote>",
+        "should support a custom class on block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "> a\n>\n> > b",
+            &Options {
+                compile: CompileOptions {
+                    block_quote_class: Some("a \"b\" <c>".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<blockquote class=\"a &quot;b&quot; &lt;c&gt;\">\n<p>a</p>\n<blockquote class=\"a &quot;b&quot; &lt;c&gt;\">\n<p>b</p>\n</blockquote>\n</blockquote>",
+        "should encode custom classes on block quotes"
+    );
+
     assert_eq!(
         to_mdast("> a", &Default::default())?,
         Node::Root(Root {
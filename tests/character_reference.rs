@@ -196,6 +196,99 @@ fn character_reference() -> Result<(), String> {
         "should not support the other characters inside a hexademical"
     );
 
+    assert_eq!(
+        to_html("a & b"),
+        "<p>a &amp; b</p>",
+        "should encode a bare ampersand in text"
+    );
+
+    assert_eq!(
+        to_html("a &amp; b"),
+        "<p>a &amp; b</p>",
+        "should not double encode an ampersand from a character reference in text"
+    );
+
+    assert_eq!(
+        to_html("a &#38; &#x26; b"),
+        "<p>a &amp; &amp; b</p>",
+        "should not double encode an ampersand from numeric character references"
+    );
+
+    assert_eq!(
+        to_html("a &amp;amp; b"),
+        "<p>a &amp;amp; b</p>",
+        "should encode an ampersand decoded from a character reference once"
+    );
+
+    assert_eq!(
+        to_html("[a & &amp;](b&c&amp;d \"e & &amp;\")"),
+        "<p><a href=\"b&amp;c&amp;d\" title=\"e &amp; &amp;\">a &amp; &amp;</a></p>",
+        "should encode bare ampersands and not double encode references in attributes"
+    );
+
+    assert_eq!(
+        to_html("![a & &amp;](b)"),
+        "<p><img src=\"b\" alt=\"a &amp; &amp;\" /></p>",
+        "should encode bare ampersands and not double encode references in alt"
+    );
+
+    assert_eq!(
+        to_html("```a&b&amp;c\n```"),
+        "<pre><code class=\"language-a&amp;b&amp;c\"></code></pre>",
+        "should encode bare ampersands and not double encode references in info strings"
+    );
+
+    assert_eq!(
+        to_html("`a & &amp;`"),
+        "<p><code>a &amp; &amp;amp;</code></p>",
+        "should encode ampersands, including in references, in code"
+    );
+
+    assert_eq!(
+        to_html("&#x110000; &#9999999;"),
+        "<p>� �</p>",
+        "should replace numeric character references above the Unicode range"
+    );
+
+    assert_eq!(
+        to_html("&#x10FFFF;"),
+        "<p>\u{10FFFF}</p>",
+        "should support the last code point"
+    );
+
+    assert_eq!(
+        to_html("&#xD800; &#xDFFF; &#55296;"),
+        "<p>� � �</p>",
+        "should replace numeric character references to surrogates"
+    );
+
+    assert_eq!(
+        to_html("&#0; &#x0;"),
+        "<p>� �</p>",
+        "should replace numeric character references to NUL"
+    );
+
+    assert_eq!(
+        to_html("&#12345678; &#x1234567;"),
+        "<p>&amp;#12345678; &amp;#x1234567;</p>",
+        "should not support numeric character references w/ too many digits"
+    );
+
+    assert_eq!(
+        to_mdast("&#xD800;", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "�".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 9, 8))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 9, 8))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 9, 8))
+        }),
+        "should replace numeric character references to surrogates in mdast"
+    );
+
     assert_eq!(
         to_html_with_options(
             "&amp;",
@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Code, Node, Root},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -98,6 +98,24 @@ fn code_fenced() -> Result<(), String> {
       "should remove up to three space from the content if the opening sequence is indented w/ 3 spaces"
     );
 
+    assert_eq!(
+        to_html("  ```\n  a\n    b\n"),
+        "<pre><code>a\n  b\n</code></pre>\n",
+        "should keep indentation past that of the opening sequence (2 spaces)"
+    );
+
+    assert_eq!(
+        to_html("  ```\n  a\n    b\n c\nd\n  ```"),
+        "<pre><code>a\n  b\nc\nd\n</code></pre>",
+        "should remove at most the indentation of the opening sequence (2 spaces)"
+    );
+
+    assert_eq!(
+        to_html("  ```\n\ta\n"),
+        "<pre><code>  a\n</code></pre>\n",
+        "should remove the indentation of the opening sequence from a tab"
+    );
+
     assert_eq!(
         to_html("    ```\n    aaa\n    ```"),
         "<pre><code>```\naaa\n```\n</code></pre>",
@@ -261,6 +279,30 @@ fn code_fenced() -> Result<(), String> {
         "should not support lazyness (3)"
     );
 
+    assert_eq!(
+        to_html("> ```\n> a\n```"),
+        "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>\n<pre><code></code></pre>\n",
+        "should not close code in a block quote w/ a closing fence outside it"
+    );
+
+    assert_eq!(
+        to_html("> ```\n> a\n> ```"),
+        "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>",
+        "should close code in a block quote w/ a closing fence in it"
+    );
+
+    assert_eq!(
+        to_html("> ```\n> a\n```\nb\n```"),
+        "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>\n<pre><code>b\n</code></pre>",
+        "should open new code w/ a closing fence outside a block quote"
+    );
+
+    assert_eq!(
+        to_html("- ```\n  a\n```"),
+        "<ul>\n<li>\n<pre><code>a\n</code></pre>\n</li>\n</ul>\n<pre><code></code></pre>\n",
+        "should not close code in a list item w/ a closing fence outside it"
+    );
+
     assert_eq!(
         to_html_with_options(
             "```",
@@ -279,6 +321,142 @@ fn code_fenced() -> Result<(), String> {
         "should support turning off code (fenced)"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "```js\na\n```",
+            &Options {
+                compile: CompileOptions {
+                    code_block_class: Some("highlight".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<pre class=\"highlight\"><code class=\"language-js\">a\n</code></pre>",
+        "should support a custom class on code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "~~~\na\n~~~",
+            &Options {
+                compile: CompileOptions {
+                    code_block_class: Some("highlight".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<pre class=\"highlight\"><code>a\n</code></pre>",
+        "should support a custom class on code (fenced) w/o info"
+    );
+
+    let line_numbers = Options {
+        compile: CompileOptions {
+            code_line_numbers: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("```js\na\nb\nc\n```", &line_numbers)?,
+        "<pre data-line-start=\"2\" data-line-count=\"3\"><code class=\"language-js\">a\nb\nc\n</code></pre>",
+        "should support line numbers on code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options("x\n\n> ~~~\n> a\n>\n> b", &line_numbers)?,
+        "<p>x</p>\n<blockquote>\n<pre data-line-start=\"4\" data-line-count=\"3\"><code>a\n\nb\n</code></pre>\n</blockquote>",
+        "should support line numbers on code (fenced) w/o closing fence, in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\n\n", &line_numbers)?,
+        "<pre data-line-start=\"2\" data-line-count=\"2\"><code>a\n\n</code></pre>\n",
+        "should not count a final line ending in line numbers"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\n```", &line_numbers)?,
+        "<pre data-line-start=\"2\" data-line-count=\"0\"><code></code></pre>",
+        "should support line numbers on empty code (fenced)"
+    );
+
+    let copy = Options {
+        compile: CompileOptions {
+            code_block_copy_attribute: Some("data-code".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let copy_value = Options {
+        compile: CompileOptions {
+            code_block_copy_attribute: Some("data-copy".into()),
+            code_block_copy_value: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("```js\na\n```"),
+        "<pre><code class=\"language-js\">a\n</code></pre>",
+        "should not add a copy attribute by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("```js\na\n```", &copy)?,
+        "<pre data-code=\"\"><code class=\"language-js\">a\n</code></pre>",
+        "should support a copy attribute on code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "```\na\n```",
+            &Options {
+                compile: CompileOptions {
+                    code_block_copy_attribute: Some("data-a onclick".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<pre><code>a\n</code></pre>",
+        "should not add a copy attribute w/ an invalid name"
+    );
+
+    assert_eq!(
+        to_html_with_options("```js\nif (a < b && c) {\n  d(\"e\")\n}\n```", &copy_value)?,
+        "<pre data-copy=\"if (a &lt; b &amp;&amp; c) {\n  d(&quot;e&quot;)\n}\"><code class=\"language-js\">if (a &lt; b &amp;&amp; c) {\n  d(&quot;e&quot;)\n}\n</code></pre>",
+        "should support the code as the value of the copy attribute, encoded"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\n\na\n\n\nb\n\n```", &copy_value)?,
+        "<pre data-copy=\"\na\n\n\nb\n\"><code>\na\n\n\nb\n\n</code></pre>",
+        "should support blank lines in the value of the copy attribute"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\r\na\r\nb\r\n```", &copy_value)?,
+        "<pre data-copy=\"a\r\nb\"><code>a\r\nb\r\n</code></pre>",
+        "should keep line endings in the value of the copy attribute"
+    );
+
+    assert_eq!(
+        to_html_with_options("> ```\n>\ta\nb", &copy_value)?,
+        "<blockquote>\n<pre data-copy=\"   a\"><code>   a\n</code></pre>\n</blockquote>\n<p>b</p>",
+        "should support the copy attribute in containers, w/ virtual spaces, and w/o closing fence"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\n```", &copy_value)?,
+        "<pre data-copy=\"\"><code></code></pre>",
+        "should support the copy attribute on empty code (fenced)"
+    );
+
     assert_eq!(
         to_mdast(
             "```js extra\nconsole.log(1)\nconsole.log(2)\n```",
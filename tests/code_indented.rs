@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Code, Node, Root},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Markdown, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -44,6 +44,39 @@ fn code_indented() -> Result<(), String> {
         "should support blank lines in indented code (3)"
     );
 
+    assert_eq!(
+        to_html("    a\n\n    b"),
+        "<pre><code>a\n\nb\n</code></pre>",
+        "should support an interior blank line in one block"
+    );
+
+    assert_eq!(
+        to_html("    a\n\n"),
+        "<pre><code>a\n</code></pre>\n",
+        "should not include trailing blank lines"
+    );
+
+    assert_eq!(
+        to_html("    a\n    \n      \n\nb"),
+        "<pre><code>a\n</code></pre>\n<p>b</p>",
+        "should not include trailing whitespace-only lines"
+    );
+
+    assert_eq!(
+        to_html("- a\n\n      b\n      \n      "),
+        "<ul>\n<li>\n<p>a</p>\n<pre><code>b\n</code></pre>\n</li>\n</ul>",
+        "should not include trailing whitespace-only lines (in list item)"
+    );
+
+    assert_eq!(
+        Markdown::new("    a\n\n    b\n\n    \n", &ParseOptions::default())?
+            .code_blocks()
+            .map(|span| span.as_str())
+            .collect::<Vec<_>>(),
+        vec!["    a\n\n    b"],
+        "should include interior blank lines but not trailing ones in the span of a block"
+    );
+
     assert_eq!(
         to_html("Foo\n    bar"),
         "<p>Foo\nbar</p>",
@@ -70,10 +103,22 @@ fn code_indented() -> Result<(), String> {
 
     assert_eq!(
         to_html("\n    \n    foo\n    "),
-        "<pre><code>foo\n</code></pre>",
+        "<pre><code>foo\n</code></pre>\n",
         "should not support initial blank lines"
     );
 
+    assert_eq!(
+        to_html("    a\n      "),
+        "<pre><code>a\n</code></pre>\n",
+        "should not include a final whitespace-only line, but keep the line ending before it"
+    );
+
+    assert_eq!(
+        to_html("```\na\n```\n      "),
+        "<pre><code>a\n</code></pre>\n",
+        "should keep the line ending before a final whitespace-only line, like fenced code"
+    );
+
     assert_eq!(
         to_html("    foo  "),
         "<pre><code>foo  \n</code></pre>",
@@ -202,6 +247,70 @@ fn code_indented() -> Result<(), String> {
         "should support turning off code (indented, 9)"
     );
 
+    assert_eq!(
+        to_html_with_options("a\n\n    b\n    c", &off)?,
+        "<p>a</p>\n<p>b\nc</p>",
+        "should support turning off code (indented, 10)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n    ===", &off)?,
+        "<h1>a</h1>",
+        "should support turning off code (indented, 11)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\t\t---", &off)?,
+        "<h2>a</h2>",
+        "should support turning off code (indented, 12)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "    a",
+            &Options {
+                compile: CompileOptions {
+                    code_block_class: Some("highlight".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<pre class=\"highlight\"><code>a\n</code></pre>",
+        "should support a custom class on code (indented)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\n\n    b\n\n    c\n\n",
+            &Options {
+                compile: CompileOptions {
+                    code_line_numbers: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a</p>\n<pre data-line-start=\"3\" data-line-count=\"3\"><code>b\n\nc\n</code></pre>\n",
+        "should support line numbers on code (indented)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\n\n    b\n\n     c\n\n",
+            &Options {
+                compile: CompileOptions {
+                    code_block_copy_attribute: Some("data-code".into()),
+                    code_block_copy_value: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a</p>\n<pre data-code=\"b\n\n c\"><code>b\n\n c\n</code></pre>\n",
+        "should support a copy attribute on code (indented)"
+    );
+
     assert_eq!(
         to_mdast(
             "\tconsole.log(1)\n    console.log(2)\n",
@@ -0,0 +1,226 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, EventName, Markdown, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn comment_line() -> Result<(), String> {
+    let percent = Options {
+        parse: ParseOptions {
+            line_comment_prefix: Some("%".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let slashes = Options {
+        parse: ParseOptions {
+            line_comment_prefix: Some("//".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("% hidden\nvisible"),
+        "<p>% hidden\nvisible</p>",
+        "should not support comment lines by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("% hidden\nvisible", &percent)?,
+        "<p>visible</p>",
+        "should support a comment line"
+    );
+
+    assert_eq!(
+        to_html_with_options("%", &percent)?,
+        "",
+        "should support a comment line with just a prefix"
+    );
+
+    assert_eq!(
+        to_html_with_options("a % b", &percent)?,
+        "<p>a % b</p>",
+        "should not support a prefix in the middle of a line"
+    );
+
+    assert_eq!(
+        to_html_with_options("  % a", &percent)?,
+        "<p>% a</p>",
+        "should not support an indented prefix"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n% b\nc", &percent)?,
+        "<p>a\nc</p>",
+        "should support comment lines in paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n% b\n% c\nd", &percent)?,
+        "<p>a\nd</p>",
+        "should support several comment lines in paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\r\n% b\r\nc", &percent)?,
+        "<p>a\r\nc</p>",
+        "should support comment lines in paragraphs (crlf)"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: b\n% c\n[a]", &percent)?,
+        "<p><a href=\"b\">a</a></p>",
+        "should support comment lines after definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n% b\n# c", &percent)?,
+        "<h1>a</h1>\n<h1>c</h1>",
+        "should support comment lines between other flow"
+    );
+
+    assert_eq!(
+        to_html_with_options("> % a\n> b", &percent)?,
+        "<blockquote>\n<p>b</p>\n</blockquote>",
+        "should support comment lines in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n  % b\n  c", &percent)?,
+        "<ul>\n<li>a\nc</li>\n</ul>",
+        "should support comment lines in list items"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n% b", &percent)?,
+        "<blockquote>\n<p>a</p>\n</blockquote>",
+        "should support a comment line at the end of a container"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n%", &percent)?,
+        "<p>a</p>",
+        "should not keep the line ending before a final comment line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n%\n", &percent)?,
+        "<p>a</p>\n",
+        "should keep the line ending of a final comment line"
+    );
+
+    assert_eq!(
+        to_html_with_options("    a\n% b\n    c", &percent)?,
+        "<pre><code>a\nc\n</code></pre>",
+        "should support comment lines in code (indented)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "| a |\n% b\n| - |\n% c\n| d |",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs::gfm(),
+                    line_comment_prefix: Some("%".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>d</td>\n</tr>\n</tbody>\n</table>",
+        "should support comment lines in tables"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n% b\n===", &percent)?,
+        "<h1>a</h1>",
+        "should support comment lines in headings (setext)"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n% b\n- c", &percent)?,
+        "<ul>\n<li>a</li>\n<li>c</li>\n</ul>",
+        "should support comment lines between list items"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n% b\nc", &percent)?,
+        "<blockquote>\n<p>a\nc</p>\n</blockquote>",
+        "should support lazy lines after comment lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\n% a\n```", &percent)?,
+        "<pre><code>% a\n</code></pre>",
+        "should not support comment lines in code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options("// a\n/ b\nc // d", &slashes)?,
+        "<p>/ b\nc // d</p>",
+        "should support a prefix of several characters"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "% a",
+            &Options {
+                parse: ParseOptions {
+                    line_comment_prefix: Some(String::new()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>% a</p>",
+        "should not support an empty prefix"
+    );
+
+    let markdown = Markdown::new("% a\nb\n% c", &percent.parse)?;
+
+    assert_eq!(
+        markdown
+            .spans(&[EventName::CommentLine])
+            .map(|span| span.as_str())
+            .collect::<Vec<_>>(),
+        vec!["% a\n", "% c"],
+        "should emit comment lines as events"
+    );
+
+    assert_eq!(
+        to_mdast("% a\nb", &percent.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "b".into(),
+                    position: Some(Position::new(2, 1, 4, 2, 2, 5))
+                }),],
+                position: Some(Position::new(2, 1, 4, 2, 2, 5))
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 2, 5))
+        }),
+        "should not support comment lines in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("a\n% b\nc", &percent.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a\nc".into(),
+                    position: Some(Position::new(1, 1, 0, 3, 2, 7))
+                }),],
+                position: Some(Position::new(1, 1, 0, 3, 2, 7))
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 2, 7))
+        }),
+        "should not support comment lines in mdast (paragraph)"
+    );
+
+    Ok(())
+}
@@ -23,6 +23,12 @@ fn definition() -> Result<(), String> {
         "should support link definitions"
     );
 
+    assert_eq!(
+        to_html("[a]: b \"he said \\\"hi\\\" &amp; bye\"\n\n[a]"),
+        "<p><a href=\"b\" title=\"he said &quot;hi&quot; &amp; bye\">a</a></p>",
+        "should support character escapes and references in titles"
+    );
+
     assert_eq!(
         to_html("[foo]:\n\n/url\n\n[foo]"),
         "<p>[foo]:</p>\n<p>/url</p>\n<p>[foo]</p>",
@@ -247,13 +253,97 @@ fn definition() -> Result<(), String> {
         "should support definitions in block quotes (3)"
     );
 
+    assert_eq!(
+        to_html("[a]: b \"c\\\nd\"\n\n[a]"),
+        "<p><a href=\"b\" title=\"c\\\nd\">a</a></p>",
+        "should not support a hard break (escape) in a title (double quotes)"
+    );
+
+    assert_eq!(
+        to_html("[a]: b 'c\\\nd'\n\n[a]"),
+        "<p><a href=\"b\" title=\"c\\\nd\">a</a></p>",
+        "should not support a hard break (escape) in a title (single quotes)"
+    );
+
+    assert_eq!(
+        to_html("[a]: b (c\\\nd)\n\n[a]"),
+        "<p><a href=\"b\" title=\"c\\\nd\">a</a></p>",
+        "should not support a hard break (escape) in a title (parens)"
+    );
+
+    assert_eq!(
+        to_html("[a\\\nb]: c\n\n[a\\\nb]"),
+        "<p><a href=\"c\">a<br />\nb</a></p>",
+        "should support a backslash before a line ending in a label, as a hard break (escape) in the reference"
+    );
+
+    assert_eq!(
+        to_html("[a\\\nb]: c\n\n[a\\ b]"),
+        "<p><a href=\"c\">a\\ b</a></p>",
+        "should match labels w/ a backslash before a line ending to labels w/ a backslash before whitespace"
+    );
+
     // Extra
+    assert_eq!(
+        to_html("> [a]: b 'c'\n\n[a][] [A]"),
+        "<blockquote>\n</blockquote>\n<p><a href=\"b\" title=\"c\">a</a> <a href=\"b\" title=\"c\">A</a></p>",
+        "should support references in later top-level paragraphs to definitions in block quotes"
+    );
+
+    assert_eq!(
+        to_html("[a]\n\n> [a]: b"),
+        "<p><a href=\"b\">a</a></p>\n<blockquote>\n</blockquote>",
+        "should support references to definitions in later block quotes"
+    );
+
+    assert_eq!(
+        to_html("* [a]: b\n\n[a]"),
+        "<ul>\n<li></li>\n</ul>\n<p><a href=\"b\">a</a></p>",
+        "should support definitions in list items"
+    );
+
+    assert_eq!(
+        to_html("> * > [a]: b\n\n[a]"),
+        "<blockquote>\n<ul>\n<li>\n<blockquote>\n</blockquote>\n</li>\n</ul>\n</blockquote>\n<p><a href=\"b\">a</a></p>",
+        "should support definitions in deeply nested containers"
+    );
+
+    assert_eq!(
+        to_html("> [a]\n\n* [a]: b"),
+        "<blockquote>\n<p><a href=\"b\">a</a></p>\n</blockquote>\n<ul>\n<li></li>\n</ul>",
+        "should support references in one container to definitions in another"
+    );
+
     assert_eq!(
         to_html("[\\[\\+\\]]: example.com\n\nLink: [\\[\\+\\]]."),
         "<p>Link: <a href=\"example.com\">[+]</a>.</p>",
         "should match w/ character escapes"
     );
 
+    assert_eq!(
+        to_html("[a[b]c]: d\n\n[a[b]c]"),
+        "<p>[a[b]c]: d</p>\n<p>[a[b]c]</p>",
+        "should not support unescaped brackets in a label, even if balanced"
+    );
+
+    assert_eq!(
+        to_html("[a\\[b\\]c]: d\n\n[a\\[b\\]c]"),
+        "<p><a href=\"d\">a[b]c</a></p>",
+        "should support escaped brackets in a label"
+    );
+
+    assert_eq!(
+        to_html("[x][a[b]c]\n\n[a[b]c]: d"),
+        "<p>[x][a[b]c]</p>\n<p>[a[b]c]: d</p>",
+        "should not support unescaped brackets in the label of a full reference"
+    );
+
+    assert_eq!(
+        to_html("[x][A\\[B\\]C]\n\n[a\\[b\\]c]: d"),
+        "<p><a href=\"d\">x</a></p>\n",
+        "should support escaped brackets in the label of a full reference (case-insensitive)"
+    );
+
     assert_eq!(
         to_html("[x]: \\\"&#x20;\\(\\)\\\"\n\n[x]"),
         "<p><a href=\"%22%20()%22\">x</a></p>",
@@ -507,6 +597,89 @@ fn definition() -> Result<(), String> {
         "should support turning off definitions"
     );
 
+    let single_line_labels = Options {
+        parse: ParseOptions {
+            multiline_labels: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("[a\nb]: c\n\n[a b]"),
+        "<p><a href=\"c\">a b</a></p>",
+        "should support line endings in labels by default"
+    );
+
+    assert_eq!(
+        to_html("[a\nb]: <c> \"d\ne\"\n\n[a\nb]"),
+        "<p><a href=\"c\" title=\"d\ne\">a\nb</a></p>",
+        "should support line endings in labels and titles, and in references"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a\nb]: c\n\n[a b]", &single_line_labels)?,
+        "<p>[a\nb]: c</p>\n<p>[a b]</p>",
+        "should not support line endings in definition labels w/ `multiline_labels: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a b]: c\n\n[a\nb]", &single_line_labels)?,
+        "<p>[a\nb]</p>",
+        "should not support line endings in shortcut references w/ `multiline_labels: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a b]: c\n\n[d][a\nb]", &single_line_labels)?,
+        "<p>[d][a\nb]</p>",
+        "should not support line endings in full references w/ `multiline_labels: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a b]: c\n\n[a b]", &single_line_labels)?,
+        "<p><a href=\"c\">a b</a></p>",
+        "should support labels on one line w/ `multiline_labels: false`"
+    );
+
+    assert_eq!(
+        to_html("[a]: url"),
+        "",
+        "should not output unused definitions"
+    );
+
+    assert_eq!(
+        to_html("[a]: b \"c\"\n[d]: e\n"),
+        "",
+        "should not output several unused definitions, or their line endings"
+    );
+
+    assert_eq!(
+        to_html("x\n\n[a]: b\n\ny"),
+        "<p>x</p>\n<p>y</p>",
+        "should not leave stray text for unused definitions between paragraphs"
+    );
+
+    assert_eq!(
+        to_html("> [a]: b\n\n- [c]: d"),
+        "<blockquote>\n</blockquote>\n<ul>\n<li></li>\n</ul>",
+        "should not output unused definitions in containers"
+    );
+
+    assert_eq!(
+        to_mdast("[a]: b", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Definition(Definition {
+                url: "b".into(),
+                identifier: "a".into(),
+                label: Some("a".into()),
+                title: None,
+                position: Some(Position::new(1, 1, 0, 1, 7, 6))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 7, 6))
+        }),
+        "should keep unused definitions in mdast"
+    );
+
     assert_eq!(
         to_mdast("[a]: <b> 'c'", &Default::default())?,
         Node::Root(Root {
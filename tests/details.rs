@@ -0,0 +1,210 @@
+use markdown::{
+    mdast::{MdxJsxFlowElement, Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn details() -> Result<(), String> {
+    let details = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                details: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html(">>> a\nb\n<<<"),
+        "<blockquote>\n<blockquote>\n<blockquote>\n<p>a\nb\n&lt;&lt;&lt;</p>\n</blockquote>\n</blockquote>\n</blockquote>",
+        "should not support details by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\nb\n<<<", &details)?,
+        "<details>\n<summary>a</summary>\n<p>b</p>\n</details>",
+        "should support details"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a *b* \\& &amp;\nc\n<<<", &details)?,
+        "<details>\n<summary>a <em>b</em> &amp; &amp;</summary>\n<p>c</p>\n</details>",
+        "should support text in the summary"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>\na\n<<<", &details)?,
+        "<details>\n<p>a</p>\n</details>",
+        "should support details w/o summary"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\n<<<", &details)?,
+        "<details>\n<summary>a</summary>\n</details>",
+        "should support empty details"
+    );
+
+    assert_eq!(
+        to_html_with_options(">> a", &details)?,
+        "<blockquote>\n<blockquote>\n<p>a</p>\n</blockquote>\n</blockquote>",
+        "should not support details w/ less than 3 markers"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\nb\n\n# c\n\n- d\n\n> e\n<<<\nf", &details)?,
+        "<details>\n<summary>a</summary>\n<p>b</p>\n<h1>c</h1>\n<ul>\n<li>d</li>\n</ul>\n<blockquote>\n<p>e</p>\n</blockquote>\n</details>\n<p>f</p>",
+        "should support flow and containers in details"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n>>> b\nc\n<<<", &details)?,
+        "<p>a</p>\n<details>\n<summary>b</summary>\n<p>c</p>\n</details>",
+        "should support details interrupting a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\nb", &details)?,
+        "<details>\n<summary>a</summary>\n<p>b</p>\n</details>",
+        "should close unclosed details at the end of the document"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\n<<< b\n<<<", &details)?,
+        "<details>\n<summary>a</summary>\n<p>&lt;&lt;&lt; b</p>\n</details>",
+        "should not support content after a closing fence"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\n<<<<<  \nb", &details)?,
+        "<details>\n<summary>a</summary>\n</details>\n<p>b</p>",
+        "should support longer closing fences, and whitespace after them"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>> a\n<<<\n<<<<", &details)?,
+        "<details>\n<summary>a</summary>\n<p>&lt;&lt;&lt;</p>\n</details>",
+        "should not support shorter closing fences"
+    );
+
+    assert_eq!(
+        to_html_with_options("   >>> a\n   b\n   <<<", &details)?,
+        "<details>\n<summary>a</summary>\n<p>b</p>\n</details>",
+        "should support indented fences"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\n    <<<\n<<<", &details)?,
+        "<details>\n<summary>a</summary>\n<pre><code>&lt;&lt;&lt;\n</code></pre>\n</details>",
+        "should not support closing fences indented 4 spaces"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\r\nb\r\n<<<\r\nc", &details)?,
+        "<details>\r\n<summary>a</summary>\r\n<p>b</p>\r\n</details>\r\n<p>c</p>",
+        "should support carriage return + line feeds"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\n>>> b\nc\n<<<\nd\n<<<", &details)?,
+        "<details>\n<summary>a</summary>\n<details>\n<summary>b</summary>\n<p>c</p>\n</details>\n</details>\n<p>d\n&lt;&lt;&lt;</p>",
+        "should close nested details with the same fence together"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>> a\n>>> b\nc\n<<<\nd\n<<<<\ne", &details)?,
+        "<details>\n<summary>a</summary>\n<details>\n<summary>b</summary>\n<p>c</p>\n</details>\n<p>d</p>\n</details>\n<p>e</p>",
+        "should support nested details (longer outer fence)"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>> a\n>>> b\nc\n<<<<\nd", &details)?,
+        "<details>\n<summary>a</summary>\n<details>\n<summary>b</summary>\n<p>c</p>\n</details>\n</details>\n<p>d</p>",
+        "should close outer details with a fence long enough for them"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>>> a\n>>> b\nc\n<<<<\nd\n<<<<<", &details)?,
+        "<details>\n<summary>a</summary>\n<details>\n<summary>b</summary>\n<p>c</p>\n</details>\n<p>d</p>\n</details>",
+        "should not close outer details with a fence shorter than theirs"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>> a\n> >>> b\n> c\n> <<<<\nd", &details)?,
+        "<details>\n<summary>a</summary>\n<blockquote>\n<details>\n<summary>b</summary>\n<p>c</p>\n</details>\n</blockquote>\n<p>d</p>\n</details>",
+        "should not close details outside other containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("> >>> a\n> b\n> <<<\n> c", &details)?,
+        "<blockquote>\n<details>\n<summary>a</summary>\n<p>b</p>\n</details>\n<p>c</p>\n</blockquote>",
+        "should support details in block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("- >>> a\n  b\n  <<<\n- c", &details)?,
+        "<ul>\n<li>\n<details>\n<summary>a</summary>\n<p>b</p>\n</details>\n</li>\n<li>\n<p>c</p>\n</li>\n</ul>",
+        "should support details in list items"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\n```\n<<<\n```\n<<<", &details)?,
+        "<details>\n<summary>a</summary>\n<pre><code>&lt;&lt;&lt;\n</code></pre>\n</details>",
+        "should not close details in code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\n<div>\n<<<\n\n<<<", &details)?,
+        "<details>\n<summary>a</summary>\n&lt;div&gt;\n&lt;&lt;&lt;\n</details>",
+        "should not close details in html (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\n```\nb\n```\n<<<\nc", &details)?,
+        "<details>\n<summary>a</summary>\n<pre><code>b\n</code></pre>\n</details>\n<p>c</p>",
+        "should close details after closed code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\n> ```\n<<<", &details)?,
+        "<details>\n<summary>a</summary>\n<blockquote>\n<pre><code></code></pre>\n</blockquote>\n</details>",
+        "should close details when code (fenced) in an inner container is not continued"
+    );
+
+    assert_eq!(
+        to_mdast(">>> a\nb\n<<<", &details.parse)?,
+        Node::Root(Root {
+            children: vec![Node::MdxJsxFlowElement(MdxJsxFlowElement {
+                name: Some("details".into()),
+                attributes: vec![],
+                children: vec![
+                    Node::MdxJsxFlowElement(MdxJsxFlowElement {
+                        name: Some("summary".into()),
+                        attributes: vec![],
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(1, 5, 4, 1, 6, 5))
+                        })],
+                        position: Some(Position::new(1, 5, 4, 1, 6, 5))
+                    }),
+                    Node::Paragraph(Paragraph {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(2, 1, 6, 2, 2, 7))
+                        })],
+                        position: Some(Position::new(2, 1, 6, 2, 2, 7))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 3, 4, 11))
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 4, 11))
+        }),
+        "should support details as `<details>` and `<summary>` elements in mdast"
+    );
+
+    Ok(())
+}
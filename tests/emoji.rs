@@ -0,0 +1,134 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn emoji() -> Result<(), String> {
+    let emoji = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                emoji: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html(":smile:"),
+        "<p>:smile:</p>",
+        "should not support emoji by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(":smile:", &emoji)?,
+        "<p>😄</p>",
+        "should support emoji"
+    );
+
+    assert_eq!(
+        to_html_with_options(":+1: :-1: :100:", &emoji)?,
+        "<p>👍 👎 💯</p>",
+        "should support emoji w/ `+`, `-`, and digits"
+    );
+
+    assert_eq!(
+        to_html_with_options(":nope:", &emoji)?,
+        "<p>:nope:</p>",
+        "should not support unknown shortcodes"
+    );
+
+    assert_eq!(
+        to_html_with_options(":Smile:", &emoji)?,
+        "<p>:Smile:</p>",
+        "should not support shortcodes in a different case"
+    );
+
+    assert_eq!(
+        to_html_with_options("`:smile:`", &emoji)?,
+        "<p><code>:smile:</code></p>",
+        "should not support emoji in code (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\n:smile:\n```", &emoji)?,
+        "<pre><code>:smile:\n</code></pre>",
+        "should not support emoji in code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\:smile:", &emoji)?,
+        "<p>:smile:</p>",
+        "should not support an escaped emoji"
+    );
+
+    assert_eq!(
+        to_html_with_options(":smile", &emoji)?,
+        "<p>:smile</p>",
+        "should not support a missing closing colon"
+    );
+
+    assert_eq!(
+        to_html_with_options(": smile:", &emoji)?,
+        "<p>: smile:</p>",
+        "should not support whitespace in shortcodes"
+    );
+
+    assert_eq!(
+        to_html_with_options("::", &emoji)?,
+        "<p>::</p>",
+        "should not support empty shortcodes"
+    );
+
+    assert_eq!(
+        to_html_with_options("::smile:", &emoji)?,
+        "<p>:😄</p>",
+        "should support emoji after a colon"
+    );
+
+    assert_eq!(
+        to_html_with_options(":smile::+1:", &emoji)?,
+        "<p>😄👍</p>",
+        "should support adjacent emoji"
+    );
+
+    assert_eq!(
+        to_html_with_options("a:smile:b", &emoji)?,
+        "<p>a😄b</p>",
+        "should support emoji between letters"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :nope: :smile: b", &emoji)?,
+        "<p>a :nope: 😄 b</p>",
+        "should support emoji after unknown shortcodes"
+    );
+
+    assert_eq!(
+        to_html_with_options("[:tada:](b) ![:tada:](c)", &emoji)?,
+        "<p><a href=\"b\">🎉</a> <img src=\"c\" alt=\"🎉\" /></p>",
+        "should support emoji in links and images"
+    );
+
+    assert_eq!(
+        to_mdast("a :smile: b", &emoji.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a 😄 b".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 12, 11))
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 12, 11))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 12, 11))
+        }),
+        "should support emoji as text in mdast"
+    );
+
+    Ok(())
+}
@@ -1,8 +1,9 @@
 use markdown::{
+    frontmatter as get_frontmatter,
     mdast::{Node, Root, Toml, Yaml},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    Constructs, Frontmatter, FrontmatterKind, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -103,6 +104,44 @@ fn frontmatter() -> Result<(), String> {
         "should not support frontmatter after content"
     );
 
+    assert_eq!(
+        to_html_with_options("---\ntitle: Jupyter\n---\n\n---\ntitle: Saturn\n---", &frontmatter)?,
+        "<hr />\n<h2>title: Saturn</h2>",
+        "should not support a second frontmatter (yaml): it’s a thematic break and a heading (setext)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "+++\ntitle = \"Jupyter\"\n+++\n\n+++\ntitle = \"Saturn\"\n+++",
+            &frontmatter
+        )?,
+        "<p>+++\ntitle = &quot;Saturn&quot;\n+++</p>",
+        "should not support a second frontmatter (toml)"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\ntitle: Jupyter\n---\n\nNeptune\nSaturn", &frontmatter)?,
+        "<p>Neptune\nSaturn</p>",
+        "should support a blank line and then a paragraph w/ several lines after frontmatter"
+    );
+
+    assert_eq!(
+        to_html_with_options("\u{feff}---\ntitle: Jupyter\n---\n## Neptune", &frontmatter)?,
+        "<h2>Neptune</h2>",
+        "should support frontmatter after a byte order mark"
+    );
+
+    assert_eq!(
+        to_mdast("---\na\n---\n---\nb\n---", &frontmatter.parse)?
+            .children()
+            .map(|children| children
+                .iter()
+                .filter(|child| matches!(child, Node::Yaml(_)))
+                .count()),
+        Some(1),
+        "should only support one frontmatter in mdast"
+    );
+
     assert_eq!(
         to_html_with_options("> ---\n> ---\n> ## Neptune", &frontmatter)?,
         "<blockquote>\n<hr />\n<hr />\n<h2>Neptune</h2>\n</blockquote>",
@@ -151,5 +190,71 @@ fn frontmatter() -> Result<(), String> {
         "should support toml as `Toml`s in mdast"
     );
 
+    assert_eq!(
+        get_frontmatter(
+            "---\ntitle: Neptune\ntags: [a, b]\n---\n# Hi",
+            &frontmatter.parse
+        )?,
+        Some(Frontmatter {
+            kind: FrontmatterKind::Yaml,
+            value: "title: Neptune\ntags: [a, b]"
+        }),
+        "should support getting yaml frontmatter"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\ntitle: Neptune\ntags: [a, b]\n---\n# Hi", &frontmatter)?,
+        "<h1>Hi</h1>",
+        "should not include yaml frontmatter in html"
+    );
+
+    assert_eq!(
+        get_frontmatter("+++\r\ntitle = \"Jupyter\"\r\n+++", &frontmatter.parse)?,
+        Some(Frontmatter {
+            kind: FrontmatterKind::Toml,
+            value: "title = \"Jupyter\""
+        }),
+        "should support getting toml frontmatter"
+    );
+
+    assert_eq!(
+        get_frontmatter("---\n\na\n\nb\n\n---", &frontmatter.parse)?,
+        Some(Frontmatter {
+            kind: FrontmatterKind::Yaml,
+            value: "a\n\nb"
+        }),
+        "should support blank lines when getting frontmatter"
+    );
+
+    assert_eq!(
+        get_frontmatter("---\n\n  \na: b\n\t\n\n---", &frontmatter.parse)?,
+        Some(Frontmatter {
+            kind: FrontmatterKind::Yaml,
+            value: "  \na: b\n\t"
+        }),
+        "should trim empty lines, but not whitespace-only lines, at the start and end when getting frontmatter"
+    );
+
+    assert_eq!(
+        get_frontmatter("---\n---", &frontmatter.parse)?,
+        Some(Frontmatter {
+            kind: FrontmatterKind::Yaml,
+            value: ""
+        }),
+        "should support getting empty frontmatter"
+    );
+
+    assert_eq!(
+        get_frontmatter("# Hi\n\n---\na: b\n---", &frontmatter.parse)?,
+        None,
+        "should not get frontmatter that is not at the start"
+    );
+
+    assert_eq!(
+        get_frontmatter("---\na: b\n---", &ParseOptions::default())?,
+        None,
+        "should not get frontmatter if it is not enabled"
+    );
+
     Ok(())
 }
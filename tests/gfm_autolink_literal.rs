@@ -7,7 +7,7 @@ use markdown::{
     mdast::{Link, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Options, ParseOptions,
+    EventName, Markdown, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -45,6 +45,52 @@ fn gfm_autolink_literal() -> Result<(), String> {
         "should support email urls if enabled"
     );
 
+    assert_eq!(
+        to_html_with_options("foo(https://example.com)", &Options::gfm())?,
+        "<p>foo(<a href=\"https://example.com\">https://example.com</a>)</p>",
+        "should support protocol urls after a paren"
+    );
+    assert_eq!(
+        to_html_with_options("foo(www.example.com)", &Options::gfm())?,
+        "<p>foo(<a href=\"http://www.example.com\">www.example.com</a>)</p>",
+        "should support www urls after a paren"
+    );
+    assert_eq!(
+        to_html_with_options("xhttps://example.com", &Options::gfm())?,
+        "<p>xhttps://example.com</p>",
+        "should not support protocol urls after a letter"
+    );
+    assert_eq!(
+        to_html_with_options("xwww.example.com", &Options::gfm())?,
+        "<p>xwww.example.com</p>",
+        "should not support www urls after a letter"
+    );
+    assert_eq!(
+        to_html_with_options("1https://example.com", &Options::gfm())?,
+        "<p>1<a href=\"https://example.com\">https://example.com</a></p>",
+        "should support protocol urls after a digit (as in `cmark-gfm`)"
+    );
+    assert_eq!(
+        to_html_with_options("1www.example.com", &Options::gfm())?,
+        "<p>1www.example.com</p>",
+        "should not support www urls after a digit"
+    );
+    assert_eq!(
+        to_html_with_options("*https://example.com* _www.example.com_ ~https://example.com~", &Options::gfm())?,
+        "<p><em><a href=\"https://example.com\">https://example.com</a></em> <em><a href=\"http://www.example.com\">www.example.com</a></em> <del><a href=\"https://example.com\">https://example.com</a></del></p>",
+        "should support urls in attention"
+    );
+    assert_eq!(
+        to_html_with_options("*see https://example.com* **see https://example.com.**", &Options::gfm())?,
+        "<p><em>see <a href=\"https://example.com\">https://example.com</a></em> <strong>see <a href=\"https://example.com\">https://example.com</a>.</strong></p>",
+        "should support urls after other text in attention"
+    );
+    assert_eq!(
+        to_html_with_options("*a **https://example.com** b*", &Options::gfm())?,
+        "<p><em>a <strong><a href=\"https://example.com\">https://example.com</a></strong> b</em></p>",
+        "should support urls in nested attention"
+    );
+
     assert_eq!(
         to_html_with_options("[https://example.com](xxx)", &Options::gfm())?,
         "<p><a href=\"xxx\">https://example.com</a></p>",
@@ -60,6 +106,24 @@ fn gfm_autolink_literal() -> Result<(), String> {
         "<p><a href=\"xxx\">user@example.com</a></p>",
         "should not link email urls in links"
     );
+    assert_eq!(
+        to_html_with_options("[text https://example.com]", &Options::gfm())?,
+        "<p>[text <a href=\"https://example.com\">https://example.com</a>]</p>",
+        "should link urls in brackets that are not links"
+    );
+    assert_eq!(
+        to_html_with_options(
+            "[text https://example.com][]\n\n[text https://example.com]: xxx",
+            &Options::gfm()
+        )?,
+        "<p><a href=\"xxx\">text https://example.com</a></p>\n",
+        "should not link urls in references"
+    );
+    assert_eq!(
+        to_html_with_options("[*https://example.com*](xxx)", &Options::gfm())?,
+        "<p><a href=\"xxx\"><em>https://example.com</em></a></p>",
+        "should not link urls in attention in links"
+    );
 
     assert_eq!(
         to_html_with_options("user@example.com", &Options::gfm())?,
@@ -272,6 +336,53 @@ Email me at:scyther@pokemon.com"###,
         "should support `mailto:` and `xmpp:` protocols"
     );
 
+    assert_eq!(
+        to_html_with_options("mailto:a@b.com.", &Options::gfm())?,
+        "<p><a href=\"mailto:a@b.com\">mailto:a@b.com</a>.</p>",
+        "should not include a trailing dot in `mailto:`"
+    );
+
+    assert_eq!(
+        to_html_with_options("https://a.com.", &Options::gfm())?,
+        "<p><a href=\"https://a.com\">https://a.com</a>.</p>",
+        "should emit a trimmed trailing dot after the link"
+    );
+
+    assert_eq!(
+        to_html_with_options("https://a.com/b?c!\" www.d.com). e@f.com.", &Options::gfm())?,
+        "<p><a href=\"https://a.com/b?c\">https://a.com/b?c</a>!&quot; <a href=\"http://www.d.com\">www.d.com</a>). <a href=\"mailto:e@f.com\">e@f.com</a>.</p>",
+        "should emit trimmed trailing punctuation after the link, as text (encoded)"
+    );
+
+    assert_eq!(
+        to_html_with_options("(https://a.com/(b)) www.c.com/d*e*.", &Options::gfm())?,
+        "<p>(<a href=\"https://a.com/(b)\">https://a.com/(b)</a>) <a href=\"http://www.c.com/d*e\">www.c.com/d*e</a>*.</p>",
+        "should emit trimmed unbalanced parens and other trailing punctuation after the link"
+    );
+
+    assert_eq!(
+        to_html_with_options("xmpp:a@b.com", &Options::gfm())?,
+        "<p><a href=\"xmpp:a@b.com\">xmpp:a@b.com</a></p>",
+        "should support a plain `xmpp:`"
+    );
+
+    let markdown = Markdown::new("mailto:a@b.com. xmpp:c@d.com/e!", &ParseOptions::gfm())?;
+
+    assert_eq!(
+        markdown
+            .spans(&[
+                EventName::GfmAutolinkLiteralMailto,
+                EventName::GfmAutolinkLiteralXmpp
+            ])
+            .map(|span| (span.name().clone(), span.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            (EventName::GfmAutolinkLiteralMailto, "mailto:a@b.com"),
+            (EventName::GfmAutolinkLiteralXmpp, "xmpp:c@d.com/e")
+        ],
+        "should emit `mailto:` and `xmpp:` events, w/o trailing punctuation"
+    );
+
     assert_eq!(
         to_html_with_options(
             r###"
@@ -161,6 +161,48 @@ fn gfm_footnote() -> Result<(), String> {
         "should supports multiple calls to the same definition"
     );
 
+    assert_eq!(
+        to_html_with_options("a[^b]\n\n[^b]: c\n\n    d\n\n    - e\n    - f\n\ng", &Options::gfm())?,
+        "<p>a<sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>
+<p>g</p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-b\">
+<p>c</p>
+<p>d</p>
+<ul>
+<li>e</li>
+<li>f</li>
+</ul>
+<a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a>
+</li>
+</ol>
+</section>
+",
+        "should support several paragraphs and a nested list in a definition"
+    );
+
+    assert_eq!(
+        to_html_with_options("a[^b]\n\n[^b]: c\nd\n\n    e\n\n    > f\n    g", &Options::gfm())?,
+        "<p>a<sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-b\">
+<p>c
+d</p>
+<p>e</p>
+<blockquote>
+<p>f
+g</p>
+</blockquote>
+<a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a>
+</li>
+</ol>
+</section>
+",
+        "should support lazy lines and a block quote in a definition"
+    );
+
     assert_eq!(
         to_html_with_options("![^a](b)", &Options::gfm())?,
         "<p>!<a href=\"b\">^a</a></p>",
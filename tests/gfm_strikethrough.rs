@@ -7,7 +7,7 @@ use markdown::{
     mdast::{Delete, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Options, ParseOptions,
+    CompileOptions, Options, ParseOptions, StrikethroughElement,
 };
 use pretty_assertions::assert_eq;
 
@@ -393,6 +393,27 @@ u ~**xxx**~ zzz
         "should support strikethrough w/ one tilde if `singleTilde: true`"
     );
 
+    assert_eq!(
+        to_html_with_options("~~a~~", &Options::gfm())?,
+        "<p><del>a</del></p>",
+        "should use `<del>` by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "~~a~~ ~b~ ![~c~](d)",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    strikethrough_element: StrikethroughElement::S,
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<p><s>a</s> <s>b</s> <img src=\"d\" alt=\"c\" /></p>",
+        "should support `strikethrough_element: StrikethroughElement::S`"
+    );
+
     assert_eq!(
         to_mdast("a ~~alpha~~ b.", &ParseOptions::gfm())?,
         Node::Root(Root {
@@ -1,5 +1,5 @@
 use markdown::{
-    mdast::{AlignKind, InlineCode, Node, Root, Table, TableCell, TableRow, Text},
+    mdast::{AlignKind, Html, InlineCode, Node, Root, Table, TableCell, TableRow, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
     CompileOptions, Constructs, Options, ParseOptions,
@@ -1837,6 +1837,134 @@ normal escape: <a href="https://github.com/github/cmark-gfm/issues/277">https://
         "should match loose escapes like GitHub"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "| a |\n| - |\n| b |",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_table_class: Some("data".into()),
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<table class=\"data\">\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b</td>\n</tr>\n</tbody>\n</table>",
+        "should support a custom class on tables"
+    );
+
+    assert_eq!(
+        to_html_with_options("  | a | b |\n  | - | - |\n  | c | d |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>c</td>\n<td>d</td>\n</tr>\n</tbody>\n</table>",
+        "should support a table indented 2 spaces"
+    );
+
+    assert_eq!(
+        to_html_with_options("   |  a  |\n | :-: |\n|\tb\t|", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th align=\"center\">a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td align=\"center\">b</td>\n</tr>\n</tbody>\n</table>",
+        "should support rows indented differently, up to 3 spaces"
+    );
+
+    assert_eq!(
+        to_html_with_options("   a  |  b  \n   --- | ---   \n   c   |   d   \t", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>c</td>\n<td>d</td>\n</tr>\n</tbody>\n</table>",
+        "should trim whitespace around cells, w/o pipes at the edges"
+    );
+
+    assert_eq!(
+        to_html_with_options("    | a |\n    | - |", &Options::gfm())?,
+        "<pre><code>| a |\n| - |\n</code></pre>",
+        "should not support a table indented 4 spaces"
+    );
+
+    let colgroup = Options {
+        parse: ParseOptions::gfm(),
+        compile: CompileOptions {
+            gfm_table_colgroup: true,
+            ..CompileOptions::gfm()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options("| a | b | c | d |\n| :- | :-: | -: | - |\n| e |", &colgroup)?,
+        "<table>\n<colgroup>\n<col style=\"text-align: left\" />\n<col style=\"text-align: center\" />\n<col style=\"text-align: right\" />\n<col />\n</colgroup>\n<thead>\n<tr>\n<th align=\"left\">a</th>\n<th align=\"center\">b</th>\n<th align=\"right\">c</th>\n<th>d</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td align=\"left\">e</td>\n<td align=\"center\"></td>\n<td align=\"right\"></td>\n<td></td>\n</tr>\n</tbody>\n</table>",
+        "should support `gfm_table_colgroup` w/ mixed alignment"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "| a | b |\n| - | :-: |",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_table_colgroup: true,
+                    pretty: true,
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<table>\n  <colgroup>\n    <col />\n    <col style=\"text-align: center\" />\n  </colgroup>\n  <thead>\n    <tr>\n      <th>a</th>\n      <th align=\"center\">b</th>\n    </tr>\n  </thead>\n</table>",
+        "should support `gfm_table_colgroup` w/ `pretty`"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| b<br>c |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b&lt;br&gt;c</td>\n</tr>\n</tbody>\n</table>",
+        "should support `<br>` in cells as HTML (text), safe by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "| a |\n| - |\n| b<br>c<br/>d |",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    ..CompileOptions::gfm()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b<br>c<br/>d</td>\n</tr>\n</tbody>\n</table>",
+        "should support `<br>` in cells as HTML (text), w/ `allow_dangerous_html`"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| b\\\nc |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b\\</td>\n</tr>\n<tr>\n<td>c</td>\n</tr>\n</tbody>\n</table>",
+        "should not support hard breaks (escape) in cells, as rows are one line"
+    );
+
+    assert_eq!(
+        to_mdast("| a<br>b |\n| - |", &ParseOptions::gfm())?,
+        Node::Root(Root {
+            children: vec![Node::Table(Table {
+                align: vec![AlignKind::None],
+                children: vec![Node::TableRow(TableRow {
+                    children: vec![Node::TableCell(TableCell {
+                        children: vec![
+                            Node::Text(Text {
+                                value: "a".into(),
+                                position: Some(Position::new(1, 3, 2, 1, 4, 3))
+                            }),
+                            Node::Html(Html {
+                                value: "<br>".into(),
+                                position: Some(Position::new(1, 4, 3, 1, 8, 7))
+                            }),
+                            Node::Text(Text {
+                                value: "b".into(),
+                                position: Some(Position::new(1, 8, 7, 1, 9, 8))
+                            }),
+                        ],
+                        position: Some(Position::new(1, 1, 0, 1, 11, 10))
+                    }),],
+                    position: Some(Position::new(1, 1, 0, 1, 11, 10))
+                }),],
+                position: Some(Position::new(1, 1, 0, 2, 6, 16))
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 6, 16))
+        }),
+        "should support `<br>` in cells as HTML (text) in mdast"
+    );
+
     assert_eq!(
         to_mdast(
             "| none | left | right | center |\n| - | :- | -: | :-: |\n| a |\n| b | c | d | e | f |",
@@ -53,6 +53,42 @@ fn gfm_task_list_item() -> Result<(), String> {
         "should not support laziness (2)"
     );
 
+    assert_eq!(
+        to_html_with_options("- a [x]", &Options::gfm())?,
+        "<ul>\n<li>a [x]</li>\n</ul>",
+        "should not support a check after text"
+    );
+
+    assert_eq!(
+        to_html_with_options("- *a* [x] b", &Options::gfm())?,
+        "<ul>\n<li><em>a</em> [x] b</li>\n</ul>",
+        "should not support a check after other inlines"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n  [x] b", &Options::gfm())?,
+        "<ul>\n<li>a\n[x] b</li>\n</ul>",
+        "should not support a check on a later line"
+    );
+
+    assert_eq!(
+        to_html_with_options("- [x]a", &Options::gfm())?,
+        "<ul>\n<li>[x]a</li>\n</ul>",
+        "should not support a check w/o whitespace after it"
+    );
+
+    assert_eq!(
+        to_html_with_options("- [x] [x] a", &Options::gfm())?,
+        "<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> [x] a</li>\n</ul>",
+        "should support only one check, at the start"
+    );
+
+    assert_eq!(
+        to_html_with_options("- \\[x] a", &Options::gfm())?,
+        "<ul>\n<li>[x] a</li>\n</ul>",
+        "should not support an escaped check"
+    );
+
     assert_eq!(
         to_html_with_options(
             &r###"
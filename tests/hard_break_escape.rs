@@ -62,6 +62,36 @@ fn hard_break_escape() -> Result<(), String> {
         "should support turning off hard break (escape)"
     );
 
+    assert_eq!(
+        to_html("a\\"),
+        "<p>a\\</p>",
+        "should not support a backslash at the end of the document"
+    );
+
+    assert_eq!(
+        to_html("a\\\n"),
+        "<p>a\\</p>\n",
+        "should not support a backslash before a final line ending"
+    );
+
+    assert_eq!(
+        to_html("a\\\n\nb"),
+        "<p>a\\</p>\n<p>b</p>",
+        "should not support a backslash before a blank line"
+    );
+
+    assert_eq!(
+        to_html("a\\\\\n"),
+        "<p>a\\</p>\n",
+        "should support an escaped backslash before a final line ending"
+    );
+
+    assert_eq!(
+        to_html("a\\\\\nb"),
+        "<p>a\\\nb</p>",
+        "should not support an escaped backslash as a hard break"
+    );
+
     assert_eq!(
         to_mdast("a\\\nb.", &Default::default())?,
         Node::Root(Root {
@@ -110,6 +110,54 @@ fn hard_break_trailing() -> Result<(), String> {
         "should support a mixed line suffix after a span (3)"
     );
 
+    assert_eq!(
+        to_html("a\\ \nb"),
+        "<p>a\\\nb</p>",
+        "should not support an escaped space (1 space after a backslash)"
+    );
+
+    assert_eq!(
+        to_html("a\\  \nb"),
+        "<p>a\\<br />\nb</p>",
+        "should support 2 spaces after a backslash"
+    );
+
+    assert_eq!(
+        to_html("a\\\t\nb"),
+        "<p>a\\\nb</p>",
+        "should not support a tab after a backslash"
+    );
+
+    assert_eq!(
+        to_html("a \\ \nb"),
+        "<p>a \\\nb</p>",
+        "should not support a space, a backslash, and a space"
+    );
+
+    assert_eq!(
+        to_html("a \\\nb"),
+        "<p>a <br />\nb</p>",
+        "should support a space before a hard break (escape)"
+    );
+
+    assert_eq!(
+        to_html("a\\\\  \nb"),
+        "<p>a\\<br />\nb</p>",
+        "should support 2 spaces after an escaped backslash"
+    );
+
+    assert_eq!(
+        to_html("a\\\\\nb"),
+        "<p>a\\\nb</p>",
+        "should not support an escaped backslash as a hard break (escape)"
+    );
+
+    assert_eq!(
+        to_html("a\\\\\\\nb"),
+        "<p>a\\<br />\nb</p>",
+        "should support a hard break (escape) after an escaped backslash"
+    );
+
     assert_eq!(
         to_html_with_options(
             "a  \nb",
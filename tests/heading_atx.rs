@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Heading, Node, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, EventName, Markdown, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -224,6 +224,169 @@ fn heading_atx() -> Result<(), String> {
         "should support turning off heading (atx)"
     );
 
+    assert_eq!(
+        to_html("#  foo  "),
+        "<h1>foo</h1>",
+        "should trim whitespace around the text"
+    );
+
+    assert_eq!(
+        to_html("#   "),
+        "<h1></h1>",
+        "should support an empty heading with only whitespace"
+    );
+
+    let keep = ParseOptions {
+        heading_atx_trailing_whitespace: true,
+        ..Default::default()
+    };
+
+    let texts = |value: &str, options: &ParseOptions| -> Result<Vec<String>, String> {
+        Ok(Markdown::new(value, options)?
+            .spans(&[EventName::HeadingAtxText])
+            .map(|span| span.as_str().to_string())
+            .collect())
+    };
+
+    assert_eq!(
+        texts("#  foo  \n## bar\t##", &Default::default())?,
+        vec!["foo", "bar"],
+        "should not include trailing whitespace in the text by default"
+    );
+
+    assert_eq!(
+        texts("#  foo  \n## bar\t##\n### baz", &keep)?,
+        vec!["foo  ", "bar\t", "baz"],
+        "should include trailing whitespace in the text w/ `heading_atx_trailing_whitespace`"
+    );
+
+    assert_eq!(
+        texts("#   \n## ##", &keep)?,
+        Vec::<String>::new(),
+        "should not turn whitespace into text w/ `heading_atx_trailing_whitespace`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "#  foo  \n#   ",
+            &Options {
+                parse: keep,
+                ..Default::default()
+            }
+        )?,
+        "<h1>foo</h1>\n<h1></h1>",
+        "should not change the HTML w/ `heading_atx_trailing_whitespace`"
+    );
+
+    let headline = Options {
+        compile: CompileOptions {
+            attributes: vec![(EventName::HeadingAtx, "itemprop".into(), "headline".into())],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("# alpha", &headline)?,
+        "<h1 itemprop=\"headline\">alpha</h1>",
+        "should support extra attributes on headings (atx)"
+    );
+
+    assert_eq!(
+        to_html_with_options("alpha\n=====\n\nbravo", &headline)?,
+        "<h1>alpha</h1>\n<p>bravo</p>",
+        "should not add extra attributes for other names"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "## alpha",
+            &Options {
+                compile: CompileOptions {
+                    attributes: vec![
+                        (EventName::HeadingAtx, "property".into(), "name".into()),
+                        (EventName::HeadingAtx, "data-a".into(), "\"b\" & c".into()),
+                    ],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h2 property=\"name\" data-a=\"&quot;b&quot; &amp; c\">alpha</h2>",
+        "should support several extra attributes, and encode their values"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# alpha",
+            &Options {
+                compile: CompileOptions {
+                    attributes: vec![
+                        (EventName::HeadingAtx, "a onclick".into(), "b".into()),
+                        (EventName::HeadingAtx, "c=\"d\"".into(), "e".into()),
+                        (EventName::HeadingAtx, "f>".into(), "g".into()),
+                        (EventName::HeadingAtx, "".into(), "h".into()),
+                        (EventName::HeadingAtx, "data-i".into(), "j".into()),
+                    ],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1 data-i=\"j\">alpha</h1>",
+        "should drop extra attributes w/ invalid names"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# alpha",
+            &Options {
+                compile: CompileOptions {
+                    attributes: vec![
+                        (EventName::HeadingAtx, "class".into(), "a".into()),
+                        (EventName::HeadingAtx, "CLASS".into(), "b".into()),
+                    ],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1 class=\"a b\">alpha</h1>",
+        "should merge extra `class` attributes"
+    );
+
+    let offset = |heading_offset| Options {
+        compile: CompileOptions {
+            heading_offset,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("# a", &offset(1))?,
+        "<h2>a</h2>",
+        "should support `heading_offset`"
+    );
+
+    assert_eq!(
+        to_html_with_options("###### b", &offset(1))?,
+        "<h6>b</h6>",
+        "should clamp `heading_offset` to a rank of 6"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a\n\n### b", &offset(-2))?,
+        "<h1>a</h1>\n<h1>b</h1>",
+        "should support a negative `heading_offset`, clamped to a rank of 1"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a", &offset(i8::MAX))?,
+        "<h6>a</h6>",
+        "should support a huge `heading_offset`"
+    );
+
     assert_eq!(
         to_mdast("## alpha #", &Default::default())?,
         Node::Root(Root {
@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Heading, Node, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -297,6 +297,80 @@ fn heading_setext() -> Result<(), String> {
         "should support turning off setext underlines"
     );
 
+    let setext_off = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                heading_setext: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a\n===", &setext_off)?,
+        "<p>a\n===</p>",
+        "should support `=` underlines as paragraph text w/o setext headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n---", &setext_off)?,
+        "<p>a</p>\n<hr />",
+        "should support `-` underlines as thematic breaks w/o setext headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> ===\n> b\n> ---", &setext_off)?,
+        "<blockquote>\n<p>a\n===\nb</p>\n<hr />\n</blockquote>",
+        "should support underlines in containers w/o setext headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n\nb\n===", &setext_off)?,
+        "<h1>a</h1>\n<p>b\n===</p>",
+        "should support atx headings w/o setext headings"
+    );
+
+    assert_eq!(
+        to_html("a\n        ="),
+        "<p>a\n=</p>",
+        "should not support an underline indented 4+ spaces w/ indented code"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\n        =",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        code_indented: false,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1>a</h1>",
+        "should support an underline indented 4+ spaces w/o indented code"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\n=\n\nb\n-",
+            &Options {
+                compile: CompileOptions {
+                    heading_offset: 1,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h2>a</h2>\n<h3>b</h3>",
+        "should support `heading_offset`"
+    );
+
     assert_eq!(
         to_mdast("alpha\nbravo\n==", &Default::default())?,
         Node::Root(Root {
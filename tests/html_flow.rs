@@ -1,8 +1,8 @@
 use markdown::{
-    mdast::{Html, Node, Root},
+    mdast::{Html, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, EventName, Markdown, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -59,6 +59,121 @@ fn html_flow() -> Result<(), String> {
         "should support HTML (flow) as `Html`s in mdast"
     );
 
+    let markdown = Markdown::new("<div>\r\n*a*\r\nb\r\n</div>\r\n\r\nc", &Default::default())?;
+    let points = markdown
+        .events()
+        .iter()
+        .filter(|event| {
+            matches!(
+                event.name,
+                EventName::HtmlFlow | EventName::HtmlFlowData | EventName::LineEnding
+            )
+        })
+        .map(|event| (event.point.line, event.point.column, event.point.index))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        points,
+        vec![
+            (1, 1, 0),
+            (1, 1, 0),
+            (1, 6, 5),
+            (1, 6, 5),
+            (2, 1, 7),
+            (2, 1, 7),
+            (2, 4, 10),
+            (2, 4, 10),
+            (3, 1, 12),
+            (3, 1, 12),
+            (3, 2, 13),
+            (3, 2, 13),
+            (4, 1, 15),
+            (4, 1, 15),
+            (4, 7, 21),
+            (4, 7, 21),
+            (4, 7, 21),
+            (5, 1, 23)
+        ],
+        "should support positions of chunks and line endings in HTML (flow) w/ carriage return + line feeds"
+    );
+
+    assert_eq!(
+        to_mdast("<div>\r\n*a*\r\nb\r\n</div>\r\n\r\nc", &Default::default())?,
+        Node::Root(Root {
+            children: vec![
+                Node::Html(Html {
+                    value: "<div>\r\n*a*\r\nb\r\n</div>".into(),
+                    position: Some(Position::new(1, 1, 0, 4, 7, 21))
+                }),
+                Node::Paragraph(Paragraph {
+                    children: vec![Node::Text(Text {
+                        value: "c".into(),
+                        position: Some(Position::new(6, 1, 25, 6, 2, 26))
+                    })],
+                    position: Some(Position::new(6, 1, 25, 6, 2, 26))
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 6, 2, 26))
+        }),
+        "should support HTML (flow) w/ carriage return + line feeds as `Html`s in mdast, ending on the last line"
+    );
+
+    // Get the source of each HTML (flow).
+    let html_flows = |value: &str| -> Result<Vec<String>, String> {
+        Ok(Markdown::new(value, &Default::default())?
+            .spans(&[EventName::HtmlFlow])
+            .map(|span| span.as_str().into())
+            .collect())
+    };
+
+    assert_eq!(
+        html_flows("<pre>\na\n\nb\n</pre>c\nd")?,
+        vec!["<pre>\na\n\nb\n</pre>c"],
+        "should end HTML (flow, 1: raw) at the end of the line w/ the closing tag, not at blank lines"
+    );
+
+    assert_eq!(
+        html_flows("<!-- a\n\nb -->c\nd")?,
+        vec!["<!-- a\n\nb -->c"],
+        "should end HTML (flow, 2: comment) at the end of the line w/ `-->`, not at blank lines"
+    );
+
+    assert_eq!(
+        html_flows("<?a\n\nb ?>c\nd")?,
+        vec!["<?a\n\nb ?>c"],
+        "should end HTML (flow, 3: instruction) at the end of the line w/ `?>`, not at blank lines"
+    );
+
+    assert_eq!(
+        html_flows("<!A\n\nb>c\nd")?,
+        vec!["<!A\n\nb>c"],
+        "should end HTML (flow, 4: declaration) at the end of the line w/ `>`, not at blank lines"
+    );
+
+    assert_eq!(
+        html_flows("<![CDATA[\na\n\nb]]>c\nd")?,
+        vec!["<![CDATA[\na\n\nb]]>c"],
+        "should end HTML (flow, 5: cdata) at the end of the line w/ `]]>`, not at blank lines"
+    );
+
+    assert_eq!(
+        html_flows("<div>\na\n</div>\nb\n\nc")?,
+        vec!["<div>\na\n</div>\nb"],
+        "should end HTML (flow, 6: basic) before a blank line, not at the closing tag"
+    );
+
+    assert_eq!(
+        html_flows("<x-y>\na\n</x-y>\nb\n\nc")?,
+        vec!["<x-y>\na\n</x-y>\nb"],
+        "should end HTML (flow, 7: complete) before a blank line, not at the closing tag"
+    );
+
+    assert_eq!(
+        html_flows("<div>\na\n\n<!--\n\n-->\n\n<pre>\n")?,
+        vec!["<div>\na", "<!--\n\n-->", "<pre>\n"],
+        "should support several HTML (flow), and HTML (flow) that runs to the end"
+    );
+
     Ok(())
 }
 
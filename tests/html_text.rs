@@ -444,6 +444,45 @@ to_html_with_options("<x> a", &danger)?,
         "should support turning off html (text)"
     );
 
+    assert_eq!(
+        to_html_with_options("a < b", &danger)?,
+        "<p>a &lt; b</p>",
+        "should not support a less than followed by whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <foo> b", &danger)?,
+        "<p>a <foo> b</p>",
+        "should support a tag between text"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <3 b", &danger)?,
+        "<p>a &lt;3 b</p>",
+        "should not support a less than followed by a digit"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <> b", &danger)?,
+        "<p>a &lt;&gt; b</p>",
+        "should not support an empty tag"
+    );
+
+    assert_eq!(
+        to_mdast("a <3 b", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a <3 b".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 7, 6))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 7, 6))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 7, 6))
+        }),
+        "should support a literal less than as text in mdast"
+    );
+
     assert_eq!(
         to_mdast("alpha <i>bravo</b> charlie.", &Default::default())?,
         Node::Root(Root {
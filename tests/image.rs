@@ -235,6 +235,175 @@ fn image() -> Result<(), String> {
         "should allow non-http protocols w/ `allowDangerousProtocol`"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "![a](b \"c\") [d](e) ![f][g]\n\n[g]: h",
+            &Options {
+                compile: CompileOptions {
+                    image_lazy_loading: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><img src=\"b\" alt=\"a\" title=\"c\" loading=\"lazy\" /> <a href=\"e\">d</a> <img src=\"h\" alt=\"f\" loading=\"lazy\" /></p>\n",
+        "should support `image_lazy_loading` on images (resources and references), not on links"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a](b) [c](d)",
+            &Options {
+                compile: CompileOptions {
+                    image_async_decoding: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><img src=\"b\" alt=\"a\" decoding=\"async\" /> <a href=\"d\">c</a></p>",
+        "should support `image_async_decoding` on images, not on links"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a ![b](c)](d)",
+            &Options {
+                compile: CompileOptions {
+                    image_async_decoding: true,
+                    image_lazy_loading: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><img src=\"d\" alt=\"a b\" loading=\"lazy\" decoding=\"async\" /></p>",
+        "should support `image_lazy_loading` and `image_async_decoding` together (and not in `alt`)"
+    );
+
+    let omit_empty_alt = Options {
+        compile: CompileOptions {
+            image_omit_empty_alt: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("![](a.png)"),
+        "<p><img src=\"a.png\" alt=\"\" /></p>",
+        "should add an empty `alt` by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("![](a.png)", &omit_empty_alt)?,
+        "<p><img src=\"a.png\" /></p>",
+        "should support `image_omit_empty_alt`"
+    );
+
+    assert_eq!(
+        to_html_with_options("![b](a.png \"c\") ![][d]\n\n[d]: e", &omit_empty_alt)?,
+        "<p><img src=\"a.png\" alt=\"b\" title=\"c\" /> <img src=\"e\" /></p>\n",
+        "should support `image_omit_empty_alt` w/ references, and not drop nonempty alts"
+    );
+
+    let resolver = Options {
+        compile: CompileOptions {
+            image_src_resolver: Some(|destination| {
+                if destination == "a.png" {
+                    Some("data:image/png;base64,iVBORw0KGgo=".into())
+                } else {
+                    None
+                }
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("![a](a.png) ![b](b.png)", &resolver)?,
+        "<p><img src=\"data:image/png;base64,iVBORw0KGgo=\" alt=\"a\" /> <img src=\"b.png\" alt=\"b\" /></p>",
+        "should support `image_src_resolver`, w/ `None` to keep the destination"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a][b]\n\n[b]: <a&#x2e;png>", &resolver)?,
+        "<p><img src=\"data:image/png;base64,iVBORw0KGgo=\" alt=\"a\" /></p>\n",
+        "should support `image_src_resolver` on references, w/ decoded destinations"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](a.png)", &resolver)?,
+        "<p><a href=\"a.png\">a</a></p>",
+        "should not support `image_src_resolver` on links"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a](b)",
+            &Options {
+                compile: CompileOptions {
+                    image_src_resolver: Some(|_| Some("c d\"".into())),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><img src=\"c%20d%22\" alt=\"a\" /></p>",
+        "should encode sources from `image_src_resolver`"
+    );
+
+    let figures = Options {
+        compile: CompileOptions {
+            images_as_figures: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("![a](b \"c\")", &figures)?,
+        "<figure><img src=\"b\" alt=\"a\" title=\"c\" /><figcaption>c</figcaption></figure>",
+        "should support a lone image as a figure w/ `images_as_figures`"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b)", &figures)?,
+        "<figure><img src=\"b\" alt=\"a\" /></figure>",
+        "should not add a caption to a figure w/o title"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a][b]\n\n[b]: c \"d&e\"", &figures)?,
+        "<figure><img src=\"c\" alt=\"a\" title=\"d&amp;e\" /><figcaption>d&amp;e</figcaption></figure>\n",
+        "should support a lone image reference as a figure"
+    );
+
+    assert_eq!(
+        to_html_with_options("  ![a](b) ", &figures)?,
+        "<figure><img src=\"b\" alt=\"a\" /></figure>",
+        "should support whitespace around a lone image"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ![b](c \"d\") e", &figures)?,
+        "<p>a <img src=\"c\" alt=\"b\" title=\"d\" /> e</p>",
+        "should not support an image with text around it as a figure"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b)![c](d)", &figures)?,
+        "<p><img src=\"b\" alt=\"a\" /><img src=\"d\" alt=\"c\" /></p>",
+        "should not support several images as a figure"
+    );
+
+    assert_eq!(
+        to_html_with_options("- ![a](b)", &figures)?,
+        "<ul>\n<li><img src=\"b\" alt=\"a\" /></li>\n</ul>",
+        "should not support a lone image in a tight list item as a figure"
+    );
+
     assert_eq!(
         to_mdast(
             "a ![alpha]() b ![bravo](charlie 'delta') c.",
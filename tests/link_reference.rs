@@ -383,6 +383,65 @@ fn link_reference() -> Result<(), String> {
         "should not support 1000 characters in a reference"
     );
 
+    // 499 escapes (`\]`) and `x` is 999 characters.
+    let escapes = "\\]".repeat(499);
+
+    assert_eq!(
+        to_html(format!("[{}x]: a\n[y][{}x]", escapes, escapes).as_str()),
+        "<p><a href=\"a\">y</a></p>",
+        "should count escapes as two characters in a reference (999)"
+    );
+
+    assert_eq!(
+        to_html(format!("[{}\\]]: a\n[y][{}\\]]", escapes, escapes).as_str()),
+        format!(
+            "<p>[{}]]: a\n[y][{}]]</p>",
+            "]".repeat(499),
+            "]".repeat(499)
+        ),
+        "should count escapes as two characters in a reference (1000)"
+    );
+
+    assert_eq!(
+        to_html(format!("[a]: b\n\n[a{}]", " ".repeat(998)).as_str()),
+        format!("<p><a href=\"b\">a{}</a></p>", " ".repeat(998)),
+        "should support 999 characters in a shortcut reference"
+    );
+
+    assert_eq!(
+        to_html(format!("[a]: b\n\n[a{}]", " ".repeat(999)).as_str()),
+        format!("<p>[a{}]</p>", " ".repeat(999)),
+        "should not support 1000 characters in a shortcut reference"
+    );
+
+    let nested = format!("{}a{}", "[".repeat(50_000), "]".repeat(50_000));
+
+    assert_eq!(
+        to_html(&nested),
+        format!("<p>{}</p>", nested),
+        "should support deeply nested brackets"
+    );
+
+    assert_eq!(
+        to_html(&"![".repeat(50_000)),
+        format!("<p>{}</p>", "![".repeat(50_000)),
+        "should support many image starts"
+    );
+
+    assert_eq!(
+        to_html(&format!(
+            "{}{}",
+            "[".repeat(50_000),
+            "[a](b)".repeat(50_000)
+        )),
+        format!(
+            "<p>{}{}</p>",
+            "[".repeat(50_000),
+            "<a href=\"b\">a</a>".repeat(50_000)
+        ),
+        "should support many links after many brackets"
+    );
+
     assert_eq!(
         to_html("[x] missing-colon\n\nWill it link? [x]"),
         "<p>[x] missing-colon</p>\n<p>Will it link? [x]</p>",
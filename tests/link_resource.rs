@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Image, Link, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Options,
+    CompileOptions, EventName, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -29,6 +29,18 @@ fn link_resource() -> Result<(), String> {
         "should support links w/o title"
     );
 
+    assert_eq!(
+        to_html("[a](b \"he said \\\"hi\\\" &amp; bye\")"),
+        "<p><a href=\"b\" title=\"he said &quot;hi&quot; &amp; bye\">a</a></p>",
+        "should support character escapes and references in titles"
+    );
+
+    assert_eq!(
+        to_html("![a](b 'it\\'s &quot;c&quot;')"),
+        "<p><img src=\"b\" alt=\"a\" title=\"it's &quot;c&quot;\" /></p>",
+        "should support character escapes and references in image titles"
+    );
+
     assert_eq!(
         to_html("[link]()"),
         "<p><a href=\"\">link</a></p>",
@@ -453,6 +465,18 @@ fn link_resource() -> Result<(), String> {
         "should support an eol at the start of a title"
     );
 
+    assert_eq!(
+        to_html("[a](b \"c\\\nd\")"),
+        "<p><a href=\"b\" title=\"c\\\nd\">a</a></p>",
+        "should not support a hard break (escape) in a title"
+    );
+
+    assert_eq!(
+        to_html("[a\\\nb](c)"),
+        "<p><a href=\"c\">a<br />\nb</a></p>",
+        "should support a hard break (escape) in link text"
+    );
+
     assert_eq!(
         to_html("[a](b( \"c\")"),
         "<p>[a](b( &quot;c&quot;)</p>",
@@ -465,6 +489,387 @@ fn link_resource() -> Result<(), String> {
         "should support a single NUL character as a link resource"
     );
 
+    let external = Options {
+        compile: CompileOptions {
+            external_links_target_blank: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](https://example.com)", &external)?,
+        "<p><a href=\"https://example.com\" target=\"_blank\" rel=\"noopener\">a</a></p>",
+        "should support `external_links_target_blank` on external links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](//example.com \"b\")", &external)?,
+        "<p><a href=\"//example.com\" title=\"b\" target=\"_blank\" rel=\"noopener\">a</a></p>",
+        "should support `external_links_target_blank` on protocol-relative links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a][b]\n\n[b]: https://example.com", &external)?,
+        "<p><a href=\"https://example.com\" target=\"_blank\" rel=\"noopener\">a</a></p>\n",
+        "should support `external_links_target_blank` on references"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](#b) [c](./d.html) [e](/f?g:h)", &external)?,
+        "<p><a href=\"#b\">a</a> <a href=\"./d.html\">c</a> <a href=\"/f?g:h\">e</a></p>",
+        "should not support `external_links_target_blank` on fragments and relative links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](#) [b](./c) [d](../e) [f](g) [h](?i) [j](<>)", &external)?,
+        "<p><a href=\"#\">a</a> <a href=\"./c\">b</a> <a href=\"../e\">d</a> <a href=\"g\">f</a> <a href=\"?i\">h</a> <a href=\"\">j</a></p>",
+        "should not support `external_links_target_blank` on bare fragments, paths, queries, and empty destinations"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b/c:d) [e](HTTPS://f)", &external)?,
+        "<p><a href=\"b/c:d\">a</a> <a href=\"HTTPS://f\" target=\"_blank\" rel=\"noopener\">e</a></p>",
+        "should only see a protocol before the first slash, and in any case"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](mailto:b@c.d) [e](irc://f.g) [h](xmpp:i@j.k) [l](https:m)", &external)?,
+        "<p><a href=\"mailto:b@c.d\">a</a> <a href=\"irc://f.g\">e</a> <a href=\"xmpp:i@j.k\">h</a> <a href=\"https:m\">l</a></p>",
+        "should not support `external_links_target_blank` on other protocols"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a][b] [c][d]\n\n[b]: #e\n[d]: ./f", &external)?,
+        "<p><a href=\"#e\">a</a> <a href=\"./f\">c</a></p>\n",
+        "should not support `external_links_target_blank` on references to fragments and relative links"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](https://example.com/b.png)", &external)?,
+        "<p><img src=\"https://example.com/b.png\" alt=\"a\" /></p>",
+        "should not support `external_links_target_blank` on images"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://example.com/a) [b](HTTPS://EXAMPLE.COM/b) [c](https://example.org)",
+            &Options {
+                compile: CompileOptions {
+                    external_links_target_blank: true,
+                    external_link_base: Some("https://example.com".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://example.com/a\">a</a> <a href=\"HTTPS://EXAMPLE.COM/b\">b</a> <a href=\"https://example.org\" target=\"_blank\" rel=\"noopener\">c</a></p>",
+        "should support `external_link_base`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://example.com.org) [b](https://example.com:8080/c) [c](http://example.com) [d](//example.com/e) [f](https://example.community)",
+            &Options {
+                compile: CompileOptions {
+                    external_links_target_blank: true,
+                    external_link_base: Some("https://example.com".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://example.com.org\" target=\"_blank\" rel=\"noopener\">a</a> <a href=\"https://example.com:8080/c\" target=\"_blank\" rel=\"noopener\">b</a> <a href=\"http://example.com\" target=\"_blank\" rel=\"noopener\">c</a> <a href=\"//example.com/e\">d</a> <a href=\"https://example.community\" target=\"_blank\" rel=\"noopener\">f</a></p>",
+        "should compare the protocol and host with `external_link_base`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://example.com/docs) [b](https://example.com/docs/c?d) [e](https://example.com/docsf) [g](https://example.com/h)",
+            &Options {
+                compile: CompileOptions {
+                    external_links_target_blank: true,
+                    external_link_base: Some("https://example.com/docs/".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://example.com/docs\">a</a> <a href=\"https://example.com/docs/c?d\">b</a> <a href=\"https://example.com/docsf\" target=\"_blank\" rel=\"noopener\">e</a> <a href=\"https://example.com/h\" target=\"_blank\" rel=\"noopener\">g</a></p>",
+        "should compare the path with `external_link_base` by segment"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://example.com)",
+            &Options {
+                compile: CompileOptions {
+                    external_links_target_blank: true,
+                    external_link_rel: Some("".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://example.com\" target=\"_blank\">a</a></p>",
+        "should support an empty `external_link_rel`"
+    );
+
+    let link_attributes = Options {
+        compile: CompileOptions {
+            link_attributes: Some(|destination| {
+                if destination.ends_with(".pdf") {
+                    vec![("type".into(), "application/pdf".into())]
+                } else {
+                    vec![]
+                }
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](b.pdf \"c\") [d](e.html)", &link_attributes)?,
+        "<p><a href=\"b.pdf\" title=\"c\" type=\"application/pdf\">a</a> <a href=\"e.html\">d</a></p>",
+        "should support `link_attributes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a][]\n\n[a]: <b c.p&#x64;f>", &link_attributes)?,
+        "<p><a href=\"b%20c.pdf\" type=\"application/pdf\">a</a></p>\n",
+        "should support `link_attributes` on references, w/ decoded destinations"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b.pdf) <https://c.pdf>", &link_attributes)?,
+        "<p><img src=\"b.pdf\" alt=\"a\" /> <a href=\"https://c.pdf\">https://c.pdf</a></p>",
+        "should not support `link_attributes` on images or autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](b)",
+            &Options {
+                compile: CompileOptions {
+                    link_attributes: Some(|destination| {
+                        vec![("data-destination".into(), format!("\"{}\"", destination))]
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"b\" data-destination=\"&quot;b&quot;\">a</a></p>",
+        "should encode values from `link_attributes`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](b) ![c](d) <https://e.com> *f* **g**",
+            &Options {
+                compile: CompileOptions {
+                    attributes: vec![
+                        (EventName::Link, "rel".into(), "author".into()),
+                        (EventName::Image, "itemprop".into(), "image".into()),
+                        (EventName::Emphasis, "property".into(), "f".into()),
+                        (EventName::Strong, "property".into(), "g".into()),
+                        (EventName::Paragraph, "itemprop".into(), "text".into()),
+                    ],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p itemprop=\"text\"><a href=\"b\" rel=\"author\">a</a> <img src=\"d\" alt=\"c\" itemprop=\"image\" /> <a href=\"https://e.com\">https://e.com</a> <em property=\"f\">f</em> <strong property=\"g\">g</strong></p>",
+        "should support extra attributes on links and images, but not autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://b.com)",
+            &Options {
+                compile: CompileOptions {
+                    external_links_target_blank: true,
+                    link_attributes: Some(|_| {
+                        vec![
+                            ("rel".into(), "nofollow".into()),
+                            ("c d".into(), "e".into()),
+                        ]
+                    }),
+                    attributes: vec![
+                        (EventName::Link, "rel".into(), "author".into()),
+                        (EventName::Link, "f/".into(), "g".into()),
+                    ],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://b.com\" target=\"_blank\" rel=\"noopener nofollow author\">a</a></p>",
+        "should merge `rel` attributes, and drop attributes w/ invalid names"
+    );
+
+    assert_eq!(
+        to_html("[a](https://xn--example/path%20x)"),
+        "<p><a href=\"https://xn--example/path%20x\">a</a></p>",
+        "should not double encode percent-encoded sequences or punycode hosts"
+    );
+
+    assert_eq!(
+        to_html("[a](https://例え.jp/%E3%81%82/い)"),
+        "<p><a href=\"https://%E4%BE%8B%E3%81%88.jp/%E3%81%82/%E3%81%84\">a</a></p>",
+        "should percent-encode non-ASCII hosts, w/o double encoding"
+    );
+
+    let punycode = Options {
+        compile: CompileOptions {
+            punycode_hosts: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](https://例え.jp/%E3%81%82/い)", &punycode)?,
+        "<p><a href=\"https://xn--r8jz45g.jp/%E3%81%82/%E3%81%84\">a</a></p>",
+        "should support `punycode_hosts`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](https://xn--example/path%20x)", &punycode)?,
+        "<p><a href=\"https://xn--example/path%20x\">a</a></p>",
+        "should support `punycode_hosts` w/ ASCII hosts"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](//münchen.de/b.png) [c](./münchen)", &punycode)?,
+        "<p><img src=\"//m%C3%BCnchen.de/b.png\" alt=\"a\" /> <a href=\"./m%C3%BCnchen\">c</a></p>",
+        "should not support `punycode_hosts` w/o protocol"
+    );
+
+    assert_eq!(
+        to_html("[*a*](b)"),
+        "<p><a href=\"b\"><em>a</em></a></p>",
+        "should support emphasis in link text"
+    );
+
+    assert_eq!(
+        to_html("[a](*b*)"),
+        "<p><a href=\"*b*\">a</a></p>",
+        "should not support emphasis in a raw destination"
+    );
+
+    assert_eq!(
+        to_html("[a](<*b*>)"),
+        "<p><a href=\"*b*\">a</a></p>",
+        "should not support emphasis in an enclosed destination"
+    );
+
+    assert_eq!(
+        to_html("[a](b \"*c*\")"),
+        "<p><a href=\"b\" title=\"*c*\">a</a></p>",
+        "should not support emphasis in a title"
+    );
+
+    assert_eq!(
+        to_html("*[a](*b*)*"),
+        "<p><em><a href=\"*b*\">a</a></em></p>",
+        "should not match attention outside a link with markers in its destination"
+    );
+
+    assert_eq!(
+        to_html("[a*](b*)*"),
+        "<p><a href=\"b*\">a*</a>*</p>",
+        "should not match attention in link text with markers in its destination"
+    );
+
+    assert_eq!(
+        to_html("*a [b*](c)"),
+        "<p>*a <a href=\"c\">b*</a></p>",
+        "should not match attention across the start of link text"
+    );
+
+    assert_eq!(
+        to_html("![*a*](*b*)"),
+        "<p><img src=\"*b*\" alt=\"a\" /></p>",
+        "should support emphasis in image text, but not in its destination"
+    );
+
+    let literal_backslash = Options {
+        parse: ParseOptions {
+            literal_backslash_in_destinations: true,
+            ..Default::default()
+        },
+        compile: CompileOptions {
+            allow_dangerous_protocol: true,
+            ..Default::default()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](C:\\x\\y)", &danger)?,
+        "<p><a href=\"C:%5Cx%5Cy\">a</a></p>",
+        "should support backslashes before non-punctuation in destinations"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](C:\\x\\y)", &literal_backslash)?,
+        "<p><a href=\"C:%5Cx%5Cy\">a</a></p>",
+        "should support backslashes before non-punctuation in destinations (literal backslashes)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](C:\\x\\y) [b](..\\x\\y)",
+            &Options {
+                parse: ParseOptions {
+                    literal_backslash_in_destinations: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"\">a</a> <a href=\"..%5Cx%5Cy\">b</a></p>",
+        "should drop drive letter paths as dangerous protocols w/ `literal_backslash_in_destinations`, but not relative paths"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](C:\\\\share\\*b)", &danger)?,
+        "<p><a href=\"C:%5Cshare*b\">a</a></p>",
+        "should support character escapes in destinations by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](C:\\\\share\\*b)", &literal_backslash)?,
+        "<p><a href=\"C:%5C%5Cshare%5C*b\">a</a></p>",
+        "should not support character escapes in destinations w/ `literal_backslash_in_destinations`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](C:\\(b\\))", &literal_backslash)?,
+        "<p><a href=\"C:%5C(b%5C)\">a</a></p>",
+        "should not support escaped parens in raw destinations w/ `literal_backslash_in_destinations`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](<C:\\x\\>)", &literal_backslash)?,
+        "<p><a href=\"C:%5Cx%5C\">a</a></p>",
+        "should not support escaped angle brackets in enclosed destinations w/ `literal_backslash_in_destinations`"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](C:\\*b&amp;.png \"\\*\") \\*", &literal_backslash)?,
+        "<p><img src=\"C:%5C*b&amp;.png\" alt=\"a\" title=\"*\" /> *</p>",
+        "should keep character references, and escapes outside destinations, w/ `literal_backslash_in_destinations`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]\n\n[a]: C:\\*x", &literal_backslash)?,
+        "<p><a href=\"C:%5C*x\">a</a></p>\n",
+        "should support `literal_backslash_in_destinations` in definitions"
+    );
+
     assert_eq!(
         to_mdast(
             "a [alpha]() b [bravo](charlie 'delta') c.",
@@ -533,5 +938,28 @@ fn link_resource() -> Result<(), String> {
         "should support nested links in mdast"
     );
 
+    assert_eq!(
+        to_mdast(
+            "[a](b \"he said \\\"hi\\\" &amp; bye\")",
+            &Default::default()
+        )?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Link(Link {
+                    children: vec![Node::Text(Text {
+                        value: "a".into(),
+                        position: Some(Position::new(1, 2, 1, 1, 3, 2))
+                    }),],
+                    url: "b".into(),
+                    title: Some("he said \"hi\" & bye".into()),
+                    position: Some(Position::new(1, 1, 0, 1, 34, 33))
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 34, 33))
+            }),],
+            position: Some(Position::new(1, 1, 0, 1, 34, 33))
+        }),
+        "should support character escapes and references in titles in mdast"
+    );
+
     Ok(())
 }
@@ -1,8 +1,10 @@
 use markdown::{
+    list_item_marker,
     mdast::{List, ListItem, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, EventKind, EventName, ListItemMarker, Markdown, Options,
+    ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -329,6 +331,68 @@ fn list() -> Result<(), String> {
         "should support a new list by changing the marker (ordered)"
     );
 
+    assert_eq!(
+        to_html("1) a\n2) b"),
+        "<ol>\n<li>a</li>\n<li>b</li>\n</ol>",
+        "should support a paren as an ordered list delimiter"
+    );
+
+    assert_eq!(
+        to_html("1. a\n2) b"),
+        "<ol>\n<li>a</li>\n</ol>\n<ol start=\"2\">\n<li>b</li>\n</ol>",
+        "should support a new list by changing the delimiter from a dot to a paren"
+    );
+
+    assert_eq!(
+        to_html("1) a\n\n2. b"),
+        "<ol>\n<li>a</li>\n</ol>\n<ol start=\"2\">\n<li>b</li>\n</ol>",
+        "should support a new list by changing the delimiter from a paren to a dot"
+    );
+
+    // Get the markers of all list items.
+    let markers = |value: &str| -> Result<Vec<Option<ListItemMarker>>, String> {
+        let markdown = Markdown::new(value, &ParseOptions::default())?;
+        let events = markdown.events();
+        Ok(events
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| d.kind == EventKind::Enter && d.name == EventName::ListItem)
+            .map(|(index, _)| list_item_marker(events, markdown.value().as_bytes(), index))
+            .collect())
+    };
+
+    assert_eq!(
+        markers("1. a\n\n1) b")?,
+        vec![Some(ListItemMarker::Dot), Some(ListItemMarker::Paren)],
+        "should support getting ordered list item markers"
+    );
+
+    assert_eq!(
+        markers("* a\n- b\n+ c")?,
+        vec![
+            Some(ListItemMarker::Asterisk),
+            Some(ListItemMarker::Dash),
+            Some(ListItemMarker::Plus)
+        ],
+        "should support getting unordered list item markers"
+    );
+
+    assert_eq!(
+        (
+            ListItemMarker::Dot.ordered(),
+            ListItemMarker::Paren.ordered(),
+            ListItemMarker::Dash.ordered()
+        ),
+        (true, true, false),
+        "should know whether list item markers are ordered"
+    );
+
+    assert_eq!(
+        ListItemMarker::from_byte(b'a'),
+        None,
+        "should not get a list item marker from other bytes"
+    );
+
     assert_eq!(
         to_html("Foo\n- bar\n- baz"),
         "<p>Foo</p>\n<ul>\n<li>bar</li>\n<li>baz</li>\n</ul>",
@@ -589,6 +653,38 @@ fn list() -> Result<(), String> {
         "should support turning off lists"
     );
 
+    let sourcepos = Options {
+        compile: CompileOptions {
+            source_positions: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("- a\n- b", &sourcepos)?,
+        "<ul data-sourcepos=\"1:1-2:3\">\n<li data-sourcepos=\"1:1-1:3\">a</li>\n<li data-sourcepos=\"2:1-2:3\">b</li>\n</ul>",
+        "should support source positions on list items"
+    );
+
+    assert_eq!(
+        to_html_with_options("1. a\n\n   b\n\n\n2. c\n\nd", &sourcepos)?,
+        "<ol data-sourcepos=\"1:1-6:4\">\n<li data-sourcepos=\"1:1-3:4\">\n<p data-sourcepos=\"1:4-1:4\">a</p>\n<p data-sourcepos=\"3:4-3:4\">b</p>\n</li>\n<li data-sourcepos=\"6:1-6:4\">\n<p data-sourcepos=\"6:4-6:4\">c</p>\n</li>\n</ol>\n<p data-sourcepos=\"8:1-8:1\">d</p>",
+        "should support source positions on list items w/ blank lines in them (excluding trailing ones)"
+    );
+
+    assert_eq!(
+        to_html_with_options("> # a\n>\n> b\n> -\n> ***\n>\n>     c", &sourcepos)?,
+        "<blockquote data-sourcepos=\"1:1-7:7\">\n<h1 data-sourcepos=\"1:3-1:5\">a</h1>\n<h2 data-sourcepos=\"3:3-4:3\">b</h2>\n<hr data-sourcepos=\"5:3-5:5\" />\n<pre data-sourcepos=\"7:3-7:7\"><code>c\n</code></pre>\n</blockquote>",
+        "should support source positions on other flow"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n  \n- b\n\n  - c", &sourcepos)?,
+        "<ul data-sourcepos=\"1:1-5:5\">\n<li data-sourcepos=\"1:1-1:3\">\n<p data-sourcepos=\"1:3-1:3\">a</p>\n</li>\n<li data-sourcepos=\"3:1-5:5\">\n<p data-sourcepos=\"3:3-3:3\">b</p>\n<ul data-sourcepos=\"5:3-5:5\">\n<li data-sourcepos=\"5:3-5:5\">c</li>\n</ul>\n</li>\n</ul>",
+        "should not include trailing whitespace in source positions"
+    );
+
     assert_eq!(
         to_mdast("* a", &Default::default())?,
         Node::Root(Root {
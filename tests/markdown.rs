@@ -0,0 +1,353 @@
+use markdown::{is_inline_only, EventIndex, EventKind, EventName, Markdown, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn markdown() -> Result<(), String> {
+    let doc = "# a\n\n[b](c) and [d][] and ![e](f)\n\n> [g](<h i> \"j\")\n\nk\n=\n\n```l\nm\n```\n\n    n\n\n[d]: o\n";
+    let markdown = Markdown::new(doc, &ParseOptions::default())?;
+
+    assert_eq!(
+        markdown
+            .links()
+            .filter_map(|link| link.find(&EventName::ResourceDestinationString))
+            .map(|destination| destination.as_str())
+            .collect::<Vec<_>>(),
+        vec!["c", "h i"],
+        "should support getting link destinations"
+    );
+
+    assert_eq!(
+        markdown
+            .links()
+            .map(|link| link.as_str())
+            .collect::<Vec<_>>(),
+        vec!["[b](c)", "[d][]", "[g](<h i> \"j\")"],
+        "should support getting links"
+    );
+
+    assert_eq!(
+        markdown
+            .headings()
+            .map(|heading| (heading.name().clone(), heading.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            (EventName::HeadingAtx, "# a"),
+            (EventName::HeadingSetext, "k\n=")
+        ],
+        "should support getting headings"
+    );
+
+    assert_eq!(
+        markdown
+            .code_blocks()
+            .map(|code| code.as_str())
+            .collect::<Vec<_>>(),
+        vec!["```l\nm\n```", "    n"],
+        "should support getting code blocks"
+    );
+
+    assert_eq!(
+        markdown
+            .spans(&[EventName::Image])
+            .map(|image| image.as_str())
+            .collect::<Vec<_>>(),
+        vec!["![e](f)"],
+        "should support getting other things"
+    );
+
+    assert_eq!(
+        markdown
+            .spans(&[
+                EventName::Paragraph,
+                EventName::HeadingAtx,
+                EventName::HeadingSetext
+            ])
+            .map(|span| span.to_plain())
+            .collect::<Vec<_>>(),
+        vec!["a", "b and d and e", "g", "k"],
+        "should support getting plain text"
+    );
+
+    // Get the plain text of the first span named `name`.
+    let plain = |value: &str, name: EventName, options: &ParseOptions| -> Result<String, String> {
+        Ok(Markdown::new(value, options)?
+            .spans(&[name])
+            .next()
+            .expect("expected span")
+            .to_plain())
+    };
+    let default = ParseOptions::default();
+
+    assert_eq!(
+        plain("**a** `b` [c](x)", EventName::Paragraph, &default)?,
+        "a b c",
+        "should drop markup, and the destinations of links"
+    );
+
+    assert_eq!(
+        plain("# a *b* ![c](d \"e\") #", EventName::HeadingAtx, &default)?,
+        "a b c",
+        "should keep the alt of images in headings"
+    );
+
+    assert_eq!(
+        plain("a\\*b &amp; &#35; &#x26;", EventName::Paragraph, &default)?,
+        "a*b & # &",
+        "should decode escapes and references"
+    );
+
+    assert_eq!(
+        plain("[a *b*][c]\n\n[c]: d", EventName::Paragraph, &default)?,
+        "a b",
+        "should drop the label of references"
+    );
+
+    assert_eq!(
+        plain("a <b>c</b> <https://d>", EventName::Paragraph, &default)?,
+        "a c https://d",
+        "should drop HTML, but keep autolinks"
+    );
+
+    assert_eq!(
+        plain("a\nb\\\nc", EventName::Paragraph, &default)?,
+        "a\nb\nc",
+        "should keep line endings"
+    );
+
+    assert_eq!(
+        plain("a\nb\n===", EventName::HeadingSetext, &default)?,
+        "a\nb",
+        "should drop line endings after text"
+    );
+
+    assert_eq!(
+        plain(
+            "> a\n>\n> ```js\n> b\n> ```",
+            EventName::BlockQuote,
+            &default
+        )?,
+        "a\nb",
+        "should drop container markers and fences"
+    );
+
+    assert_eq!(
+        plain(
+            "| a | *b* |\n| - | - |",
+            EventName::GfmTableCell,
+            &ParseOptions::gfm()
+        )?,
+        "a",
+        "should support table cells"
+    );
+
+    let mut emoji = ParseOptions::gfm();
+    emoji.constructs.emoji = true;
+
+    assert_eq!(
+        plain("- a :+1: [^b]\n\n[^b]: c", EventName::ListItem, &emoji)?,
+        "a 👍 ",
+        "should drop footnote calls, and decode emoji"
+    );
+
+    assert_eq!(
+        Markdown::new(
+            "- a\n\n-   b\n\n-     c\n\n1.\td\n\n-\n  e",
+            &ParseOptions::default()
+        )?
+        .spans(&[EventName::ListItem])
+        .map(|item| item.list_item_spacing())
+        .collect::<Vec<_>>(),
+        vec![Some(1), Some(3), Some(1), Some(2), Some(0)],
+        "should support getting the spacing after list item markers"
+    );
+
+    assert_eq!(
+        markdown.headings().next().unwrap().list_item_spacing(),
+        None,
+        "should not support getting the spacing of other things"
+    );
+
+    assert_eq!(
+        Markdown::new("a\n=\n\nb\n---\n\n> c\n> ==", &ParseOptions::default())?
+            .headings()
+            .map(|heading| heading.heading_rank())
+            .collect::<Vec<_>>(),
+        vec![Some(1), Some(2), Some(1)],
+        "should support getting the rank of headings (setext) from their underline"
+    );
+
+    assert_eq!(
+        Markdown::new("# a\n\n### b ###\n\n###### c", &ParseOptions::default())?
+            .headings()
+            .map(|heading| heading.heading_rank())
+            .collect::<Vec<_>>(),
+        vec![Some(1), Some(3), Some(6)],
+        "should support getting the rank of headings (atx)"
+    );
+
+    assert_eq!(
+        markdown.code_blocks().next().unwrap().heading_rank(),
+        None,
+        "should not support getting the rank of other things"
+    );
+
+    let markdown = Markdown::new(
+        "[a]: <b c>\n[d]: e\n[f]: <>\n[g]: h\\*&amp;i\n\n[j](<k l> \"m\") [n](o&#x20;p) [q]()",
+        &ParseOptions::default(),
+    )?;
+
+    assert_eq!(
+        markdown
+            .spans(&[EventName::Definition])
+            .map(|definition| definition.destination())
+            .collect::<Vec<_>>(),
+        vec![
+            Some("b c".into()),
+            Some("e".into()),
+            Some("".into()),
+            Some("h*&i".into())
+        ],
+        "should support getting the destination of definitions, w/ and w/o angle brackets"
+    );
+
+    assert_eq!(
+        markdown
+            .spans(&[EventName::Resource])
+            .map(|resource| resource.destination())
+            .collect::<Vec<_>>(),
+        vec![Some("k l".into()), Some("o p".into()), Some("".into())],
+        "should support getting the destination of resources"
+    );
+
+    assert_eq!(
+        markdown.links().next().unwrap().destination(),
+        None,
+        "should not support getting the destination of other things"
+    );
+
+    let markdown = Markdown::new(
+        "[a] [b][] [c][d] ![e][a] [f](g) [![h][A]][b]\n\n[a]: 1\n[b]: 2\n[A]: 3",
+        &ParseOptions::default(),
+    )?;
+    let offset = |index: usize| markdown.events()[index].point.index;
+
+    assert_eq!(
+        markdown
+            .reference_definitions()
+            .into_iter()
+            .map(|(reference, definition)| (offset(reference), offset(definition)))
+            .collect::<Vec<_>>(),
+        vec![(0, 46), (4, 53), (17, 46), (32, 53), (33, 46)],
+        "should support getting the definitions of references (shortcut, collapsed, full, in images, nested; w/o unresolved references and resources; first definition wins)"
+    );
+
+    assert_eq!(
+        Markdown::new("[a]\n\n> [a]: b", &ParseOptions::default())?
+            .reference_definitions()
+            .len(),
+        1,
+        "should support definitions in containers"
+    );
+
+    let markdown = Markdown::new("[a] [b]\n\n[a]: c", &ParseOptions::default())?;
+
+    let offset = |index: usize| markdown.events()[index].point.index;
+
+    assert_eq!(
+        markdown
+            .reference_definitions()
+            .into_iter()
+            .map(|(reference, definition)| (offset(reference), offset(definition)))
+            .collect::<Vec<_>>(),
+        vec![(0, 9)],
+        "should not include unresolved references"
+    );
+
+    let markdown = Markdown::new(
+        "[a](b) *c [d][e]* ![f](g)\n\n[e]: h\n\n> [i](j)",
+        &ParseOptions::default(),
+    )?;
+    let index = EventIndex::new(markdown.events());
+
+    assert_eq!(
+        index
+            .get(&EventName::Link)
+            .iter()
+            .map(|index| markdown.events()[*index].point.index)
+            .collect::<Vec<_>>(),
+        vec![0, 10, 37],
+        "should support getting all link enters from an index"
+    );
+
+    assert_eq!(
+        index.get(&EventName::Data),
+        markdown
+            .iter()
+            .enumerate()
+            .filter(|(_, event)| event.kind == EventKind::Enter && event.name == EventName::Data)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>(),
+        "should get the same indices from an index as when going through all events"
+    );
+
+    assert_eq!(
+        index.get(&EventName::HeadingAtx),
+        &[] as &[usize],
+        "should support getting nothing from an index"
+    );
+
+    assert_eq!(
+        markdown.events().len(),
+        markdown.iter().count(),
+        "should support iterating over events by reference"
+    );
+
+    let events = Markdown::new("a", &ParseOptions::default())?
+        .into_iter()
+        .map(|event| (event.kind, event.name))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        events,
+        vec![
+            (EventKind::Enter, EventName::Paragraph),
+            (EventKind::Enter, EventName::Data),
+            (EventKind::Exit, EventName::Data),
+            (EventKind::Exit, EventName::Paragraph)
+        ],
+        "should support iterating over events"
+    );
+
+    let inline_only = |value: &str| -> Result<bool, String> {
+        Ok(is_inline_only(
+            Markdown::new(value, &ParseOptions::default())?.events(),
+        ))
+    };
+
+    assert!(inline_only("a *b*")?, "should support a paragraph");
+
+    assert!(
+        inline_only("\n\n  a\nb  \n\n")?,
+        "should support a paragraph w/ blank lines around it"
+    );
+
+    assert!(
+        !inline_only("# h\n\np")?,
+        "should not support a heading and a paragraph"
+    );
+
+    assert!(!inline_only("a\n\nb")?, "should not support two paragraphs");
+
+    assert!(!inline_only("> a")?, "should not support containers");
+
+    assert!(
+        !inline_only("[a]: b\nc")?,
+        "should not support definitions next to a paragraph"
+    );
+
+    assert!(!inline_only("")?, "should not support empty documents");
+
+    assert!(!inline_only("  \n")?, "should not support blank documents");
+
+    Ok(())
+}
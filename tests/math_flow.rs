@@ -255,6 +255,72 @@ fn math_flow() -> Result<(), String> {
         "should not support lazyness (3)"
     );
 
+    let latex = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                math_flow_latex: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("\\[\na\n\\]", &math)?,
+        "<p>[\na\n]</p>",
+        "should not support math (flow) with LaTeX delimiters by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\[\n\\frac{1}{2}\n\\]", &latex)?,
+        "<pre><code class=\"language-math math-display\">\\frac{1}{2}\n</code></pre>",
+        "should support math (flow) with LaTeX delimiters w/ `math_flow_latex`"
+    );
+
+    assert_eq!(
+        to_html_with_options("  \\[ \n   a\n  b\n \\] ", &latex)?,
+        "<pre><code class=\"language-math math-display\"> a\nb\n</code></pre>",
+        "should support indent and trailing whitespace around LaTeX delimiters"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\[a\\]", &latex)?,
+        "<p>[a]</p>",
+        "should not support content after an opening LaTeX delimiter"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\[\na\n\\] b", &latex)?,
+        "<pre><code class=\"language-math math-display\">a\n\\] b\n</code></pre>\n",
+        "should not support content after a closing LaTeX delimiter"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\\[\nb\n\\]\nc", &latex)?,
+        "<p>a</p>\n<pre><code class=\"language-math math-display\">b\n</code></pre>\n<p>c</p>",
+        "should support math (flow) with LaTeX delimiters interrupting paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("- \\[\n  a\n  \\]", &latex)?,
+        "<ul>\n<li>\n<pre><code class=\"language-math math-display\">a\n</code></pre>\n</li>\n</ul>",
+        "should support math (flow) with LaTeX delimiters in containers"
+    );
+
+    assert_eq!(
+        to_mdast("\\[\na\n\\]", &latex.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Math(Math {
+                value: "a".into(),
+                meta: None,
+                position: Some(Position::new(1, 1, 0, 3, 3, 7))
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 3, 7))
+        }),
+        "should support math (flow) with LaTeX delimiters as `Math`s in mdast"
+    );
+
     assert_eq!(
         to_mdast("$$extra\nabc\ndef\n$$", &math.parse)?,
         Node::Root(Root {
@@ -207,6 +207,92 @@ fn math_text() -> Result<(), String> {
         "should support an escaped initial dollar"
     );
 
+    let latex = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                math_text_latex: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a \\(b\\) c", &math)?,
+        "<p>a (b) c</p>",
+        "should not support math (text) with LaTeX delimiters by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a \\(b\\) c", &latex)?,
+        "<p>a <code class=\"language-math math-inline\">b</code> c</p>",
+        "should support math (text) with LaTeX delimiters w/ `math_text_latex`"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\( a \\)", &latex)?,
+        "<p><code class=\"language-math math-inline\">a</code></p>",
+        "should strip padding in math (text) with LaTeX delimiters"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\(a\nb\\)", &latex)?,
+        "<p><code class=\"language-math math-inline\">a b</code></p>",
+        "should support line endings in math (text) with LaTeX delimiters"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\(a\\\\)b\\)", &latex)?,
+        "<p><code class=\"language-math math-inline\">a\\\\)b</code></p>",
+        "should support escaped closing delimiters in math (text) with LaTeX delimiters"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\\\(a\\)", &latex)?,
+        "<p>\\(a)</p>",
+        "should not support an escaped opening delimiter"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\(a", &latex)?,
+        "<p>(a</p>",
+        "should not support a missing closing delimiter"
+    );
+
+    assert_eq!(
+        to_html_with_options("`\\(a\\)`", &latex)?,
+        "<p><code>\\(a\\)</code></p>",
+        "should not support math (text) with LaTeX delimiters in code (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("*\\(a*\\)*", &latex)?,
+        "<p><em><code class=\"language-math math-inline\">a*</code></em></p>",
+        "should not support attention in math (text) with LaTeX delimiters"
+    );
+
+    assert_eq!(
+        to_mdast("a \\(b\\)", &latex.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::InlineMath(InlineMath {
+                        value: "b".into(),
+                        position: Some(Position::new(1, 3, 2, 1, 8, 7))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 8, 7))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 8, 7))
+        }),
+        "should support math (text) with LaTeX delimiters as `InlineMath`s in mdast"
+    );
+
     assert_eq!(
         to_mdast("a $alpha$ b.", &math.parse)?,
         Node::Root(Root {
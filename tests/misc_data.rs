@@ -0,0 +1,89 @@
+use markdown::{EventName, Markdown, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn data() -> Result<(), String> {
+    // Get the start and end indices of all data.
+    let data = |value: &str| -> Result<Vec<(usize, usize)>, String> {
+        let markdown = Markdown::new(value, &ParseOptions::gfm())?;
+        Ok(markdown
+            .spans(&[EventName::Data])
+            .map(|span| {
+                let events = span.events();
+                (events[0].point.index, events[events.len() - 1].point.index)
+            })
+            .collect())
+    };
+
+    assert_eq!(
+        data("a*b&c\\d[e~f]g!h")?,
+        vec![(0, 15)],
+        "should merge data around markers that turn out to be data"
+    );
+
+    assert_eq!(
+        data("a\\*b")?,
+        vec![(0, 1), (3, 4)],
+        "should not merge data across other events"
+    );
+
+    assert_eq!(
+        data("a\nb")?,
+        vec![(0, 1), (2, 3)],
+        "should not merge data across line endings"
+    );
+
+    assert_eq!(
+        data("[a*b](c)d")?,
+        vec![(1, 4), (6, 7), (8, 9)],
+        "should not merge data across links"
+    );
+
+    // Large prose document, with things that stop plain bytes at different
+    // places in words.
+    let section = "# Alpha *beta* gamma ##\n\nDelta epsilon zeta eta theta iota kappa lambda mu nu xi omicron pi rho.\nSigma\ttau upsilon phi chi psi omega, [a](b) and `c` and &amp; and <d> and ~e~.\r\n\n> Ä block quote with non-ASCII — characters, like “quotes”, in it.\n> And a lazy\nline.\n\n| Alpha | Beta gamma delta |\n| - | - |\n| Epsilon zeta | Eta \\| theta |\n\n- List item\n  continued\twith a tab.\n\n";
+    let section_lines = section.matches('\n').count();
+    let value = section.repeat(64);
+    let markdown = Markdown::new(&value, &ParseOptions::gfm())?;
+    let section_markdown = Markdown::new(section, &ParseOptions::gfm())?;
+    let events = markdown.events();
+    let section_events = section_markdown.events();
+    let mut index = 0;
+
+    assert_eq!(
+        events.len(),
+        section_events.len() * 64,
+        "should have the same number of events for each section"
+    );
+
+    while index < events.len() {
+        let event = &events[index];
+        let expected = &section_events[index % section_events.len()];
+        let section_index = index / section_events.len();
+
+        assert_eq!(
+            (
+                &event.kind,
+                &event.name,
+                event.point.line - section_index * section_lines,
+                event.point.column,
+                event.point.index - section_index * section.len(),
+                event.point.vs
+            ),
+            (
+                &expected.kind,
+                &expected.name,
+                expected.point.line,
+                expected.point.column,
+                expected.point.index,
+                expected.point.vs
+            ),
+            "should have the same events for each section of a large document (event {})",
+            index
+        );
+
+        index += 1;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use markdown::{diff_events, EventChange, EventName, Markdown, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn diff() -> Result<(), String> {
+    let options = ParseOptions::default();
+    let doc = "# Neptune\n\n> Saturn *and* [Jupiter](https://example.com).\n\n* Mercury\n* Venus\n\n```js\nearth()\n```\n";
+    let old = Markdown::new(doc, &options)?;
+
+    assert_eq!(
+        diff_events(old.events(), old.events()),
+        vec![],
+        "should not find changes in the same events"
+    );
+
+    let value = doc.replace("*and*", "*andy*");
+    let new = Markdown::new(&value, &options)?;
+    let changes = diff_events(old.events(), new.events());
+
+    assert_eq!(
+        changes.len(),
+        2,
+        "should find a small diff for a single character edit"
+    );
+
+    assert_eq!(
+        changes
+            .iter()
+            .map(|change| match change {
+                EventChange::Delete(index) => &old.events()[*index].name,
+                EventChange::Insert(index) => &new.events()[*index].name,
+            })
+            .collect::<Vec<_>>(),
+        vec![&EventName::Data, &EventName::Data],
+        "should find that the data in the emphasis changed"
+    );
+
+    let value = format!("Pluto\n\n{}", doc);
+    let new = Markdown::new(&value, &options)?;
+
+    assert_eq!(
+        diff_events(old.events(), new.events()),
+        (0..8).map(EventChange::Insert).collect::<Vec<_>>(),
+        "should find a small diff when adding something before other things"
+    );
+
+    let value = doc.replace("* Venus\n", "");
+    let new = Markdown::new(&value, &options)?;
+
+    assert_eq!(
+        diff_events(old.events(), new.events())
+            .iter()
+            .all(|change| matches!(change, EventChange::Delete(_))),
+        true,
+        "should only find deletes when removing something"
+    );
+
+    assert_eq!(
+        diff_events(&[], old.events()).len(),
+        old.events().len(),
+        "should insert everything from nothing"
+    );
+
+    assert_eq!(
+        diff_events(old.events(), &[]).len(),
+        old.events().len(),
+        "should delete everything to nothing"
+    );
+
+    // Keeping what was reached after each edit takes quadratic memory here.
+    let old_value = (0..200)
+        .map(|index| format!("* {} *{}*\n", index, "a".repeat(index % 7)))
+        .collect::<String>();
+    let new_value = (0..200)
+        .map(|index| format!("> `{}` [b]({})\n\n", "c".repeat(index % 5), index))
+        .collect::<String>();
+    let old = Markdown::new(&old_value, &options)?;
+    let new = Markdown::new(&new_value, &options)?;
+    let changes = diff_events(old.events(), new.events());
+    let deletes = changes
+        .iter()
+        .filter(|change| matches!(change, EventChange::Delete(_)))
+        .count();
+
+    assert_eq!(
+        old.events().len() - deletes,
+        new.events().len() - (changes.len() - deletes),
+        "should diff large, dissimilar documents"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,228 @@
+use markdown::{
+    resolve, str_between, to_html, to_html_from_events, tokenize, Event, EventKind, EventName,
+    EventPoint, Markdown, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Build events on a single line, from `enter` or `exit`, a name, and an
+/// index.
+macro_rules! events {
+    ($($kind:ident $name:ident $index:expr),* $(,)?) => {
+        vec![$(Event::$kind(EventName::$name, EventPoint::new(1, $index + 1, $index))),*]
+    };
+}
+
+#[test]
+fn misc_events() -> Result<(), String> {
+    let events = events![
+        enter Paragraph 0,
+        enter Emphasis 0,
+        enter EmphasisSequence 0,
+        exit EmphasisSequence 1,
+        enter EmphasisText 1,
+        enter Data 1,
+        exit Data 2,
+        exit EmphasisText 2,
+        enter EmphasisSequence 2,
+        exit EmphasisSequence 3,
+        exit Emphasis 3,
+        exit Paragraph 3,
+    ];
+
+    assert_eq!(
+        (&events[0].kind, &events[0].name, events[0].point.column),
+        (&EventKind::Enter, &EventName::Paragraph, 1),
+        "should support creating enter events"
+    );
+
+    assert_eq!(
+        (
+            &events[11].kind,
+            events[11].point.index,
+            events[11].link.is_none()
+        ),
+        (&EventKind::Exit, 3, true),
+        "should support creating exit events"
+    );
+
+    assert_eq!(
+        to_html_from_events("*a*", &events, &Options::default())?,
+        to_html("*a*"),
+        "should support compiling created events"
+    );
+
+    assert_eq!(
+        to_html_from_events("*a*", &events[..11], &Options::default()),
+        Err("1:1: Expected an exit of `Paragraph` (unbalanced-events)".into()),
+        "should crash on an enter w/o exit"
+    );
+
+    assert_eq!(
+        to_html_from_events("*a*", &events[1..], &Options::default()),
+        Err("1:4: Unexpected exit of `Paragraph`, expected no exit (unbalanced-events)".into()),
+        "should crash on an exit w/o enter"
+    );
+
+    assert_eq!(
+        to_html_from_events(
+            "*a*",
+            &events![enter Paragraph 0, enter Data 0, exit Paragraph 1, exit Data 1],
+            &Options::default()
+        ),
+        Err(
+            "1:2: Unexpected exit of `Paragraph`, expected an exit of `Data` (unbalanced-events)"
+                .into()
+        ),
+        "should crash on an exit of something else"
+    );
+
+    assert_eq!(
+        to_html_from_events("a", &events, &Options::default()),
+        Err(
+            "1:3: Unexpected event at index `2`, expected at most `1` (events-out-of-bounds)"
+                .into()
+        ),
+        "should crash on events past the end of the document"
+    );
+
+    let markdown = Markdown::new("a *b*\n\n```js\nc\n```", &ParseOptions::default())?;
+
+    assert_eq!(
+        to_html_from_events(markdown.value(), markdown.events(), &Options::default())?,
+        to_html(markdown.value()),
+        "should support compiling parsed events"
+    );
+
+    // Get the line, column, and index of the first paragraph exit.
+    let paragraph_exit = |value: &str| -> Result<(usize, usize, usize), String> {
+        let markdown = Markdown::new(value, &ParseOptions::default())?;
+        let point = &markdown
+            .events()
+            .iter()
+            .find(|event| event.kind == EventKind::Exit && event.name == EventName::Paragraph)
+            .expect("expected paragraph")
+            .point;
+        Ok((point.line, point.column, point.index))
+    };
+
+    assert_eq!(
+        paragraph_exit("a\n\n")?,
+        (1, 2, 1),
+        "should exit paragraphs at the end of their last line, not after blank lines"
+    );
+
+    assert_eq!(
+        paragraph_exit("a\nb\r\n\r\n  \n\nc")?,
+        (2, 2, 3),
+        "should exit paragraphs before several blank lines"
+    );
+
+    assert_eq!(
+        paragraph_exit("> a\n>\n")?,
+        (1, 4, 3),
+        "should exit paragraphs before blank lines in containers"
+    );
+
+    // Get the source of every `name`.
+    let sources = |value: &str, name: EventName| -> Result<Vec<String>, String> {
+        let markdown = Markdown::new(value, &ParseOptions::default())?;
+        let events = markdown.events();
+        let mut stack = vec![];
+        let mut result = vec![];
+
+        for event in events {
+            if event.name == name {
+                if event.kind == EventKind::Enter {
+                    stack.push(&event.point);
+                } else {
+                    let start = stack.pop().expect("expected enter");
+                    result.push(str_between(markdown.value(), start, &event.point).into());
+                }
+            }
+        }
+
+        Ok(result)
+    };
+
+    assert_eq!(
+        sources("a *b* **c**", EventName::Emphasis)?,
+        vec!["*b*"],
+        "should support getting the source between two points"
+    );
+
+    assert_eq!(
+        sources("# Ünïcödé 👋\n\n日本語 *テキスト*", EventName::Data)?,
+        vec!["Ünïcödé 👋", "日本語 ", "テキスト"],
+        "should support getting the source between two points, w/ non-ASCII"
+    );
+
+    assert_eq!(
+        sources("> a\n> * b\n>\n>   c", EventName::ListItem)?,
+        vec!["* b\n>\n>   c"],
+        "should support getting the source between two points, across lines"
+    );
+
+    assert_eq!(
+        sources("\ta\n\n```\n\t\tb\n```", EventName::CodeFlowChunk)?,
+        vec!["a", "\t\tb"],
+        "should support getting the source between two points, w/ tabs"
+    );
+
+    // Events can’t be compared, but their debug output can.
+    let debug = |events: &[Event]| format!("{:?}", events);
+    let value = "a *b* **c**";
+    let tokenized = tokenize(value, &ParseOptions::default())?;
+
+    assert_eq!(
+        tokenized
+            .events
+            .iter()
+            .map(|event| (&event.kind, &event.name, event.point.index))
+            .collect::<Vec<_>>(),
+        vec![
+            (&EventKind::Enter, &EventName::Content, 0),
+            (&EventKind::Exit, &EventName::Content, 11)
+        ],
+        "should support tokenizing w/o resolving (emphasis is not parsed yet)"
+    );
+
+    let resolved = resolve(value, tokenized, &ParseOptions::default())?;
+
+    assert_eq!(
+        debug(&resolved),
+        debug(Markdown::new(value, &ParseOptions::default())?.events()),
+        "should support resolving tokenized events, the same as parsing at once"
+    );
+
+    assert_eq!(
+        resolved
+            .iter()
+            .filter(|event| event.kind == EventKind::Enter)
+            .map(|event| &event.name)
+            .filter(|name| matches!(name, EventName::Emphasis | EventName::Strong))
+            .collect::<Vec<_>>(),
+        vec![&EventName::Emphasis, &EventName::Strong],
+        "should support resolving emphasis and strong"
+    );
+
+    let value = "- a\n- b\n\nc\n===\n\n[d]: e\n\n[^f]\n\n[^f]: g";
+    let options = ParseOptions::gfm();
+
+    assert_eq!(
+        debug(&resolve(value, tokenize(value, &options)?, &options)?),
+        debug(Markdown::new(value, &options)?.events()),
+        "should support resolving lists, headings, definitions, and footnotes"
+    );
+
+    let tokenized = tokenize(value, &options)?;
+
+    assert!(
+        !tokenized
+            .events
+            .iter()
+            .any(|event| matches!(event.name, EventName::ListUnordered | EventName::Paragraph)),
+        "should not group list items or form paragraphs when tokenizing"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use markdown::{
+    mdast::{Emphasis, Node, Paragraph, Root, Text},
+    to_html, to_mdast,
+    unist::Position,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn format_characters() -> Result<(), String> {
+    assert_eq!(
+        to_html("a\u{AD}b"),
+        "<p>a\u{AD}b</p>",
+        "should keep a soft hyphen"
+    );
+
+    assert_eq!(
+        to_html("a\u{200B}b\u{200C}c\u{200D}d\u{2060}e\u{FEFF}f"),
+        "<p>a\u{200B}b\u{200C}c\u{200D}d\u{2060}e\u{FEFF}f</p>",
+        "should keep zero-width characters"
+    );
+
+    assert_eq!(
+        to_html("\u{200E}a\u{200F}b\u{202B}c\u{202C}d\u{2067}e\u{2069}"),
+        "<p>\u{200E}a\u{200F}b\u{202B}c\u{202C}d\u{2067}e\u{2069}</p>",
+        "should keep bidirectional controls"
+    );
+
+    assert_eq!(
+        to_html("*\u{AD}a\u{AD}*"),
+        "<p><em>\u{AD}a\u{AD}</em></p>",
+        "should not treat a soft hyphen as whitespace next to attention"
+    );
+
+    assert_eq!(
+        to_html("a\u{AD}_b_\u{AD}c"),
+        "<p>a\u{AD}_b_\u{AD}c</p>",
+        "should not treat a soft hyphen as punctuation next to attention (intraword `_`)"
+    );
+
+    assert_eq!(
+        to_html("\u{200F}*a*\u{200E} \u{2067}**b**\u{2069}"),
+        "<p>\u{200F}<em>a</em>\u{200E} \u{2067}<strong>b</strong>\u{2069}</p>",
+        "should support attention between bidirectional controls"
+    );
+
+    assert_eq!(
+        to_html("a *\u{200B}b*"),
+        "<p>a <em>\u{200B}b</em></p>",
+        "should treat a zero-width space as other, not whitespace"
+    );
+
+    assert_eq!(
+        to_mdast("\u{AD}*a*\u{200F}", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "\u{AD}".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::Emphasis(Emphasis {
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(1, 4, 3, 1, 5, 4))
+                        })],
+                        position: Some(Position::new(1, 3, 2, 1, 6, 5))
+                    }),
+                    Node::Text(Text {
+                        value: "\u{200F}".into(),
+                        position: Some(Position::new(1, 6, 5, 1, 9, 8))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 9, 8))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 9, 8))
+        }),
+        "should count format characters in positions by their bytes"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use markdown::{to_html, to_html_into, to_html_with_options, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn into() -> Result<(), String> {
+    let value = "# a\n\n> b *c*\n\n* d\n\n[e][]\n\n[e]: f\n\n```g\nh\n```";
+    let mut html = String::with_capacity(256);
+    let capacity = html.capacity();
+
+    to_html_into(value, &Options::default(), &mut html)?;
+
+    assert_eq!(
+        html,
+        to_html(value),
+        "should write the same HTML as `to_html` into a pre-allocated buffer"
+    );
+
+    assert_eq!(
+        html.capacity(),
+        capacity,
+        "should reuse the allocation of a large enough buffer"
+    );
+
+    html.clear();
+    to_html_into("a\r\nb", &Options::default(), &mut html)?;
+
+    assert_eq!(
+        html,
+        to_html("a\r\nb"),
+        "should write the same HTML as `to_html` into a cleared buffer"
+    );
+
+    let mut html = String::from("<main>");
+    to_html_into("a\n\nb", &Options::default(), &mut html)?;
+    html.push_str("</main>");
+
+    assert_eq!(
+        html, "<main><p>a</p>\n<p>b</p></main>",
+        "should append to existing content, w/o line ending before the HTML"
+    );
+
+    let mut html = String::from("<main>\n");
+    to_html_into("[^a]\n\n[^a]: b", &Options::gfm(), &mut html)?;
+
+    assert_eq!(
+        html,
+        format!(
+            "<main>\n{}",
+            to_html_with_options("[^a]\n\n[^a]: b", &Options::gfm())?
+        ),
+        "should append the same HTML as `to_html_with_options` (footnotes)"
+    );
+
+    let mut html = String::from("a");
+    to_html_into("", &Options::default(), &mut html)?;
+
+    assert_eq!(html, "a", "should support empty markdown");
+
+    Ok(())
+}
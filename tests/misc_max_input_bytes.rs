@@ -0,0 +1,80 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn max_input_bytes() -> Result<(), String> {
+    let max = Options {
+        parse: ParseOptions {
+            max_input_bytes: Some(4),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html(&"a".repeat(1024)),
+        format!("<p>{}</p>", "a".repeat(1024)),
+        "should not limit the input by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a*", &max)?,
+        "<p><em>a</em></p>",
+        "should support input smaller than `max_input_bytes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("*ab*", &max)?,
+        "<p><em>ab</em></p>",
+        "should support input as large as `max_input_bytes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("*abc*", &max),
+        Err(
+            "1:1: Unexpected input of `5` bytes, expected at most `4` bytes (max-input-bytes)"
+                .into()
+        ),
+        "should error on input larger than `max_input_bytes`"
+    );
+
+    assert_eq!(
+        to_html_with_options("ééé", &max),
+        Err(
+            "1:1: Unexpected input of `6` bytes, expected at most `4` bytes (max-input-bytes)"
+                .into()
+        ),
+        "should count bytes, not characters"
+    );
+
+    assert_eq!(
+        to_mdast("abcde", &max.parse),
+        Err(
+            "1:1: Unexpected input of `5` bytes, expected at most `4` bytes (max-input-bytes)"
+                .into()
+        ),
+        "should error on input larger than `max_input_bytes` (mdast)"
+    );
+
+    assert_eq!(
+        to_mdast("abcd", &max.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "abcd".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 5, 4))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 5, 4))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 5, 4))
+        }),
+        "should support input as large as `max_input_bytes` (mdast)"
+    );
+
+    Ok(())
+}
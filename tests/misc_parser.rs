@@ -0,0 +1,34 @@
+use markdown::{to_html_from_events, to_html_with_options, Markdown, Options, Parser};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn parser() -> Result<(), String> {
+    let values = [
+        "[a]: b\n\n> c *d*\n\n- e [a]",
+        "f\n===\n\n    g\n\n[a]",
+        "",
+        "[^h]\n\n[^h]: i",
+    ];
+    let options = Options::gfm();
+    let mut parser = Parser::new();
+
+    for value in values {
+        let events = parser.parse(value, &options.parse)?;
+
+        assert_eq!(
+            format!("{:?}", events),
+            format!("{:?}", Markdown::new(value, &options.parse)?.events()),
+            "should parse like a new parser each time ({:?})",
+            value
+        );
+
+        assert_eq!(
+            to_html_from_events(value, events, &options)?,
+            to_html_with_options(value, &options)?,
+            "should not keep definitions from earlier documents ({:?})",
+            value
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn pretty() -> Result<(), String> {
+    let pretty = Options {
+        compile: CompileOptions {
+            pretty: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("- a\n\n  - b\n\n    c\n- d"),
+        "<ul>\n<li>\n<p>a</p>\n<ul>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n</ul>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>",
+        "should not indent by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n\n  - b\n\n    c\n- d", &pretty)?,
+        "<ul>\n  <li>\n    <p>a</p>\n    <ul>\n      <li>\n        <p>b</p>\n        <p>c</p>\n      </li>\n    </ul>\n  </li>\n  <li>\n    <p>d</p>\n  </li>\n</ul>",
+        "should indent nested lists w/ `pretty`"
+    );
+
+    assert_eq!(
+        to_html_with_options("1. a\n   * b\n     c\n2. d", &pretty)?,
+        "<ol>\n  <li>a\n    <ul>\n      <li>b\nc</li>\n    </ul>\n  </li>\n  <li>d</li>\n</ol>",
+        "should not indent inline content in tight lists w/ `pretty`"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> > b\n>\n> ```\n> c\n>   d\n> ```", &pretty)?,
+        "<blockquote>\n  <p>a</p>\n  <blockquote>\n    <p>b</p>\n  </blockquote>\n  <pre><code>c\n  d\n</code></pre>\n</blockquote>",
+        "should indent block quotes, but not code, w/ `pretty`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "> <div>\n> a\n> </div>",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    pretty: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<blockquote>\n<div>\na\n</div>\n</blockquote>",
+        "should not indent HTML (flow) w/ `pretty`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "| a | b |\n| - | - |\n| c |",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    pretty: true,
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<table>\n  <thead>\n    <tr>\n      <th>a</th>\n      <th>b</th>\n    </tr>\n  </thead>\n  <tbody>\n    <tr>\n      <td>c</td>\n      <td></td>\n    </tr>\n  </tbody>\n</table>",
+        "should indent tables w/ `pretty`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a[^b]\n\n[^b]: > c",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    pretty: true,
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<p>a<sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n  <ol>\n    <li id=\"user-content-fn-b\">\n      <blockquote>\n        <p>c</p>\n      </blockquote>\n      <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a>\n    </li>\n  </ol>\n</section>\n",
+        "should indent the footnote section w/ `pretty`"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\r\n> * b", &pretty)?,
+        "<blockquote>\r\n  <p>a</p>\r\n  <ul>\r\n    <li>b</li>\r\n  </ul>\r\n</blockquote>",
+        "should support other line endings w/ `pretty`"
+    );
+
+    Ok(())
+}
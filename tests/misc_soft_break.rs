@@ -1,8 +1,13 @@
-use markdown::to_html;
+use markdown::{
+    mdast::{Break, Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Options,
+};
 use pretty_assertions::assert_eq;
 
 #[test]
-fn soft_break() {
+fn soft_break() -> Result<(), String> {
     assert_eq!(
         to_html("foo\nbaz"),
         "<p>foo\nbaz</p>",
@@ -14,4 +19,96 @@ fn soft_break() {
         "<p>foo\nbaz</p>",
         "should trim spaces around line endings"
     );
+
+    let hard = Options {
+        compile: CompileOptions {
+            soft_break_as_hard_break: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a\nb", &hard)?,
+        "<p>a<br />\nb</p>",
+        "should support turning soft breaks into hard breaks (`soft_break_as_hard_break`)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a \n b\r\nc", &hard)?,
+        "<p>a<br />\nb<br />\r\nc</p>",
+        "should support whitespace and other line endings w/ `soft_break_as_hard_break`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\\\nb  \nc", &hard)?,
+        "<p>a<br />\nb<br />\nc</p>",
+        "should not add extra breaks after hard breaks w/ `soft_break_as_hard_break`"
+    );
+
+    assert_eq!(
+        to_html_with_options("`a\nb` <i\nj> ![c\nd](e)", &hard)?,
+        "<p><code>a b</code> &lt;i\nj&gt; <img src=\"e\" alt=\"c\nd\" /></p>",
+        "should not affect code (text), html (text), or image alt w/ `soft_break_as_hard_break`"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\nb\n```\n\n    c\n    d", &hard)?,
+        "<pre><code>a\nb\n</code></pre>\n<pre><code>c\nd\n</code></pre>",
+        "should not affect code (flow) w/ `soft_break_as_hard_break`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\n\nc", &hard)?,
+        "<p>a<br />\nb</p>\n<p>c</p>",
+        "should not affect line endings between blocks w/ `soft_break_as_hard_break`"
+    );
+
+    assert_eq!(
+        to_mdast("a \n b", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a\nb".into(),
+                    position: Some(Position::new(1, 1, 0, 2, 3, 5))
+                })],
+                position: Some(Position::new(1, 1, 0, 2, 3, 5))
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 3, 5))
+        }),
+        "should support soft breaks as line endings in `Text`s in mdast, not as `Break`s"
+    );
+
+    assert_eq!(
+        to_mdast("a\nb\\\nc  \nd", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a\nb".into(),
+                        position: Some(Position::new(1, 1, 0, 2, 2, 3))
+                    }),
+                    Node::Break(Break {
+                        position: Some(Position::new(2, 2, 3, 3, 1, 5))
+                    }),
+                    Node::Text(Text {
+                        value: "c".into(),
+                        position: Some(Position::new(3, 1, 5, 3, 2, 6))
+                    }),
+                    Node::Break(Break {
+                        position: Some(Position::new(3, 2, 6, 4, 1, 9))
+                    }),
+                    Node::Text(Text {
+                        value: "d".into(),
+                        position: Some(Position::new(4, 1, 9, 4, 2, 10))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 4, 2, 10))
+            })],
+            position: Some(Position::new(1, 1, 0, 4, 2, 10))
+        }),
+        "should support soft breaks next to hard breaks in mdast"
+    );
+
+    Ok(())
 }
@@ -0,0 +1,100 @@
+use markdown::{to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn strip_style_attributes() -> Result<(), String> {
+    let strip = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            strip_style_attributes: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options(
+            "<div style=\"x\">",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<div style=\"x\">",
+        "should not strip `style` attributes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<div style=\"x\">",
+            &Options {
+                compile: CompileOptions {
+                    strip_style_attributes: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "&lt;div style=&quot;x&quot;&gt;",
+        "should do nothing w/o `allow_dangerous_html`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div style=\"x\">", &strip)?,
+        "<div>",
+        "should strip `style` attributes (double quotes, html (flow))"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <span style='x'>b</span>", &strip)?,
+        "<p>a <span>b</span></p>",
+        "should strip `style` attributes (single quotes, html (text))"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div style=x class=y>", &strip)?,
+        "<div class=y>",
+        "should strip `style` attributes (unquoted)"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div  STYLE = \"x\"\tid=\"y\" >", &strip)?,
+        "<div\tid=\"y\" >",
+        "should strip `style` attributes (whitespace, case-insensitive)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<div class=\"a\" style=\"b: c; d: e\" id=\"f\" style>",
+            &strip
+        )?,
+        "<div class=\"a\" id=\"f\">",
+        "should strip several `style` attributes, and keep other attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<img styles=\"a\" data-style=\"b\" title=\"style=c\" />",
+            &strip
+        )?,
+        "<img styles=\"a\" data-style=\"b\" title=\"style=c\" />",
+        "should not strip other attributes or values that include `style`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div\nstyle=\"a\nb\"\nclass=\"c\">", &strip)?,
+        "<div\n\n\nclass=\"c\">",
+        "should strip `style` attributes across lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("<p>a style=\"b\" c</p>", &strip)?,
+        "<p>a style=\"b\" c</p>",
+        "should not strip things that look like `style` attributes outside tags"
+    );
+
+    Ok(())
+}
@@ -1,4 +1,7 @@
-use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use markdown::{
+    to_html, to_html_with_options, CompileOptions, EventKind, EventName, Markdown, Options,
+    ParseOptions,
+};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -284,9 +287,93 @@ fn tabs_virtual_spaces() {
 
     assert_eq!(
         to_html("-\ta\n\n\tb"),
-        "<ul>\n<li>\n<p>a</p>\n<p>\tb</p>\n</li>\n</ul>",
-        // To do: CM.js does not output the tab before `b`. See if that makes sense?
-        // "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
-        "should support a part of a tab as a container, and the rest of a tab as flow"
+        "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
+        "should expand a tab after a list item marker to find the content column"
     );
+
+    assert_eq!(
+        to_html("-\ta\n\tb"),
+        "<ul>\n<li>a\nb</li>\n</ul>",
+        "should support a tab as the indent of a list item continuation"
+    );
+
+    assert_eq!(
+        to_html("1.\ta\n\n\tb\n\n10.\tc\n\n\td"),
+        "<ol>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n<p>d</p>\n</li>\n</ol>",
+        "should expand a tab after an ordered list item marker to the next tab stop"
+    );
+
+    assert_eq!(
+        to_html("-\ta\n\n  b"),
+        "<ul>\n<li>a</li>\n</ul>\n<p>b</p>",
+        "should not support a continuation indented less than an expanded tab"
+    );
+
+    assert_eq!(
+        to_html("-\ta\n\n\t\tb"),
+        "<ul>\n<li>\n<p>a</p>\n<pre><code>b\n</code></pre>\n</li>\n</ul>",
+        "should support indented code after a tab as the indent of a list item continuation"
+    );
+}
+
+#[test]
+fn tabs_editor_column() -> Result<(), String> {
+    let value = "\t\ta\n \tb\n\t\t\tc\td";
+    let markdown = Markdown::new(value, &ParseOptions::default())?;
+    let columns = markdown
+        .iter()
+        .filter(|event| event.kind == EventKind::Enter && event.name == EventName::CodeFlowChunk)
+        .map(|event| {
+            (
+                event.point.column,
+                event.point.editor_column(value.as_bytes()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        columns,
+        vec![(5, 2), (5, 3), (5, 2)],
+        "should count tabs up to tab stops in `column`, but as 1 in `editor_column`"
+    );
+
+    let value = "a\tb\r\n\t*c*";
+    let markdown = Markdown::new(value, &ParseOptions::default())?;
+    let columns = markdown
+        .iter()
+        .filter(|event| event.kind == EventKind::Enter && event.name == EventName::Data)
+        .map(|event| {
+            (
+                event.point.column,
+                event.point.editor_column(value.as_bytes()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        columns,
+        vec![(1, 1), (6, 3)],
+        "should support `editor_column` after tabs in text, and after CRLF"
+    );
+
+    let value = "é\t👍 *a*";
+    let markdown = Markdown::new(value, &ParseOptions::default())?;
+    let columns = markdown
+        .iter()
+        .filter(|event| event.kind == EventKind::Enter && event.name == EventName::Emphasis)
+        .map(|event| {
+            (
+                event.point.column,
+                event.point.editor_column(value.as_bytes()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        columns,
+        vec![(10, 5)],
+        "should count characters, not bytes, in `editor_column`"
+    );
+
+    Ok(())
 }
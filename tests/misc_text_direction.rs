@@ -0,0 +1,93 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn text_direction() -> Result<(), String> {
+    assert_eq!(
+        to_html("مرحبا بالعالم"),
+        "<p>مرحبا بالعالم</p>",
+        "should not mark paragraphs by default"
+    );
+
+    let auto = Options {
+        compile: CompileOptions {
+            auto_text_direction: true,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("مرحبا بالعالم", &auto)?,
+        "<p dir=\"rtl\">مرحبا بالعالم</p>",
+        "should mark Arabic paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("שלום עולם", &auto)?,
+        "<p dir=\"rtl\">שלום עולם</p>",
+        "should mark Hebrew paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("hello world", &auto)?,
+        "<p>hello world</p>",
+        "should not mark left-to-right paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("مرحبا بالعالم (hello)", &auto)?,
+        "<p dir=\"rtl\">مرحبا بالعالم (hello)</p>",
+        "should mark mixed paragraphs that are mostly right-to-left"
+    );
+
+    assert_eq!(
+        to_html_with_options("hello world (مرحبا)", &auto)?,
+        "<p>hello world (مرحبا)</p>",
+        "should not mark mixed paragraphs that are mostly left-to-right"
+    );
+
+    assert_eq!(
+        to_html_with_options("123 !?", &auto)?,
+        "<p>123 !?</p>",
+        "should not mark paragraphs without letters"
+    );
+
+    assert_eq!(
+        to_html_with_options("abc ١٢٣٤", &auto)?,
+        "<p>abc ١٢٣٤</p>",
+        "should not count right-to-left digits as letters"
+    );
+
+    assert_eq!(
+        to_html_with_options("abc ؟؟؟؟", &auto)?,
+        "<p>abc ؟؟؟؟</p>",
+        "should not count right-to-left punctuation as letters"
+    );
+
+    assert_eq!(
+        to_html_with_options("a بَ", &auto)?,
+        "<p>a بَ</p>",
+        "should not count right-to-left marks as letters"
+    );
+
+    assert_eq!(
+        to_html_with_options("*مرحبا* `hello world`", &auto)?,
+        "<p dir=\"rtl\"><em>مرحبا</em> <code>hello world</code></p>",
+        "should look at data in nested text, but not at code"
+    );
+
+    assert_eq!(
+        to_html_with_options("- مرحبا\n\n  שלום\n\n- hello", &auto)?,
+        "<ul>\n<li>\n<p dir=\"rtl\">مرحبا</p>\n<p dir=\"rtl\">שלום</p>\n</li>\n<li>\n<p>hello</p>\n</li>\n</ul>",
+        "should mark paragraphs in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("# مرحبا", &auto)?,
+        "<h1>مرحبا</h1>",
+        "should not mark headings"
+    );
+
+    Ok(())
+}
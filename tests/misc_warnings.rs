@@ -0,0 +1,274 @@
+use markdown::{to_html_with_report, unist::Position, Constructs, Options, ParseOptions, Warning};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn warnings() -> Result<(), String> {
+    assert_eq!(
+        to_html_with_report("a *b*", &Options::default())?,
+        ("<p>a <em>b</em></p>".into(), vec![]),
+        "should support no warnings"
+    );
+
+    assert_eq!(
+        to_html_with_report("[a][b]", &Options::default())?,
+        (
+            "<p>[a][b]</p>".into(),
+            vec![Warning {
+                message: "Undefined reference `b`, expected a definition with that label".into(),
+                position: Position::new(1, 4, 3, 1, 7, 6)
+            }]
+        ),
+        "should warn for an undefined reference"
+    );
+
+    assert_eq!(
+        to_html_with_report("[a][b]\n\n[b]: c", &Options::default())?,
+        ("<p><a href=\"c\">a</a></p>\n".into(), vec![]),
+        "should not warn for a defined reference"
+    );
+
+    assert_eq!(
+        to_html_with_report("[a][B]\n\n[b]: c", &Options::default())?,
+        ("<p><a href=\"c\">a</a></p>\n".into(), vec![]),
+        "should not warn for a defined reference w/ a different case"
+    );
+
+    assert_eq!(
+        to_html_with_report("[a]", &Options::default())?,
+        (
+            "<p>[a]</p>".into(),
+            vec![Warning {
+                message: "Undefined reference `a`, expected a definition with that label".into(),
+                position: Position::new(1, 1, 0, 1, 4, 3)
+            }]
+        ),
+        "should warn for an undefined shortcut reference"
+    );
+
+    assert_eq!(
+        to_html_with_report("[a][]", &Options::default())?,
+        (
+            "<p>[a][]</p>".into(),
+            vec![Warning {
+                message: "Undefined reference `a`, expected a definition with that label".into(),
+                position: Position::new(1, 1, 0, 1, 4, 3)
+            }]
+        ),
+        "should warn for an undefined collapsed reference"
+    );
+
+    assert_eq!(
+        to_html_with_report("![a]", &Options::default())?.1,
+        vec![Warning {
+            message: "Undefined reference `a`, expected a definition with that label".into(),
+            position: Position::new(1, 1, 0, 1, 5, 4)
+        }],
+        "should warn for an undefined shortcut reference (image)"
+    );
+
+    assert_eq!(
+        to_html_with_report("[a] [b][] [c](d)\n\n[a]: e\n[b]: f", &Options::default())?.1,
+        vec![],
+        "should not warn for defined shortcut and collapsed references, or resources"
+    );
+
+    assert_eq!(
+        to_html_with_report("[^a] [^b]\n\n[^b]: c", &Options::gfm())?.1,
+        vec![Warning {
+            message: "Undefined reference `^a`, expected a definition with that label".into(),
+            position: Position::new(1, 1, 0, 1, 5, 4)
+        }],
+        "should warn for an undefined footnote call"
+    );
+
+    assert_eq!(
+        to_html_with_report("> [a][b]\n\n* [c][d]", &Options::default())?
+            .1
+            .iter()
+            .map(|d| d.position.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            Position::new(1, 6, 5, 1, 9, 8),
+            Position::new(3, 6, 15, 3, 9, 18)
+        ],
+        "should warn for undefined references in containers"
+    );
+
+    assert_eq!(
+        to_html_with_report(&"[a] [b][c] ".repeat(20_000), &Options::default())?
+            .1
+            .len(),
+        40_000,
+        "should warn once for each of many undefined references (linear in the number of warnings)"
+    );
+
+    assert_eq!(
+        to_html_with_report("```js\na", &Options::default())?,
+        (
+            "<pre><code class=\"language-js\">a\n</code></pre>\n".into(),
+            vec![Warning {
+                message: "Unclosed code (fenced), expected a closing fence".into(),
+                position: Position::new(1, 1, 0, 2, 2, 7)
+            }]
+        ),
+        "should warn for unclosed code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_report("> ```\n> a\n\nb", &Options::default())?.1,
+        vec![Warning {
+            message: "Unclosed code (fenced), expected a closing fence".into(),
+            position: Position::new(1, 3, 2, 2, 4, 9)
+        }],
+        "should warn for code (fenced) closed by its container"
+    );
+
+    assert_eq!(
+        to_html_with_report("```\na\n```", &Options::default())?.1,
+        vec![],
+        "should not warn for closed code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_report("$$\na", &Options::gfm())?.1,
+        vec![],
+        "should not warn for math if it is off"
+    );
+
+    assert_eq!(
+        to_html_with_report(
+            "$$\na",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        math_flow: true,
+                        ..Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?
+        .1,
+        vec![Warning {
+            message: "Unclosed math (flow), expected a closing fence".into(),
+            position: Position::new(1, 1, 0, 2, 2, 4)
+        }],
+        "should warn for unclosed math (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_report("[a]: x\n[a]: y\n\n[a]", &Options::default())?,
+        (
+            "<p><a href=\"x\">a</a></p>".into(),
+            vec![Warning {
+                message: "Duplicate definition `a`, expected a unique label (the first definition with it is used)".into(),
+                position: Position::new(2, 1, 7, 2, 7, 13)
+            }]
+        ),
+        "should warn for a duplicate definition"
+    );
+
+    assert_eq!(
+        to_html_with_report("[a]: x\n\n> [A ]: y\n\n[b]: z\n\n[a] [b]", &Options::default())?.1,
+        vec![Warning {
+            message: "Duplicate definition `A `, expected a unique label (the first definition with it is used)".into(),
+            position: Position::new(3, 3, 10, 3, 10, 17)
+        }],
+        "should warn for a duplicate definition w/ a different label that normalizes to the same identifier"
+    );
+
+    assert_eq!(
+        to_html_with_report("[a]: x\n[b]: y", &Options::default())?.1,
+        vec![],
+        "should not warn for different definitions"
+    );
+
+    assert_eq!(
+        to_html_with_report("[^a]\n\n[^a]: x\n\n[^A]: y", &Options::gfm())?.1,
+        vec![Warning {
+            message: "Duplicate footnote definition `A`, expected a unique label (the first footnote definition with it is used)".into(),
+            position: Position::new(5, 1, 15, 5, 8, 22)
+        }],
+        "should warn for a duplicate footnote definition"
+    );
+
+    assert_eq!(
+        to_html_with_report("[^a]: x\n\n[a]: y\n\n[^a] [a]", &Options::gfm())?.1,
+        vec![],
+        "should not warn for a definition and a footnote definition with the same label"
+    );
+
+    let attention = Options {
+        parse: ParseOptions {
+            attention_warnings: true,
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html_with_report("*a", &Options::default())?.1,
+        vec![],
+        "should not warn for unmatched attention by default"
+    );
+
+    assert_eq!(
+        to_html_with_report("*a", &attention)?,
+        (
+            "<p>*a</p>".into(),
+            vec![Warning {
+                message: "Unmatched `*`, expected a closing sequence".into(),
+                position: Position::new(1, 1, 0, 1, 2, 1)
+            }]
+        ),
+        "should warn for an unmatched opening sequence"
+    );
+
+    assert_eq!(
+        to_html_with_report("a*", &attention)?,
+        (
+            "<p>a*</p>".into(),
+            vec![Warning {
+                message: "Unmatched `*`, expected an opening sequence".into(),
+                position: Position::new(1, 2, 1, 1, 3, 2)
+            }]
+        ),
+        "should warn for an unmatched closing sequence"
+    );
+
+    assert_eq!(
+        to_html_with_report("*a*", &attention)?,
+        ("<p><em>a</em></p>".into(), vec![]),
+        "should not warn for matched attention"
+    );
+
+    assert_eq!(
+        to_html_with_report("**a*", &attention)?,
+        (
+            "<p>*<em>a</em></p>".into(),
+            vec![Warning {
+                message: "Unmatched `*`, expected a closing sequence".into(),
+                position: Position::new(1, 1, 0, 1, 2, 1)
+            }]
+        ),
+        "should warn for the rest of a partially matched sequence"
+    );
+
+    assert_eq!(
+        to_html_with_report("a * b, snake_case, ~c", &attention)?.1,
+        vec![],
+        "should not warn for sequences that cannot open or close, or for other markers"
+    );
+
+    assert_eq!(
+        to_html_with_report("> a __b\n> c", &attention)?.1,
+        vec![Warning {
+            message: "Unmatched `__`, expected a closing sequence".into(),
+            position: Position::new(1, 5, 4, 1, 7, 6)
+        }],
+        "should warn for unmatched attention in containers"
+    );
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use markdown::{
-    mdast::{Node, Root},
+    mdast::{Code, InlineCode, Node, Paragraph, Root, Text},
     to_html, to_mdast,
     unist::Position,
 };
@@ -29,6 +29,30 @@ fn zero() -> Result<(), String> {
         "should not support NUL in a character escape"
     );
 
+    assert_eq!(
+        to_html("`a\0b`"),
+        "<p><code>a�b</code></p>",
+        "should replace NUL in code (text)"
+    );
+
+    assert_eq!(
+        to_html("```a\0b\n\0\n```"),
+        "<pre><code class=\"language-a�b\">�\n</code></pre>",
+        "should replace NUL in code (fenced)"
+    );
+
+    assert_eq!(
+        to_html("[a](b\0c \"d\0e\")"),
+        "<p><a href=\"b%EF%BF%BDc\" title=\"d�e\">a</a></p>",
+        "should replace NUL in destinations and titles"
+    );
+
+    assert_eq!(
+        to_html("a\x01b\x1fc\x7fd"),
+        "<p>a\x01b\x1fc\x7fd</p>",
+        "should keep other ASCII control characters"
+    );
+
     assert_eq!(
         to_mdast("", &Default::default())?,
         Node::Root(Root {
@@ -38,5 +62,58 @@ fn zero() -> Result<(), String> {
         "should support no markdown (ast)"
     );
 
+    assert_eq!(
+        to_mdast("a\0b", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a�b".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 4, 3))
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 4, 3))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 4, 3))
+        }),
+        "should replace NUL in text (ast)"
+    );
+
+    assert_eq!(
+        to_mdast("`\0`\n\n```\0\n\0\n```", &Default::default())?,
+        Node::Root(Root {
+            children: vec![
+                Node::Paragraph(Paragraph {
+                    children: vec![Node::InlineCode(InlineCode {
+                        value: "�".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 4, 3))
+                    }),],
+                    position: Some(Position::new(1, 1, 0, 1, 4, 3))
+                }),
+                Node::Code(Code {
+                    value: "�".into(),
+                    lang: Some("�".into()),
+                    meta: None,
+                    position: Some(Position::new(3, 1, 5, 5, 4, 15))
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 5, 4, 15))
+        }),
+        "should replace NUL in code, w/o changing positions (ast)"
+    );
+
+    assert_eq!(
+        to_mdast("a\x01b", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a\x01b".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 4, 3))
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 4, 3))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 4, 3))
+        }),
+        "should keep other ASCII control characters (ast)"
+    );
+
     Ok(())
 }
@@ -0,0 +1,138 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Constructs, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn subscript() -> Result<(), String> {
+    let sub = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                subscript: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    let gfm_sub = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                subscript: true,
+                ..Constructs::gfm()
+            },
+            ..ParseOptions::gfm()
+        },
+        compile: CompileOptions::gfm(),
+    };
+
+    assert_eq!(
+        to_html("H~2~O"),
+        "<p>H~2~O</p>",
+        "should ignore subscript by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("H~2~O", &sub)?,
+        "<p>H<sub>2</sub>O</p>",
+        "should support subscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ~b~ c", &sub)?,
+        "<p>a <sub>b</sub> c</p>",
+        "should support subscript between spaces"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ~b c~ d", &sub)?,
+        "<p>a ~b c~ d</p>",
+        "should not support subscript w/ a space"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ~b\\ c~ d", &sub)?,
+        "<p>a <sub>b c</sub> d</p>",
+        "should support subscript w/ an escaped space"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a~b\\ c~ d^e\\ f^",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        subscript: true,
+                        superscript: true,
+                        ..Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p>a<sub>b c</sub> d<sup>e f</sup></p>",
+        "should drop the backslash of escaped spaces in subscript and superscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ~b\\\\ c~ d", &sub)?,
+        "<p>a ~b\\ c~ d</p>",
+        "should not support subscript w/ an escaped backslash before a space"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ~b\tc~ d", &sub)?,
+        "<p>a ~b\tc~ d</p>",
+        "should not support subscript w/ a tab"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ~b\nc~ d", &sub)?,
+        "<p>a ~b\nc~ d</p>",
+        "should not support subscript w/ a line ending"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ~~b~~ c", &sub)?,
+        "<p>a ~~b~~ c</p>",
+        "should not support two tildes w/o strikethrough"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ~b~~ c", &sub)?,
+        "<p>a ~b~~ c</p>",
+        "should not support subscript w/ unbalanced sequences"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ~*b*~ c", &sub)?,
+        "<p>a <sub><em>b</em></sub> c</p>",
+        "should support emphasis in subscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ~~b~~ c", &gfm_sub)?,
+        "<p>a <del>b</del> c</p>",
+        "should support strikethrough w/ two tildes when subscript is on"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ~b~ c", &gfm_sub)?,
+        "<p>a <sub>b</sub> c</p>",
+        "should prefer subscript over strikethrough w/ one tilde"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~H~2~O~~", &gfm_sub)?,
+        "<p><del>H<sub>2</sub>O</del></p>",
+        "should support subscript in strikethrough"
+    );
+
+    assert_eq!(
+        to_html_with_options("![H~2~O](x)", &sub)?,
+        "<p><img src=\"x\" alt=\"H2O\" /></p>",
+        "should support subscript in image alt"
+    );
+
+    Ok(())
+}
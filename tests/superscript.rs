@@ -0,0 +1,90 @@
+use markdown::{to_html, to_html_with_options, Constructs, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn superscript() -> Result<(), String> {
+    let sup = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                superscript: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    let math_sup = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                math_text: true,
+                superscript: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("x^2^"),
+        "<p>x^2^</p>",
+        "should ignore superscript by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("x^2^", &sup)?,
+        "<p>x<sup>2</sup></p>",
+        "should support superscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^b c^ d", &sup)?,
+        "<p>a ^b c^ d</p>",
+        "should not support superscript w/ a space"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^b\\ c^ d", &sup)?,
+        "<p>a <sup>b c</sup> d</p>",
+        "should support superscript w/ an escaped space"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^b\\\\ c^ d", &sup)?,
+        "<p>a ^b\\ c^ d</p>",
+        "should not support superscript w/ an escaped backslash before a space"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^^b^^ c", &sup)?,
+        "<p>a ^^b^^ c</p>",
+        "should not support superscript w/ two carets"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^b c", &sup)?,
+        "<p>a ^b c</p>",
+        "should not support an unclosed superscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("2^10^ and ^*x*^", &sup)?,
+        "<p>2<sup>10</sup> and <sup><em>x</em></sup></p>",
+        "should support emphasis in superscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("$x^2^$", &math_sup)?,
+        "<p><code class=\"language-math math-inline\">x^2^</code></p>",
+        "should not support superscript in math (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a \\^b^ c", &sup)?,
+        "<p>a ^b^ c</p>",
+        "should not support an escaped superscript"
+    );
+
+    Ok(())
+}
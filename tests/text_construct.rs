@@ -0,0 +1,188 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_html, to_html_from_events, to_html_with_options, to_mdast,
+    unist::Position,
+    Markdown, Options, ParseOptions, TextConstruct,
+};
+use pretty_assertions::assert_eq;
+
+/// Highlight (`==a==`).
+struct Mark;
+
+impl TextConstruct for Mark {
+    fn markers(&self) -> &[u8] {
+        b"="
+    }
+
+    fn parse(&self, value: &str) -> Option<usize> {
+        let rest = value.strip_prefix("==")?;
+        let size = rest.find("==")?;
+
+        if size == 0 || rest.starts_with(' ') || rest[..size].ends_with(' ') {
+            None
+        } else {
+            Some(size + 4)
+        }
+    }
+
+    fn to_html(&self, value: &str) -> String {
+        format!("<mark>{}</mark>", &value[2..value.len() - 2])
+    }
+}
+
+/// Shout (`!a`), overlapping with images.
+struct Shout;
+
+impl TextConstruct for Shout {
+    fn markers(&self) -> &[u8] {
+        b"!"
+    }
+
+    fn parse(&self, value: &str) -> Option<usize> {
+        let size = value[1..]
+            .find(|char: char| !char.is_ascii_alphabetic())
+            .unwrap_or(value.len() - 1);
+
+        if size == 0 {
+            None
+        } else {
+            Some(size + 1)
+        }
+    }
+
+    fn to_html(&self, value: &str) -> String {
+        format!("<strong>{}</strong>", value[1..].to_uppercase())
+    }
+}
+
+#[test]
+fn text_construct() -> Result<(), String> {
+    let mark = Options {
+        parse: ParseOptions {
+            text_constructs: vec![Box::new(Mark)],
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    let gfm_mark = Options {
+        parse: ParseOptions {
+            text_constructs: vec![Box::new(Mark)],
+            ..ParseOptions::gfm()
+        },
+        ..Options::gfm()
+    };
+
+    let shout = Options {
+        parse: ParseOptions {
+            text_constructs: vec![Box::new(Shout), Box::new(Mark)],
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("a ==b== c"),
+        "<p>a ==b== c</p>",
+        "should ignore custom syntax by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ==b== c", &mark)?,
+        "<p>a <mark>b</mark> c</p>",
+        "should support a text construct"
+    );
+
+    assert_eq!(
+        to_html_with_options("a==b==c ==d==", &mark)?,
+        "<p>a<mark>b</mark>c <mark>d</mark></p>",
+        "should support text constructs in words, and several of them"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ==b c", &mark)?,
+        "<p>a ==b c</p>",
+        "should support text that does not match"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ==b\nc== d", &mark)?,
+        "<p>a ==b\nc== d</p>",
+        "should not support text constructs across lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a ==b== c*", &mark)?,
+        "<p><em>a <mark>b</mark> c</em></p>",
+        "should support text constructs in attention"
+    );
+
+    assert_eq!(
+        to_html_with_options("`==a==` ==b==", &mark)?,
+        "<p><code>==a==</code> <mark>b</mark></p>",
+        "should not support text constructs in code (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("[==a==](b)", &mark)?,
+        "<p><a href=\"b\"><mark>a</mark></a></p>",
+        "should support text constructs in links"
+    );
+
+    assert_eq!(
+        to_html_with_options("![==a==](b)", &mark)?,
+        "<p><img src=\"b\" alt=\"==a==\" /></p>",
+        "should support text constructs in image alt"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| ==b | c== |", &gfm_mark)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>==b</td>\n</tr>\n</tbody>\n</table>",
+        "should backtrack when a text construct goes past the end of the content"
+    );
+
+    assert_eq!(
+        to_html_with_options("a !b ![c](d) ==e==", &shout)?,
+        "<p>a <strong>B</strong> <img src=\"d\" alt=\"c\" /> <mark>e</mark></p>",
+        "should fall back to built-in constructs"
+    );
+
+    assert_eq!(
+        to_mdast(
+            "a ==b== c",
+            &ParseOptions {
+                text_constructs: vec![Box::new(Mark)],
+                ..ParseOptions::default()
+            }
+        )?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a ==b== c".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 10, 9))
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 10, 9))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 10, 9))
+        }),
+        "should support text constructs as text in mdast"
+    );
+
+    let markdown = Markdown::new("a ==b== c", &mark.parse)?;
+
+    assert_eq!(
+        to_html_from_events(markdown.value(), markdown.events(), &Options::default())?,
+        "<p>a ==b== c</p>",
+        "should compile text constructs as text without them"
+    );
+
+    let long = "!".repeat(100_000);
+
+    assert_eq!(
+        to_html_with_options(&long, &shout)?,
+        format!("<p>{}</p>", long),
+        "should not rescan the line at each marker"
+    );
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Node, Root, ThematicBreak},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, EventName, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -188,6 +188,72 @@ fn thematic_break() -> Result<(), String> {
         "should support turning off thematic breaks"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "a\n***\nb",
+            &Options {
+                compile: CompileOptions {
+                    thematic_break_class: Some("divider".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a</p>\n<hr class=\"divider\" />\n<p>b</p>",
+        "should support `thematic_break_class`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "***",
+            &Options {
+                compile: CompileOptions {
+                    thematic_break_class: Some("divider".into()),
+                    attributes: vec![
+                        (EventName::ThematicBreak, "class".into(), "wide".into()),
+                        (EventName::ThematicBreak, "id".into(), "a".into()),
+                    ],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<hr class=\"divider wide\" id=\"a\" />",
+        "should merge a `class` attribute w/ `thematic_break_class`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "***\n\n> ---",
+            &Options {
+                compile: CompileOptions {
+                    thematic_break_class: Some("a\"b".into()),
+                    thematic_break_tag_name: Some("div".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<div class=\"a&quot;b\"></div>\n<blockquote>\n<div class=\"a&quot;b\"></div>\n</blockquote>",
+        "should support `thematic_break_tag_name` (w/ `thematic_break_class`)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "***",
+            &Options {
+                compile: CompileOptions {
+                    thematic_break_tag_name: Some("HR".into()),
+                    source_positions: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<HR data-sourcepos=\"1:1-1:3\" />",
+        "should support `thematic_break_tag_name: \"hr\"` as a void element (w/ `source_positions`)"
+    );
+
     assert_eq!(
         to_mdast("***", &Default::default())?,
         Node::Root(Root {
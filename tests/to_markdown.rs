@@ -0,0 +1,276 @@
+use markdown::{
+    to_html, to_markdown, Constructs, HeadingStyle, ParseOptions, ThematicBreakStyle,
+    ToMarkdownOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn to_markdown_headings() -> Result<(), String> {
+    let parse = ParseOptions::default();
+    let atx = ToMarkdownOptions {
+        normalize_headings: HeadingStyle::Atx,
+        ..ToMarkdownOptions::default()
+    };
+    let setext = ToMarkdownOptions {
+        normalize_headings: HeadingStyle::Setext,
+        ..ToMarkdownOptions::default()
+    };
+
+    assert_eq!(
+        to_markdown("a\n===\n\n# b", &parse, &ToMarkdownOptions::default())?,
+        "a\n===\n\n# b",
+        "should keep headings by default"
+    );
+
+    assert_eq!(
+        to_markdown("a\n===", &parse, &atx)?,
+        "# a",
+        "should support turning a heading (setext, rank 1) into a heading (atx)"
+    );
+
+    assert_eq!(
+        to_markdown("a\n-\n\nb", &parse, &atx)?,
+        "## a\n\nb",
+        "should support turning a heading (setext, rank 2) into a heading (atx)"
+    );
+
+    assert_eq!(
+        to_markdown("  a *b\n   c* \nd\n===", &parse, &atx)?,
+        "# a *b c* d",
+        "should join lines of headings (setext)"
+    );
+
+    assert_eq!(
+        to_markdown("> a\n> b\n> ===\n> c", &parse, &atx)?,
+        "> # a b\n> c",
+        "should support headings (setext) in containers"
+    );
+
+    assert_eq!(
+        to_markdown("- > a\n  > b\n  > ---", &parse, &atx)?,
+        "- > ## a b",
+        "should support headings (setext) in nested containers"
+    );
+
+    assert_eq!(
+        to_markdown("a #\n===", &parse, &atx)?,
+        "# a \\#",
+        "should escape a final `#` that would be a closing sequence"
+    );
+
+    assert_eq!(
+        to_markdown("a ##\n===\n\n\\#\n===\n\na#\n===", &parse, &atx)?,
+        "# a \\##\n\n# \\#\n\n# a#",
+        "should escape final runs of `#` only where needed"
+    );
+
+    assert_eq!(
+        to_markdown("a\\\nb\n===", &parse, &atx)?,
+        "a\\\nb\n===",
+        "should keep headings (setext) with hard breaks"
+    );
+
+    assert_eq!(
+        to_markdown("a\r\n===\r\n", &parse, &atx)?,
+        "# a\r\n",
+        "should keep line endings"
+    );
+
+    assert_eq!(
+        to_markdown("# a\n\n## *b* ##\n\n### c", &parse, &setext)?,
+        "a\n=\n\n*b*\n---\n\n### c",
+        "should support turning headings (atx) into headings (setext)"
+    );
+
+    assert_eq!(
+        to_markdown("> - # a\r\n>\r\n> b", &parse, &setext)?,
+        "> - a\r\n>   =\r\n>\r\n> b",
+        "should support headings (atx) in containers"
+    );
+
+    assert_eq!(
+        to_markdown("p\n# a\n\n- p\n# b\n\n> p\n# c", &parse, &setext)?,
+        "p\n# a\n\n- p\n# b\n\n> p\n# c",
+        "should keep headings (atx) after paragraphs, which would be continued"
+    );
+
+    assert_eq!(
+        to_markdown("> # a\nb\n\n> - # c\n> d", &parse, &setext)?,
+        "> # a\nb\n\n> - # c\n> d",
+        "should keep headings (atx) in containers before lines that would be lazy"
+    );
+
+    assert_eq!(
+        to_markdown("p\n\n# a\nb\n\n> p\n>\n> # c", &parse, &setext)?,
+        "p\n\na\n=\nb\n\n> p\n>\n> c\n> =",
+        "should support headings (atx) after blank lines"
+    );
+
+    assert_eq!(
+        to_markdown("#\n\n# - a\n\n# [a]: b", &parse, &setext)?,
+        "#\n\n# - a\n\n# [a]: b",
+        "should keep headings (atx) that cannot be headings (setext)"
+    );
+
+    assert_eq!(
+        to_markdown("a\n===", &parse, &setext)?,
+        "a\n===",
+        "should keep headings (setext) when turning headings into setext"
+    );
+
+    assert_eq!(
+        to_markdown("# a\n---\n\n# b\n- - -", &parse, &setext)?,
+        "# a\n---\n\n# b\n- - -",
+        "should keep headings (atx) before thematic breaks"
+    );
+
+    for input in [
+        "a #\n===",
+        "a ##\n---",
+        "#\n===",
+        "a \\#\n===",
+        "a#\n===",
+        "> a #\n> ===",
+    ] {
+        assert_eq!(
+            to_html(&to_markdown(input, &parse, &atx)?),
+            to_html(input),
+            "should round trip headings (setext) to atx: {:?}",
+            input
+        );
+    }
+
+    for input in [
+        "# a\n---",
+        "# a\n***",
+        "# a\n===",
+        "# a\nb",
+        "# a\n- b",
+        "# a #\n\n## b \\#",
+        "> # a\n>\n> b",
+    ] {
+        assert_eq!(
+            to_html(&to_markdown(input, &parse, &setext)?),
+            to_html(input),
+            "should round trip headings (atx) to setext: {:?}",
+            input
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn to_markdown_thematic_breaks() -> Result<(), String> {
+    let parse = ParseOptions::default();
+    let asterisk = ToMarkdownOptions {
+        normalize_thematic_breaks: ThematicBreakStyle::Asterisk,
+        ..ToMarkdownOptions::default()
+    };
+    let dash = ToMarkdownOptions {
+        normalize_thematic_breaks: ThematicBreakStyle::Dash,
+        ..ToMarkdownOptions::default()
+    };
+    let underscore = ToMarkdownOptions {
+        normalize_thematic_breaks: ThematicBreakStyle::Underscore,
+        ..ToMarkdownOptions::default()
+    };
+
+    assert_eq!(
+        to_markdown("***\n\n- - -\n\n___", &parse, &ToMarkdownOptions::default())?,
+        "***\n\n- - -\n\n___",
+        "should keep thematic breaks by default"
+    );
+
+    assert_eq!(
+        to_markdown("***\n\n- - -\n\n _ _ _ _ ", &parse, &dash)?,
+        "---\n\n---\n\n---",
+        "should support turning thematic breaks into dashes"
+    );
+
+    assert_eq!(
+        to_markdown("***\n\n- - -\n\n _ _ _ _ ", &parse, &asterisk)?,
+        "***\n\n***\n\n***",
+        "should support turning thematic breaks into asterisks"
+    );
+
+    assert_eq!(
+        to_markdown("***\n\n- - -\n\n _ _ _ _ ", &parse, &underscore)?,
+        "___\n\n___\n\n___",
+        "should support turning thematic breaks into underscores"
+    );
+
+    assert_eq!(
+        to_markdown(
+            "***",
+            &parse,
+            &ToMarkdownOptions {
+                thematic_break_size: 5,
+                ..dash.clone()
+            }
+        )?,
+        "-----",
+        "should support `thematic_break_size`"
+    );
+
+    assert_eq!(
+        to_markdown(
+            "***",
+            &parse,
+            &ToMarkdownOptions {
+                thematic_break_size: 1,
+                ..dash.clone()
+            }
+        )?,
+        "---",
+        "should use at least 3 markers"
+    );
+
+    assert_eq!(
+        to_markdown("> a\n>\n> ***\n\n- b\n\n  ***", &parse, &dash)?,
+        "> a\n>\n> ---\n\n- b\n\n  ---",
+        "should support thematic breaks in containers"
+    );
+
+    assert_eq!(
+        to_markdown("a\n***\n\n> b\n> ***", &parse, &dash)?,
+        "a\n***\n\n> b\n> ***",
+        "should keep thematic breaks right after paragraphs when turning them into dashes"
+    );
+
+    assert_eq!(
+        to_markdown("a\n***", &parse, &underscore)?,
+        "a\n___",
+        "should support thematic breaks right after paragraphs when turning them into underscores"
+    );
+
+    assert_eq!(
+        to_markdown("- ***\n\n* - ***", &parse, &dash)?,
+        "- ***\n\n* - ***",
+        "should keep thematic breaks after list item markers of the same kind"
+    );
+
+    assert_eq!(
+        to_markdown("+ ***", &parse, &dash)?,
+        "+ ---",
+        "should support thematic breaks after list item markers of another kind"
+    );
+
+    assert_eq!(
+        to_markdown(
+            "***\n\na\n\n***",
+            &ParseOptions {
+                constructs: Constructs {
+                    frontmatter: true,
+                    ..Constructs::default()
+                },
+                ..ParseOptions::default()
+            },
+            &dash
+        )?,
+        "***\n\na\n\n---",
+        "should keep thematic breaks at the start when frontmatter is on, when turning them into dashes"
+    );
+
+    Ok(())
+}